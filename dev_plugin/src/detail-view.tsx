@@ -129,6 +129,9 @@ export default function DetailView(): ReactElement {
                 <Detail.Content.H6>H6 Title</Detail.Content.H6>
                 <Detail.Content.Image source={{ asset: "logo.png" }}/>
                 <Detail.Content.CodeBlock>Code block Test</Detail.Content.CodeBlock>
+                <Detail.Content.Markdown>
+                    {"## Markdown Test\n\nText with **bold**, *italic* and `code`, [link](https://github.com/project-gauntlet/gauntlet)\n\n- [x] Done\n- [ ] Not done\n\n| Column 1 | Column 2 |\n|---|---|\n| Cell 1 | Cell 2 |"}
+                </Detail.Content.Markdown>
                 <Detail.Content.HorizontalBreak/>
                 <Detail.Content.Paragraph>
                     You clicked {count} times
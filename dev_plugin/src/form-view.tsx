@@ -1,5 +1,5 @@
 import { ReactElement, useState } from 'react';
-import { Action, ActionPanel, Form } from "@project-gauntlet/api/components";
+import { Action, ActionPanel, DatePickerMode, Form } from "@project-gauntlet/api/components";
 
 export default function FormView(): ReactElement {
 
@@ -32,16 +32,30 @@ export default function FormView(): ReactElement {
                         }}
                     />
                 </ActionPanel>
-            }>
+            }
+            onSubmit={values => {
+                console.log(`submitted values: ${JSON.stringify(values)}`)
+            }}
+        >
             {/* uncontrolled */}
             <Form.TextField
+                id="text"
                 label={"Text Field"}
+                info={"Submitted with Ctrl+Enter"}
                 onChange={value => {
                     console.log(`uncontrolled value: ${value}`)
                 }}
             />
             <Form.PasswordField
+                id="password"
                 label={"Password Field"}
+                error={"Password is required"}
+                onChange={value => {
+                    console.log(`uncontrolled value: ${value}`)
+                }}
+            />
+            <Form.TextArea
+                label={"Text Area"}
                 onChange={value => {
                     console.log(`uncontrolled value: ${value}`)
                 }}
@@ -64,12 +78,48 @@ export default function FormView(): ReactElement {
                 <Form.Select.Item value={"select_item_3"}>Select Item 3</Form.Select.Item>
                 <Form.Select.Item value={"select_item_4"}>Select Item 4</Form.Select.Item>
             </Form.Select>
+            <Form.MultiSelect
+                label={"Multi selecting..."}
+                onChange={values => {
+                    console.log(`uncontrolled values: ${values}`)
+                }}
+            >
+                <Form.MultiSelect.Item value={"select_item_1"}>Select Item 1</Form.MultiSelect.Item>
+                <Form.MultiSelect.Item value={"select_item_2"}>Select Item 2</Form.MultiSelect.Item>
+                <Form.MultiSelect.Item value={"select_item_3"}>Select Item 3</Form.MultiSelect.Item>
+                <Form.MultiSelect.Item value={"select_item_4"}>Select Item 4</Form.MultiSelect.Item>
+            </Form.MultiSelect>
+            <Form.FilePicker
+                label={"File picking..."}
+                allowMultiple
+                canChooseDirectories
+                onChange={values => {
+                    console.log(`uncontrolled values: ${values}`)
+                }}
+            />
             <Form.DatePicker
                 label={"What is your birthday?"}
                 onChange={value => {
                     console.log(`uncontrolled value: ${value}`)
                 }}
             />
+            <Form.DatePicker
+                label={"When is the meeting?"}
+                mode={DatePickerMode.DateTime}
+                onChange={value => {
+                    console.log(`uncontrolled value: ${value}`)
+                }}
+            />
+            <Form.NumberField
+                label={"How many?"}
+                min={0}
+                max={10}
+                step={0.5}
+                precision={1}
+                onChange={value => {
+                    console.log(`uncontrolled value: ${value}`)
+                }}
+            />
             <Form.Separator/>
             {/* controlled */}
             <Form.TextField
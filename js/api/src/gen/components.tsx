@@ -41,6 +41,10 @@ declare global {
             ["gauntlet:metadata"]: {
                 children?: ElementComponent<typeof MetadataTagList | typeof MetadataLink | typeof MetadataValue | typeof MetadataIcon | typeof MetadataSeparator>;
             };
+            ["gauntlet:link"]: {
+                children?: StringComponent;
+                href: string;
+            };
             ["gauntlet:image"]: {
                 source: ImageLike;
             };
@@ -66,35 +70,74 @@ declare global {
             ["gauntlet:code_block"]: {
                 children?: StringComponent;
             };
-            ["gauntlet:paragraph"]: {
+            ["gauntlet:markdown"]: {
                 children?: StringComponent;
             };
+            ["gauntlet:code"]: {
+                children?: StringComponent;
+            };
+            ["gauntlet:paragraph"]: {
+                children?: StringOrElementComponent<typeof Link | typeof Code>;
+            };
             ["gauntlet:content"]: {
-                children?: ElementComponent<typeof Paragraph | typeof Image | typeof H1 | typeof H2 | typeof H3 | typeof H4 | typeof H5 | typeof H6 | typeof HorizontalBreak | typeof CodeBlock>;
+                children?: ElementComponent<typeof Paragraph | typeof Image | typeof H1 | typeof H2 | typeof H3 | typeof H4 | typeof H5 | typeof H6 | typeof HorizontalBreak | typeof CodeBlock | typeof Markdown>;
             };
             ["gauntlet:detail"]: {
                 children?: ElementComponent<typeof ActionPanel | typeof Metadata | typeof Content>;
                 isLoading?: boolean;
             };
             ["gauntlet:text_field"]: {
+                id?: string;
                 label?: string;
                 value?: string;
+                error?: string;
+                info?: string;
                 onChange?: (value: string | undefined) => void;
             };
             ["gauntlet:password_field"]: {
+                id?: string;
+                label?: string;
+                value?: string;
+                error?: string;
+                info?: string;
+                onChange?: (value: string | undefined) => void;
+            };
+            ["gauntlet:number_field"]: {
+                id?: string;
+                label?: string;
+                value?: number;
+                min?: number;
+                max?: number;
+                step?: number;
+                precision?: number;
+                error?: string;
+                info?: string;
+                onChange?: (value: number) => void;
+            };
+            ["gauntlet:text_area"]: {
+                id?: string;
                 label?: string;
                 value?: string;
+                error?: string;
+                info?: string;
                 onChange?: (value: string | undefined) => void;
             };
             ["gauntlet:checkbox"]: {
+                id?: string;
                 label?: string;
                 title?: string;
                 value?: boolean;
+                error?: string;
+                info?: string;
                 onChange?: (value: boolean) => void;
             };
             ["gauntlet:date_picker"]: {
+                id?: string;
                 label?: string;
                 value?: string;
+                mode?: DatePickerMode;
+                error?: string;
+                info?: string;
                 onChange?: (value: string | undefined) => void;
             };
             ["gauntlet:select_item"]: {
@@ -103,14 +146,38 @@ declare global {
             };
             ["gauntlet:select"]: {
                 children?: ElementComponent<typeof SelectItem>;
+                id?: string;
                 label?: string;
                 value?: string;
+                error?: string;
+                info?: string;
                 onChange?: (value: string | undefined) => void;
             };
+            ["gauntlet:multi_select"]: {
+                children?: ElementComponent<typeof SelectItem>;
+                id?: string;
+                label?: string;
+                value?: string[];
+                error?: string;
+                info?: string;
+                onChange?: (values: string[]) => void;
+            };
+            ["gauntlet:file_picker"]: {
+                id?: string;
+                label?: string;
+                value?: string[];
+                allowMultiple?: boolean;
+                canChooseDirectories?: boolean;
+                extensions?: string[];
+                error?: string;
+                info?: string;
+                onChange?: (values: string[]) => void;
+            };
             ["gauntlet:separator"]: {};
             ["gauntlet:form"]: {
-                children?: ElementComponent<typeof ActionPanel | typeof TextField | typeof PasswordField | typeof Checkbox | typeof DatePicker | typeof Select | typeof Separator>;
+                children?: ElementComponent<typeof ActionPanel | typeof TextField | typeof PasswordField | typeof NumberField | typeof TextArea | typeof Checkbox | typeof DatePicker | typeof Select | typeof MultiSelect | typeof FilePicker | typeof Separator>;
                 isLoading?: boolean;
+                onSubmit?: (values: Record<string, string | boolean | string[]>) => void;
             };
             ["gauntlet:inline_separator"]: {
                 icon?: Icons;
@@ -132,7 +199,22 @@ declare global {
                 icon?: ImageLike;
                 tooltip?: string;
             };
+            ["gauntlet:search_bar_dropdown_item"]: {
+                value: string;
+                title: string;
+            };
+            ["gauntlet:search_bar_dropdown_section"]: {
+                children?: ElementComponent<typeof SearchBarDropdownItem>;
+                title: string;
+            };
+            ["gauntlet:search_bar_dropdown"]: {
+                children?: ElementComponent<typeof SearchBarDropdownItem | typeof SearchBarDropdownSection>;
+                value?: string;
+                placeholder?: string;
+                onChange?: (value: string) => void;
+            };
             ["gauntlet:search_bar"]: {
+                children?: ElementComponent<typeof SearchBarDropdown>;
                 value?: string;
                 placeholder?: string;
                 onChange?: (value: string | undefined) => void;
@@ -153,6 +235,8 @@ declare global {
                 children?: ElementComponent<typeof ActionPanel | typeof ListItem | typeof ListSection | typeof SearchBar | typeof EmptyView | typeof Detail>;
                 isLoading?: boolean;
                 onItemFocusChange?: (itemId: string | undefined) => void;
+                hasMore?: boolean;
+                onLoadMore?: () => void;
             };
             ["gauntlet:grid_item"]: {
                 children?: ElementComponent<typeof IconAccessory | typeof Content>;
@@ -171,6 +255,26 @@ declare global {
                 isLoading?: boolean;
                 columns?: number;
                 onItemFocusChange?: (itemId: string | undefined) => void;
+                hasMore?: boolean;
+                onLoadMore?: () => void;
+            };
+            ["gauntlet:table_column"]: {
+                id: string;
+                title: string;
+                width?: number;
+                sortable?: boolean;
+            };
+            ["gauntlet:table_cell"]: {
+                children?: StringComponent;
+            };
+            ["gauntlet:table_row"]: {
+                children?: ElementComponent<typeof ActionPanel | typeof TableCell>;
+                id: string;
+            };
+            ["gauntlet:table"]: {
+                children?: ElementComponent<typeof ActionPanel | typeof TableColumn | typeof TableRow | typeof SearchBar | typeof EmptyView>;
+                isLoading?: boolean;
+                onRowFocusChange?: (rowId: string | undefined) => void;
             };
         }
     }
@@ -363,6 +467,11 @@ export type ImageSourceAsset = {
 };
 export type ImageSource = ImageSourceUrl | ImageSourceAsset;
 export type ImageLike = ImageSource | Icons;
+export enum DatePickerMode {
+    Date = "Date",
+    Time = "Time",
+    DateTime = "DateTime"
+}
 export interface ActionProps {
     id?: string;
     label: string;
@@ -452,6 +561,13 @@ Metadata.Link = MetadataLink;
 Metadata.Value = MetadataValue;
 Metadata.Icon = MetadataIcon;
 Metadata.Separator = MetadataSeparator;
+export interface LinkProps {
+    children?: StringComponent;
+    href: string;
+}
+export const Link: FC<LinkProps> = (props: LinkProps): ReactNode => {
+    return <gauntlet:link href={props.href}>{props.children}</gauntlet:link>;
+};
 export interface ImageProps {
     source: ImageLike;
 }
@@ -503,14 +619,31 @@ export interface CodeBlockProps {
 export const CodeBlock: FC<CodeBlockProps> = (props: CodeBlockProps): ReactNode => {
     return <gauntlet:code_block>{props.children}</gauntlet:code_block>;
 };
-export interface ParagraphProps {
+export interface MarkdownProps {
+    children?: StringComponent;
+}
+export const Markdown: FC<MarkdownProps> = (props: MarkdownProps): ReactNode => {
+    return <gauntlet:markdown>{props.children}</gauntlet:markdown>;
+};
+export interface CodeProps {
     children?: StringComponent;
 }
-export const Paragraph: FC<ParagraphProps> = (props: ParagraphProps): ReactNode => {
+export const Code: FC<CodeProps> = (props: CodeProps): ReactNode => {
+    return <gauntlet:code>{props.children}</gauntlet:code>;
+};
+export interface ParagraphProps {
+    children?: StringOrElementComponent<typeof Link | typeof Code>;
+}
+export const Paragraph: FC<ParagraphProps> & {
+    Link: typeof Link;
+    Code: typeof Code;
+} = (props: ParagraphProps): ReactNode => {
     return <gauntlet:paragraph>{props.children}</gauntlet:paragraph>;
 };
+Paragraph.Link = Link;
+Paragraph.Code = Code;
 export interface ContentProps {
-    children?: ElementComponent<typeof Paragraph | typeof Image | typeof H1 | typeof H2 | typeof H3 | typeof H4 | typeof H5 | typeof H6 | typeof HorizontalBreak | typeof CodeBlock>;
+    children?: ElementComponent<typeof Paragraph | typeof Image | typeof H1 | typeof H2 | typeof H3 | typeof H4 | typeof H5 | typeof H6 | typeof HorizontalBreak | typeof CodeBlock | typeof Markdown>;
 }
 export const Content: FC<ContentProps> & {
     Paragraph: typeof Paragraph;
@@ -523,6 +656,7 @@ export const Content: FC<ContentProps> & {
     H6: typeof H6;
     HorizontalBreak: typeof HorizontalBreak;
     CodeBlock: typeof CodeBlock;
+    Markdown: typeof Markdown;
 } = (props: ContentProps): ReactNode => {
     return <gauntlet:content>{props.children}</gauntlet:content>;
 };
@@ -536,6 +670,7 @@ Content.H5 = H5;
 Content.H6 = H6;
 Content.HorizontalBreak = HorizontalBreak;
 Content.CodeBlock = CodeBlock;
+Content.Markdown = Markdown;
 export interface DetailProps {
     children?: ElementComponent<typeof Metadata | typeof Content>;
     isLoading?: boolean;
@@ -550,37 +685,76 @@ export const Detail: FC<DetailProps> & {
 Detail.Metadata = Metadata;
 Detail.Content = Content;
 export interface TextFieldProps {
+    id?: string;
     label?: string;
     value?: string;
+    error?: string;
+    info?: string;
     onChange?: (value: string | undefined) => void;
 }
 export const TextField: FC<TextFieldProps> = (props: TextFieldProps): ReactNode => {
-    return <gauntlet:text_field label={props.label} value={props.value} onChange={props.onChange}></gauntlet:text_field>;
+    return <gauntlet:text_field id={props.id} label={props.label} value={props.value} error={props.error} info={props.info} onChange={props.onChange}></gauntlet:text_field>;
 };
 export interface PasswordFieldProps {
+    id?: string;
     label?: string;
     value?: string;
+    error?: string;
+    info?: string;
     onChange?: (value: string | undefined) => void;
 }
 export const PasswordField: FC<PasswordFieldProps> = (props: PasswordFieldProps): ReactNode => {
-    return <gauntlet:password_field label={props.label} value={props.value} onChange={props.onChange}></gauntlet:password_field>;
+    return <gauntlet:password_field id={props.id} label={props.label} value={props.value} error={props.error} info={props.info} onChange={props.onChange}></gauntlet:password_field>;
+};
+export interface NumberFieldProps {
+    id?: string;
+    label?: string;
+    value?: number;
+    min?: number;
+    max?: number;
+    step?: number;
+    precision?: number;
+    error?: string;
+    info?: string;
+    onChange?: (value: number) => void;
+}
+export const NumberField: FC<NumberFieldProps> = (props: NumberFieldProps): ReactNode => {
+    return <gauntlet:number_field id={props.id} label={props.label} value={props.value} min={props.min} max={props.max} step={props.step} precision={props.precision} error={props.error} info={props.info} onChange={props.onChange}></gauntlet:number_field>;
+};
+export interface TextAreaProps {
+    id?: string;
+    label?: string;
+    value?: string;
+    error?: string;
+    info?: string;
+    onChange?: (value: string | undefined) => void;
+}
+export const TextArea: FC<TextAreaProps> = (props: TextAreaProps): ReactNode => {
+    return <gauntlet:text_area id={props.id} label={props.label} value={props.value} error={props.error} info={props.info} onChange={props.onChange}></gauntlet:text_area>;
 };
 export interface CheckboxProps {
+    id?: string;
     label?: string;
     title?: string;
     value?: boolean;
+    error?: string;
+    info?: string;
     onChange?: (value: boolean) => void;
 }
 export const Checkbox: FC<CheckboxProps> = (props: CheckboxProps): ReactNode => {
-    return <gauntlet:checkbox label={props.label} title={props.title} value={props.value} onChange={props.onChange}></gauntlet:checkbox>;
+    return <gauntlet:checkbox id={props.id} label={props.label} title={props.title} value={props.value} error={props.error} info={props.info} onChange={props.onChange}></gauntlet:checkbox>;
 };
 export interface DatePickerProps {
+    id?: string;
     label?: string;
     value?: string;
+    mode?: DatePickerMode;
+    error?: string;
+    info?: string;
     onChange?: (value: string | undefined) => void;
 }
 export const DatePicker: FC<DatePickerProps> = (props: DatePickerProps): ReactNode => {
-    return <gauntlet:date_picker label={props.label} value={props.value} onChange={props.onChange}></gauntlet:date_picker>;
+    return <gauntlet:date_picker id={props.id} label={props.label} value={props.value} mode={props.mode} error={props.error} info={props.info} onChange={props.onChange}></gauntlet:date_picker>;
 };
 export interface SelectItemProps {
     children?: StringComponent;
@@ -591,39 +765,80 @@ export const SelectItem: FC<SelectItemProps> = (props: SelectItemProps): ReactNo
 };
 export interface SelectProps {
     children?: ElementComponent<typeof SelectItem>;
+    id?: string;
     label?: string;
     value?: string;
+    error?: string;
+    info?: string;
     onChange?: (value: string | undefined) => void;
 }
 export const Select: FC<SelectProps> & {
     Item: typeof SelectItem;
 } = (props: SelectProps): ReactNode => {
-    return <gauntlet:select label={props.label} value={props.value} onChange={props.onChange}>{props.children}</gauntlet:select>;
+    return <gauntlet:select id={props.id} label={props.label} value={props.value} error={props.error} info={props.info} onChange={props.onChange}>{props.children}</gauntlet:select>;
 };
 Select.Item = SelectItem;
+export interface MultiSelectProps {
+    children?: ElementComponent<typeof SelectItem>;
+    id?: string;
+    label?: string;
+    value?: string[];
+    error?: string;
+    info?: string;
+    onChange?: (values: string[]) => void;
+}
+export const MultiSelect: FC<MultiSelectProps> & {
+    Item: typeof SelectItem;
+} = (props: MultiSelectProps): ReactNode => {
+    return <gauntlet:multi_select id={props.id} label={props.label} value={props.value} error={props.error} info={props.info} onChange={props.onChange}>{props.children}</gauntlet:multi_select>;
+};
+MultiSelect.Item = SelectItem;
+export interface FilePickerProps {
+    id?: string;
+    label?: string;
+    value?: string[];
+    allowMultiple?: boolean;
+    canChooseDirectories?: boolean;
+    extensions?: string[];
+    error?: string;
+    info?: string;
+    onChange?: (values: string[]) => void;
+}
+export const FilePicker: FC<FilePickerProps> = (props: FilePickerProps): ReactNode => {
+    return <gauntlet:file_picker id={props.id} label={props.label} value={props.value} allowMultiple={props.allowMultiple} canChooseDirectories={props.canChooseDirectories} extensions={props.extensions} error={props.error} info={props.info} onChange={props.onChange}></gauntlet:file_picker>;
+};
 export const Separator: FC = (): ReactNode => {
     return <gauntlet:separator></gauntlet:separator>;
 };
 export interface FormProps {
-    children?: ElementComponent<typeof TextField | typeof PasswordField | typeof Checkbox | typeof DatePicker | typeof Select | typeof Separator>;
+    children?: ElementComponent<typeof TextField | typeof PasswordField | typeof NumberField | typeof TextArea | typeof Checkbox | typeof DatePicker | typeof Select | typeof MultiSelect | typeof FilePicker | typeof Separator>;
     isLoading?: boolean;
     actions?: ElementComponent<typeof ActionPanel>;
+    onSubmit?: (values: Record<string, string | boolean | string[]>) => void;
 }
 export const Form: FC<FormProps> & {
     TextField: typeof TextField;
     PasswordField: typeof PasswordField;
+    NumberField: typeof NumberField;
+    TextArea: typeof TextArea;
     Checkbox: typeof Checkbox;
     DatePicker: typeof DatePicker;
     Select: typeof Select;
+    MultiSelect: typeof MultiSelect;
+    FilePicker: typeof FilePicker;
     Separator: typeof Separator;
 } = (props: FormProps): ReactNode => {
-    return <gauntlet:form isLoading={props.isLoading}>{props.actions as any}{props.children}</gauntlet:form>;
+    return <gauntlet:form isLoading={props.isLoading} onSubmit={props.onSubmit}>{props.actions as any}{props.children}</gauntlet:form>;
 };
 Form.TextField = TextField;
 Form.PasswordField = PasswordField;
+Form.NumberField = NumberField;
+Form.TextArea = TextArea;
 Form.Checkbox = Checkbox;
 Form.DatePicker = DatePicker;
 Form.Select = Select;
+Form.MultiSelect = MultiSelect;
+Form.FilePicker = FilePicker;
 Form.Separator = Separator;
 export interface InlineSeparatorProps {
     icon?: Icons;
@@ -670,14 +885,49 @@ export interface TextAccessoryProps {
 export const TextAccessory: FC<TextAccessoryProps> = (props: TextAccessoryProps): ReactNode => {
     return <gauntlet:accessory_text text={props.text} icon={props.icon} tooltip={props.tooltip}></gauntlet:accessory_text>;
 };
+export interface SearchBarDropdownItemProps {
+    value: string;
+    title: string;
+}
+export const SearchBarDropdownItem: FC<SearchBarDropdownItemProps> = (props: SearchBarDropdownItemProps): ReactNode => {
+    return <gauntlet:search_bar_dropdown_item value={props.value} title={props.title}></gauntlet:search_bar_dropdown_item>;
+};
+export interface SearchBarDropdownSectionProps {
+    children?: ElementComponent<typeof SearchBarDropdownItem>;
+    title: string;
+}
+export const SearchBarDropdownSection: FC<SearchBarDropdownSectionProps> & {
+    Item: typeof SearchBarDropdownItem;
+} = (props: SearchBarDropdownSectionProps): ReactNode => {
+    return <gauntlet:search_bar_dropdown_section title={props.title}>{props.children}</gauntlet:search_bar_dropdown_section>;
+};
+SearchBarDropdownSection.Item = SearchBarDropdownItem;
+export interface SearchBarDropdownProps {
+    children?: ElementComponent<typeof SearchBarDropdownItem | typeof SearchBarDropdownSection>;
+    value?: string;
+    placeholder?: string;
+    onChange?: (value: string) => void;
+}
+export const SearchBarDropdown: FC<SearchBarDropdownProps> & {
+    Item: typeof SearchBarDropdownItem;
+    Section: typeof SearchBarDropdownSection;
+} = (props: SearchBarDropdownProps): ReactNode => {
+    return <gauntlet:search_bar_dropdown value={props.value} placeholder={props.placeholder} onChange={props.onChange}>{props.children}</gauntlet:search_bar_dropdown>;
+};
+SearchBarDropdown.Item = SearchBarDropdownItem;
+SearchBarDropdown.Section = SearchBarDropdownSection;
 export interface SearchBarProps {
+    children?: ElementComponent<typeof SearchBarDropdown>;
     value?: string;
     placeholder?: string;
     onChange?: (value: string | undefined) => void;
 }
-export const SearchBar: FC<SearchBarProps> = (props: SearchBarProps): ReactNode => {
-    return <gauntlet:search_bar value={props.value} placeholder={props.placeholder} onChange={props.onChange}></gauntlet:search_bar>;
+export const SearchBar: FC<SearchBarProps> & {
+    Dropdown: typeof SearchBarDropdown;
+} = (props: SearchBarProps): ReactNode => {
+    return <gauntlet:search_bar value={props.value} placeholder={props.placeholder} onChange={props.onChange}>{props.children}</gauntlet:search_bar>;
 };
+SearchBar.Dropdown = SearchBarDropdown;
 export interface ListItemProps {
     id: string;
     title: string;
@@ -704,6 +954,8 @@ export interface ListProps {
     actions?: ElementComponent<typeof ActionPanel>;
     isLoading?: boolean;
     onItemFocusChange?: (itemId: string | undefined) => void;
+    hasMore?: boolean;
+    onLoadMore?: () => void;
 }
 export const List: FC<ListProps> & {
     Item: typeof ListItem;
@@ -712,7 +964,7 @@ export const List: FC<ListProps> & {
     EmptyView: typeof EmptyView;
     Detail: typeof Detail;
 } = (props: ListProps): ReactNode => {
-    return <gauntlet:list isLoading={props.isLoading} onItemFocusChange={props.onItemFocusChange}>{props.actions as any}{props.children}</gauntlet:list>;
+    return <gauntlet:list isLoading={props.isLoading} onItemFocusChange={props.onItemFocusChange} hasMore={props.hasMore} onLoadMore={props.onLoadMore}>{props.actions as any}{props.children}</gauntlet:list>;
 };
 List.Item = ListItem;
 List.Section = ListSection;
@@ -750,6 +1002,8 @@ export interface GridProps {
     actions?: ElementComponent<typeof ActionPanel>;
     columns?: number;
     onItemFocusChange?: (itemId: string | undefined) => void;
+    hasMore?: boolean;
+    onLoadMore?: () => void;
 }
 export const Grid: FC<GridProps> & {
     Item: typeof GridItem;
@@ -757,9 +1011,53 @@ export const Grid: FC<GridProps> & {
     SearchBar: typeof SearchBar;
     EmptyView: typeof EmptyView;
 } = (props: GridProps): ReactNode => {
-    return <gauntlet:grid isLoading={props.isLoading} columns={props.columns} onItemFocusChange={props.onItemFocusChange}>{props.actions as any}{props.children}</gauntlet:grid>;
+    return <gauntlet:grid isLoading={props.isLoading} columns={props.columns} onItemFocusChange={props.onItemFocusChange} hasMore={props.hasMore} onLoadMore={props.onLoadMore}>{props.actions as any}{props.children}</gauntlet:grid>;
 };
 Grid.Item = GridItem;
 Grid.Section = GridSection;
 Grid.SearchBar = SearchBar;
 Grid.EmptyView = EmptyView;
+export interface TableColumnProps {
+    id: string;
+    title: string;
+    width?: number;
+    sortable?: boolean;
+}
+export const TableColumn: FC<TableColumnProps> = (props: TableColumnProps): ReactNode => {
+    return <gauntlet:table_column id={props.id} title={props.title} width={props.width} sortable={props.sortable}></gauntlet:table_column>;
+};
+export interface TableCellProps {
+    children?: StringComponent;
+}
+export const TableCell: FC<TableCellProps> = (props: TableCellProps): ReactNode => {
+    return <gauntlet:table_cell>{props.children}</gauntlet:table_cell>;
+};
+export interface TableRowProps {
+    children?: ElementComponent<typeof TableCell>;
+    id: string;
+    actions?: ElementComponent<typeof ActionPanel>;
+}
+export const TableRow: FC<TableRowProps> & {
+    Cell: typeof TableCell;
+} = (props: TableRowProps): ReactNode => {
+    return <gauntlet:table_row id={props.id}>{props.actions as any}{props.children}</gauntlet:table_row>;
+};
+TableRow.Cell = TableCell;
+export interface TableProps {
+    children?: ElementComponent<typeof TableColumn | typeof TableRow | typeof SearchBar | typeof EmptyView>;
+    isLoading?: boolean;
+    actions?: ElementComponent<typeof ActionPanel>;
+    onRowFocusChange?: (rowId: string | undefined) => void;
+}
+export const Table: FC<TableProps> & {
+    Column: typeof TableColumn;
+    Row: typeof TableRow;
+    SearchBar: typeof SearchBar;
+    EmptyView: typeof EmptyView;
+} = (props: TableProps): ReactNode => {
+    return <gauntlet:table isLoading={props.isLoading} onRowFocusChange={props.onRowFocusChange}>{props.actions as any}{props.children}</gauntlet:table>;
+};
+Table.Column = TableColumn;
+Table.Row = TableRow;
+Table.SearchBar = SearchBar;
+Table.EmptyView = EmptyView;
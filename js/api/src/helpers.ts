@@ -1,6 +1,7 @@
 // @ts-ignore TODO how to add declaration for this?
-import { getAssetData, getAssetDataSync, getPluginPreferences, getEntrypointPreferences, showHudWindow } from "ext:gauntlet/renderer.js";
+import { addPreferencesChangedListener, getAssetData, getAssetDataSync, getPluginPreferences, getEntrypointPreferences, setFileSystemWatchListener, showHudWindow } from "ext:gauntlet/renderer.js";
 import {
+    call_plugin_function,
     clipboard_clear,
     clipboard_read,
     clipboard_read_text,
@@ -9,7 +10,17 @@ import {
     environment_gauntlet_version,
     environment_is_development,
     environment_plugin_cache_dir,
-    environment_plugin_data_dir
+    environment_plugin_data_dir,
+    environment_plugin_id,
+    fs_unwatch,
+    fs_watch,
+    process_kill,
+    process_next_event,
+    process_spawn,
+    storage_apply,
+    storage_clear,
+    storage_get,
+    storage_list
 } from "ext:core/ops";
 import type { FC } from "react";
 
@@ -25,6 +36,11 @@ export function showHud(display: string): void {
     return showHudWindow(display)
 }
 
+// entrypointId is undefined if plugin preferences were changed
+export function onPreferencesChanged(listener: (entrypointId: string | undefined) => void): () => void {
+    return addPreferencesChangedListener(listener)
+}
+
 export interface GeneratedEntrypoint {
     name: string
     actions: GeneratedEntrypointAction[]
@@ -71,6 +87,20 @@ export type GeneratorContext<P = object, E = object> = {
 export type CommandContext<P = object, E = object> = {
     pluginPreferences: P,
     entrypointPreferences: E,
+    // values of arguments declared in manifest, keyed by argument id, empty optional arguments are omitted
+    arguments: { [id: string]: string },
+    // selection, clipboard or `gauntlet open --input` content, only present if entrypoint declares matching "accepts_input" in manifest
+    input?: EntrypointInput,
+};
+
+export type ViewProps = {
+    arguments: { [id: string]: string },
+    input?: EntrypointInput,
+};
+
+export type EntrypointInput = {
+    text?: string,
+    image?: ArrayBuffer, // png
 };
 
 export const Clipboard: Clipboard = {
@@ -131,6 +161,9 @@ export const Environment: Environment = {
     get isDevelopment(): boolean {
         return environment_is_development()
     },
+    get pluginId(): string {
+        return environment_plugin_id()
+    },
     get pluginDataDir(): string {
         return environment_plugin_data_dir()
     },
@@ -142,7 +175,164 @@ export const Environment: Environment = {
 export interface Environment {
     get gauntletVersion(): number;
     get isDevelopment(): boolean;
+    get pluginId(): string;
     get pluginDataDir(): string;
     get pluginCacheDir(): string;
 }
 
+// creates gauntlet:// deep link that runs command or opens view entrypoint, params are passed to it as arguments
+// if pluginId is not specified link points to entrypoint of current plugin
+export function entrypointLink(entrypointId: string, params?: { [key: string]: string }, pluginId?: string): string {
+    const plugin = encodeURIComponent(pluginId ?? environment_plugin_id());
+    const entrypoint = encodeURIComponent(entrypointId);
+    const query = new URLSearchParams(params).toString();
+
+    return `gauntlet://${plugin}/${entrypoint}${query ? `?${query}` : ""}`
+}
+
+// calls function exported by other plugin in its manifest, requires plugin id to be listed in "permissions.plugins"
+// arguments and result are serialized as json
+export async function callPluginFunction<R = unknown>(pluginId: string, functionId: string, args?: unknown): Promise<R> {
+    const result = await call_plugin_function(pluginId, functionId, JSON.stringify(args ?? null));
+
+    return JSON.parse(result)
+}
+
+export type PluginFunctionContext = {
+    callerPluginId: string
+}
+
+export interface StorageSetOptions {
+    // entry is removed after specified number of seconds
+    ttlSeconds?: number
+}
+
+export interface StorageTransaction {
+    set(key: string, value: unknown, options?: StorageSetOptions): StorageTransaction
+    delete(key: string): StorageTransaction
+    // applies all operations atomically
+    commit(): Promise<void>
+}
+
+// values are stored as json in per-plugin sqlite database, namespaces separate unrelated data
+export class PluginStorage {
+    readonly #namespace: string
+
+    constructor(namespace: string = "default") {
+        this.#namespace = namespace
+    }
+
+    async get<T = unknown>(key: string): Promise<T | undefined> {
+        const value = await storage_get(this.#namespace, key);
+
+        return value === null ? undefined : JSON.parse(value)
+    }
+
+    async set(key: string, value: unknown, options: StorageSetOptions = {}): Promise<void> {
+        await this.transaction().set(key, value, options).commit()
+    }
+
+    async delete(key: string): Promise<void> {
+        await this.transaction().delete(key).commit()
+    }
+
+    // entries are sorted by key, all entries are returned if prefix is not specified
+    async list<T = unknown>(prefix: string = ""): Promise<{ key: string, value: T }[]> {
+        const entries = await storage_list(this.#namespace, prefix);
+
+        return entries.map(entry => ({ key: entry.key, value: JSON.parse(entry.value) }))
+    }
+
+    async clear(): Promise<void> {
+        await storage_clear(this.#namespace)
+    }
+
+    transaction(): StorageTransaction {
+        const namespace = this.#namespace;
+        const operations: StorageOperation[] = [];
+
+        const transaction: StorageTransaction = {
+            set: (key, value, options = {}) => {
+                operations.push({ type: "Set", key, value: JSON.stringify(value ?? null), ttlSeconds: options.ttlSeconds })
+                return transaction
+            },
+            delete: (key) => {
+                operations.push({ type: "Delete", key })
+                return transaction
+            },
+            commit: () => storage_apply(namespace, operations)
+        };
+
+        return transaction
+    }
+}
+
+export interface FileSystemChange {
+    path: string
+    kind: "Create" | "Modify" | "Delete"
+}
+
+export interface WatchOptions {
+    recursive?: boolean
+}
+
+// requires path to be inside one of paths of "filesystem.read" permission
+// changes are debounced and delivered in batches, returns function that stops watching
+export function watchPath(path: string, listener: (changes: FileSystemChange[]) => void, options: WatchOptions = {}): () => void {
+    const watchId = fs_watch(path, options.recursive ?? true);
+
+    setFileSystemWatchListener(watchId, listener)
+
+    return () => {
+        setFileSystemWatchListener(watchId, undefined)
+        fs_unwatch(watchId)
+    }
+}
+
+export interface SpawnOptions {
+    args?: string[]
+    cwd?: string
+    onStdout?: (chunk: string) => void
+    onStderr?: (chunk: string) => void
+}
+
+export interface SpawnedProcess {
+    // resolves with exit code, which is undefined if process was killed
+    exited: Promise<number | undefined>
+    kill: () => void
+}
+
+// requires "exec" permission for the command
+// process spawned while view is open is killed when view is closed and loading bar is shown while it is running
+export async function spawn(command: string, options: SpawnOptions = {}): Promise<SpawnedProcess> {
+    const id = await process_spawn(command, options.args ?? [], options.cwd);
+
+    const exited = (async () => {
+        while (true) {
+            const event = await process_next_event(id);
+
+            if (event === null) {
+                return undefined
+            }
+
+            switch (event.type) {
+                case "Stdout": {
+                    options.onStdout?.(event.data)
+                    break;
+                }
+                case "Stderr": {
+                    options.onStderr?.(event.data)
+                    break;
+                }
+                case "Exit": {
+                    return event.code ?? undefined
+                }
+            }
+        }
+    })();
+
+    return {
+        exited,
+        kill: () => process_kill(id)
+    }
+}
@@ -1,6 +1,6 @@
 import { ReactNode, useRef, useId, useState, useCallback, useEffect, MutableRefObject, Dispatch, SetStateAction } from 'react';
 // @ts-ignore TODO how to add declaration for this?
-import { useGauntletContext } from "ext:gauntlet/renderer.js";
+import { addPreferencesChangedListener, useGauntletContext } from "ext:gauntlet/renderer.js";
 
 export function useNavigation(): { popView: () => void, pushView: (component: ReactNode) => void } {
     const { popView, pushView }: { popView: () => void, pushView: (component: ReactNode) => void } = useGauntletContext();
@@ -18,15 +18,32 @@ export function useNavigation(): { popView: () => void, pushView: (component: Re
 export function usePluginPreferences<T extends Record<string, any>>(): T {
     const { pluginPreferences }: { pluginPreferences: () => T } = useGauntletContext();
 
+    usePreferencesChanged(changedEntrypointId => changedEntrypointId === undefined);
+
     return pluginPreferences()
 }
 
 export function useEntrypointPreferences<T extends Record<string, any>>(): T {
-    const { entrypointPreferences }: { entrypointPreferences: () => T } = useGauntletContext();
+    const { entrypointPreferences, entrypointId }: { entrypointPreferences: () => T, entrypointId: () => string } = useGauntletContext();
+
+    usePreferencesChanged(changedEntrypointId => changedEntrypointId === entrypointId());
 
     return entrypointPreferences()
 }
 
+// rerenders component when preferences matching the filter are changed
+function usePreferencesChanged(filter: (changedEntrypointId: string | undefined) => boolean) {
+    const [, setVersion] = useState(0);
+
+    useEffect(() => {
+        return addPreferencesChangedListener(changedEntrypointId => {
+            if (filter(changedEntrypointId)) {
+                setVersion(prev => prev + 1)
+            }
+        })
+    }, []);
+}
+
 export type AsyncState<T> = {
     isLoading: boolean;
     error?: unknown;
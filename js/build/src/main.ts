@@ -304,6 +304,7 @@ function packageForLinux(projectRoot: string, arch: string, profile: string): {
 
     const sourceExecutableFilePath = path.join(releaseDirPath, 'gauntlet');
     const sourceDesktopFilePath = path.join(assetsDirPath, 'gauntlet.desktop');
+    const sourceUrlHandlerDesktopFilePath = path.join(assetsDirPath, 'gauntlet-url-handler.desktop');
     const sourceServiceFilePath = path.join(assetsDirPath, 'gauntlet.service');
     const sourceLogoFilePath = path.join(assetsDirPath, 'icon_256.png');
 
@@ -315,6 +316,9 @@ function packageForLinux(projectRoot: string, arch: string, profile: string): {
     const targetDesktopFileName = 'gauntlet.desktop';
     const targetDesktopFilePath = path.join(bundleDir, targetDesktopFileName);
 
+    const targetUrlHandlerDesktopFileName = 'gauntlet-url-handler.desktop';
+    const targetUrlHandlerDesktopFilePath = path.join(bundleDir, targetUrlHandlerDesktopFileName);
+
     const targetServiceFileName = 'gauntlet.service';
     const targetServiceFilePath = path.join(bundleDir, targetServiceFileName);
 
@@ -328,10 +332,11 @@ function packageForLinux(projectRoot: string, arch: string, profile: string): {
 
     copyFileSync(sourceExecutableFilePath, targetExecutableFilePath)
     copyFileSync(sourceDesktopFilePath, targetDesktopFilePath)
+    copyFileSync(sourceUrlHandlerDesktopFilePath, targetUrlHandlerDesktopFilePath)
     copyFileSync(sourceServiceFilePath, targetServiceFilePath)
     copyFileSync(sourceLogoFilePath, targetLogoFilePath)
 
-    spawnWithErrors(`tar`, ['-czvf', archiveFileName, targetExecutableFileName, targetDesktopFileName, targetServiceFileName, targetLogoFileName], {
+    spawnWithErrors(`tar`, ['-czvf', archiveFileName, targetExecutableFileName, targetDesktopFileName, targetUrlHandlerDesktopFileName, targetServiceFileName, targetLogoFileName], {
         cwd: bundleDir
     })
 
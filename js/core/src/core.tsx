@@ -1,16 +1,25 @@
 import type { FC } from "react";
-import { runEntrypointGenerators, runGeneratedEntrypoint, runGeneratedEntrypointAction } from "./entrypoint-generator";
+import {
+    rerunEntrypointGenerators,
+    runEntrypointGenerators,
+    runGeneratedEntrypoint,
+    runGeneratedEntrypointAction
+} from "./entrypoint-generator";
 import { reloadSearchIndex } from "./search-index";
-import { closeView, handleEvent, handlePluginViewKeyboardEvent, renderInlineView, renderView } from "./render";
+import { closeView, EntrypointInput, handleEvent, handlePluginViewKeyboardEvent, renderInlineView, renderView } from "./render";
+import { notifyFileSystemWatch, notifyPreferencesChanged } from "ext:gauntlet/renderer.js";
 import {
     entrypoint_preferences_required,
+    get_entrypoint_generator_entrypoint_ids,
     get_entrypoint_preferences,
     get_plugin_preferences,
     op_entrypoint_names,
     op_inline_view_entrypoint_id,
     op_log_trace,
     op_plugin_get_pending_event,
+    plugin_function_call_finished,
     plugin_preferences_required,
+    scheduled_run_finished,
     show_plugin_error_view,
     show_preferences_required_view,
     synchronize_event
@@ -86,8 +95,8 @@ export async function runPluginLoop() {
                         break;
                     }
 
-                    const view: FC = (await import(`gauntlet:entrypoint?${entrypointId}`)).default;
-                    renderView(entrypointId, getEntrypointName(entrypointId), view)
+                    const view: FC<{ arguments: { [id: string]: string }, input?: EntrypointInput }> = (await import(`gauntlet:entrypoint?${entrypointId}`)).default;
+                    renderView(entrypointId, getEntrypointName(entrypointId), view, pluginEvent.arguments, toEntrypointInput(pluginEvent.input))
                 } catch (e) {
                     console.error("Error occurred when rendering view", entrypointId, e)
                     show_plugin_error_view(entrypointId, "View")
@@ -107,13 +116,15 @@ export async function runPluginLoop() {
                     type CommandContext<P = object, E = object> = {
                         pluginPreferences: P,
                         entrypointPreferences: E,
+                        arguments: { [id: string]: string },
+                        input?: EntrypointInput,
                     };
 
                     const pluginPreferences = get_plugin_preferences();
                     const entrypointPreferences = get_entrypoint_preferences(pluginEvent.entrypointId);
 
                     const command: (context: CommandContext) => Promise<void> | void = (await import(`gauntlet:entrypoint?${pluginEvent.entrypointId}`)).default;
-                    command({ pluginPreferences, entrypointPreferences })
+                    command({ pluginPreferences, entrypointPreferences, arguments: pluginEvent.arguments, input: toEntrypointInput(pluginEvent.input) })
                 } catch (e) {
                     console.error("Error occurred when running a command", pluginEvent.entrypointId, e)
                 }
@@ -150,10 +161,105 @@ export async function runPluginLoop() {
                 reloadSearchIndex(false)
                 break;
             }
+            case "PreferencesChanged": {
+                const entrypointId = pluginEvent.entrypointId ?? undefined;
+
+                notifyPreferencesChanged(entrypointId)
+
+                // generators receive preferences only when run, so rerun the ones affected by the change
+                const generatorEntrypointIds = await get_entrypoint_generator_entrypoint_ids();
+                const affectedGeneratorEntrypointIds = entrypointId === undefined
+                    ? generatorEntrypointIds
+                    : generatorEntrypointIds.filter(id => id === entrypointId);
+
+                if (affectedGeneratorEntrypointIds.length > 0) {
+                    // noinspection ES6MissingAwait
+                    rerunEntrypointGenerators(affectedGeneratorEntrypointIds)
+                }
+                break;
+            }
+            case "RunScheduledEntrypoint": {
+                // noinspection ES6MissingAwait
+                runScheduledEntrypoint(pluginEvent.entrypointId)
+                break;
+            }
+            case "CallPluginFunction": {
+                // noinspection ES6MissingAwait
+                callPluginFunction(pluginEvent.callId, pluginEvent.callerPluginId, pluginEvent.entrypointId, pluginEvent.functionId, pluginEvent.args)
+                break;
+            }
+            case "FileSystemWatch": {
+                notifyFileSystemWatch(pluginEvent.watchId, pluginEvent.changes)
+                break;
+            }
+        }
+    }
+}
+
+async function runScheduledEntrypoint(entrypointId: string) {
+    const reportResult = (error: unknown | undefined) => {
+        // noinspection ES6MissingAwait
+        scheduled_run_finished(entrypointId, error === undefined ? undefined : String(error))
+    };
+
+    // scheduled runs happen in background, so preferences are not asked for
+    if (await checkRequiredPreferences(entrypointId)) {
+        reportResult("Required preferences are not set")
+        return
+    }
+
+    const generatorEntrypointIds = await get_entrypoint_generator_entrypoint_ids();
+
+    if (generatorEntrypointIds.includes(entrypointId)) {
+        await rerunEntrypointGenerators([entrypointId], (_, error) => reportResult(error))
+    } else {
+        try {
+            const pluginPreferences = get_plugin_preferences();
+            const entrypointPreferences = get_entrypoint_preferences(entrypointId);
+
+            const command: (context: { pluginPreferences: object, entrypointPreferences: object, arguments: { [id: string]: string } }) => Promise<void> | void = (await import(`gauntlet:entrypoint?${entrypointId}`)).default;
+            await command({ pluginPreferences, entrypointPreferences, arguments: {} })
+
+            reportResult(undefined)
+        } catch (e) {
+            console.error("Error occurred when running a scheduled command", entrypointId, e)
+            reportResult(e)
         }
     }
 }
 
+async function callPluginFunction(callId: string, callerPluginId: string, entrypointId: string, functionId: string, args: string) {
+    try {
+        const module = await import(`gauntlet:entrypoint?${entrypointId}`);
+        const func: (args: unknown, context: { callerPluginId: string }) => Promise<unknown> | unknown = module[functionId];
+
+        if (typeof func !== "function") {
+            throw new Error(`Entrypoint '${entrypointId}' doesn't export function '${functionId}'`)
+        }
+
+        const result = await func(JSON.parse(args), { callerPluginId });
+
+        // noinspection ES6MissingAwait
+        plugin_function_call_finished(callId, result === undefined ? undefined : JSON.stringify(result), undefined)
+    } catch (e) {
+        console.error("Error occurred when running a plugin function", functionId, e)
+
+        // noinspection ES6MissingAwait
+        plugin_function_call_finished(callId, undefined, String(e))
+    }
+}
+
+function toEntrypointInput(input: EntrypointInputData | null): EntrypointInput | undefined {
+    if (input == null) {
+        return undefined
+    }
+
+    return {
+        text: input.text ?? undefined,
+        image: input.png_data ? new Uint8Array(input.png_data).buffer : undefined,
+    }
+}
+
 function getEntrypointName(entrypointId: string): string {
     const entrypointNames = op_entrypoint_names();
     const entrypointName = entrypointNames[entrypointId];
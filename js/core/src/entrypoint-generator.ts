@@ -7,7 +7,7 @@ import {
     get_plugin_preferences,
     get_entrypoint_preferences
 } from "ext:core/ops";
-import { reloadSearchIndex } from "./search-index";
+import { reloadSearchIndex, updateSearchIndex } from "./search-index";
 import type { FC } from "react";
 import { renderView } from "./render";
 
@@ -68,6 +68,8 @@ interface GeneratedEntrypointDerivedActionView {
 }
 
 
+type GeneratorFinishCallback = (generatorEntrypointId: string, error: unknown | undefined) => void
+
 type ProcessedGeneratedEntrypoints = { [lookupEntrypointId: string]: ProcessedGeneratedEntrypoint };
 type GeneratorCleanups = { [generatorEntrypointId: string]: () => (void | Promise<void>) };
 
@@ -90,116 +92,163 @@ export async function runEntrypointGenerators(): Promise<void> {
 
     const entrypointIds = await get_entrypoint_generator_entrypoint_ids();
     for (const generatorEntrypointId of entrypointIds) {
-        try {
-            const generator: Generator = (await import(`gauntlet:entrypoint?${generatorEntrypointId}`)).default;
+        await runEntrypointGenerator(generatorEntrypointId)
+    }
+}
 
-            op_log_info("entrypoint_generator", `Running entrypoint generator entrypoint ${generatorEntrypointId}`)
+export async function rerunEntrypointGenerators(generatorEntrypointIds: string[], onFinish?: GeneratorFinishCallback): Promise<void> {
+    for (const generatorEntrypointId of generatorEntrypointIds) {
+        const cleanup = generatorCleanups[generatorEntrypointId];
+        if (cleanup) {
+            try {
+                await cleanup()
+            } catch (err) {
+                console.error(`Error occurred when calling cleanup function of generator entrypoint: ${generatorEntrypointId}`, err)
+            }
+            delete generatorCleanups[generatorEntrypointId]
+        }
 
-            const add = (id: string, data: GeneratedEntrypoint) => {
-                op_log_info("entrypoint_generator", `Adding entry '${id}' by entrypoint generator entrypoint '${generatorEntrypointId}'`)
+        for (const [lookupId, value] of Object.entries(storedGeneratedEntrypoints)) {
+            if (value.generatorEntrypointId === generatorEntrypointId) {
+                delete storedGeneratedEntrypoints[lookupId]
+            }
+        }
+    }
 
-                if (data.actions.length < 1) {
-                    throw new Error(`Error when adding entry '${id}': at least one action should be provided`)
-                }
+    await reloadSearchIndex(true)
 
-                const derivedActions: GeneratedEntrypointDerivedAction[] = []
-                for (const action of data.actions) {
-                    const label = action.label;
-
-                    const run = "run" in action;
-                    const view = "view" in action;
-
-                    if (run && view) {
-                        throw new Error(`only one of 'run' or 'view' properties can be specified in action: '${label}'`)
-                    }
-
-                    if (!run && !view) {
-                        throw new Error(`one of 'run' or 'view' properties has to be specified in action: '${label}'`)
-                    }
-
-                    if (run) {
-                        derivedActions.push({
-                            type: "Command",
-                            ref: action.ref,
-                            label: action.label,
-                            run: action.run,
-                        })
-                    } else if (view) {
-                        derivedActions.push({
-                            type: "View",
-                            ref: action.ref,
-                            label: action.label,
-                            view: action.view,
-                        })
-                    }
-                }
+    for (const generatorEntrypointId of generatorEntrypointIds) {
+        await runEntrypointGenerator(generatorEntrypointId, onFinish)
+    }
+}
 
-                const lookupId = generatorEntrypointId + ":" + id;
+async function runEntrypointGenerator(generatorEntrypointId: string, onFinish?: GeneratorFinishCallback): Promise<void> {
+    try {
+        const generator: Generator = (await import(`gauntlet:entrypoint?${generatorEntrypointId}`)).default;
 
-                storedGeneratedEntrypoints[lookupId] = {
-                    generatorEntrypointId: generatorEntrypointId,
-                    id: id,
-                    uuid: crypto.randomUUID(),
-                    command: data,
-                    derivedActions,
-                }
+        op_log_info("entrypoint_generator", `Running entrypoint generator entrypoint ${generatorEntrypointId}`)
+
+        const add = (id: string, data: GeneratedEntrypoint) => {
+            op_log_info("entrypoint_generator", `Adding entry '${id}' by entrypoint generator entrypoint '${generatorEntrypointId}'`)
 
-                reloadSearchIndex(true)
+            if (data.actions.length < 1) {
+                throw new Error(`Error when adding entry '${id}': at least one action should be provided`)
             }
-            const remove = (id: string) => {
-                op_log_info("entrypoint_generator", `Removing entry '${id}' by entrypoint generator entrypoint '${generatorEntrypointId}'`)
-                const lookupId = generatorEntrypointId + ":" + id;
 
-                delete storedGeneratedEntrypoints[lookupId]
+            const derivedActions: GeneratedEntrypointDerivedAction[] = []
+            for (const action of data.actions) {
+                const label = action.label;
 
-                reloadSearchIndex(true)
-            }
+                const run = "run" in action;
+                const view = "view" in action;
 
-            const get = (id: string) => {
-                op_log_debug("entrypoint_generator", `Getting entry '${id}' by entrypoint generator entrypoint '${generatorEntrypointId}'`)
-                const lookupId = generatorEntrypointId + ":" + id;
+                if (run && view) {
+                    throw new Error(`only one of 'run' or 'view' properties can be specified in action: '${label}'`)
+                }
+
+                if (!run && !view) {
+                    throw new Error(`one of 'run' or 'view' properties has to be specified in action: '${label}'`)
+                }
 
-                const generatedEntrypoint = storedGeneratedEntrypoints[lookupId];
-                if (generatedEntrypoint) {
-                    return generatedEntrypoint.command
-                } else {
-                    return undefined
+                if (run) {
+                    derivedActions.push({
+                        type: "Command",
+                        ref: action.ref,
+                        label: action.label,
+                        run: action.run,
+                    })
+                } else if (view) {
+                    derivedActions.push({
+                        type: "View",
+                        ref: action.ref,
+                        label: action.label,
+                        view: action.view,
+                    })
                 }
             }
 
-            const getAll = (): { [id: string]: GeneratedEntrypoint } => {
-                op_log_debug("entrypoint_generator", `Getting all entries by entrypoint generator entrypoint '${generatorEntrypointId}'`)
+            const lookupId = generatorEntrypointId + ":" + id;
 
-                return Object.fromEntries(
-                    Object.entries(storedGeneratedEntrypoints)
-                        .map(([_lookupId, value]) => [value.id, value.command])
-                )
+            storedGeneratedEntrypoints[lookupId] = {
+                generatorEntrypointId: generatorEntrypointId,
+                id: id,
+                uuid: crypto.randomUUID(),
+                command: data,
+                derivedActions,
             }
 
-            const pluginPreferences = get_plugin_preferences();
-            const entrypointPreferences = get_entrypoint_preferences(generatorEntrypointId);
-
-            // noinspection ES6MissingAwait
-            (async () => {
-                try {
-                    update_loading_bar(generatorEntrypointId, true)
-                    let cleanup = await generator({ add, remove, get, getAll, pluginPreferences, entrypointPreferences })
-                    update_loading_bar(generatorEntrypointId, false)
-                    if (typeof cleanup === "function") {
-                        generatorCleanups[generatorEntrypointId] = cleanup
-                    }
-                } catch (e) {
-                    console.error(`Error occurred when calling entrypoint generator for entrypoint: ${generatorEntrypointId}`, e)
-                }
-            })()
-        } catch (e) {
-            console.error(`Error occurred when importing entrypoint generator for entrypoint: ${generatorEntrypointId}`, e)
+            updateSearchIndex(lookupId)
+        }
+        const remove = (id: string) => {
+            op_log_info("entrypoint_generator", `Removing entry '${id}' by entrypoint generator entrypoint '${generatorEntrypointId}'`)
+            const lookupId = generatorEntrypointId + ":" + id;
+
+            delete storedGeneratedEntrypoints[lookupId]
+
+            updateSearchIndex(lookupId)
+        }
+
+        const get = (id: string) => {
+            op_log_debug("entrypoint_generator", `Getting entry '${id}' by entrypoint generator entrypoint '${generatorEntrypointId}'`)
+            const lookupId = generatorEntrypointId + ":" + id;
+
+            const generatedEntrypoint = storedGeneratedEntrypoints[lookupId];
+            if (generatedEntrypoint) {
+                return generatedEntrypoint.command
+            } else {
+                return undefined
+            }
+        }
+
+        const getAll = (): { [id: string]: GeneratedEntrypoint } => {
+            op_log_debug("entrypoint_generator", `Getting all entries by entrypoint generator entrypoint '${generatorEntrypointId}'`)
+
+            return Object.fromEntries(
+                Object.entries(storedGeneratedEntrypoints)
+                    .map(([_lookupId, value]) => [value.id, value.command])
+            )
         }
+
+        const pluginPreferences = get_plugin_preferences();
+        const entrypointPreferences = get_entrypoint_preferences(generatorEntrypointId);
+
+        // noinspection ES6MissingAwait
+        (async () => {
+            try {
+                update_loading_bar(generatorEntrypointId, true)
+                let cleanup = await generator({ add, remove, get, getAll, pluginPreferences, entrypointPreferences })
+                update_loading_bar(generatorEntrypointId, false)
+                if (typeof cleanup === "function") {
+                    generatorCleanups[generatorEntrypointId] = cleanup
+                }
+                onFinish?.(generatorEntrypointId, undefined)
+            } catch (e) {
+                console.error(`Error occurred when calling entrypoint generator for entrypoint: ${generatorEntrypointId}`, e)
+                onFinish?.(generatorEntrypointId, e)
+            }
+        })()
+    } catch (e) {
+        console.error(`Error occurred when importing entrypoint generator for entrypoint: ${generatorEntrypointId}`, e)
+        onFinish?.(generatorEntrypointId, e)
     }
 }
 
 export function generatedEntrypointSearchIndex(): GeneratedSearchItem[] {
-    return Object.entries(storedGeneratedEntrypoints).map(([entrypointLookupId, value]) => ({
+    return Object.entries(storedGeneratedEntrypoints).map(([entrypointLookupId, value]) => toSearchItem(entrypointLookupId, value))
+}
+
+export function generatedEntrypointSearchItem(entrypointLookupId: string): GeneratedSearchItem | undefined {
+    const value = storedGeneratedEntrypoints[entrypointLookupId];
+
+    if (value) {
+        return toSearchItem(entrypointLookupId, value)
+    } else {
+        return undefined
+    }
+}
+
+function toSearchItem(entrypointLookupId: string, value: ProcessedGeneratedEntrypoint): GeneratedSearchItem {
+    return {
         generator_entrypoint_id: value.generatorEntrypointId,
         entrypoint_id: entrypointLookupId,
         entrypoint_uuid: value.uuid,
@@ -212,7 +261,7 @@ export function generatedEntrypointSearchIndex(): GeneratedSearchItem[] {
                 label: action.label
             })),
         entrypoint_accessories: value.command.accessories || []
-    }))
+    }
 }
 
 export async function runGeneratedEntrypointAction(entrypointId: string, key: string, modifierShift: boolean, modifierControl: boolean, modifierAlt: boolean, modifierMeta: boolean) {
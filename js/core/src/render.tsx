@@ -10,8 +10,12 @@ import type { FC } from "react";
 
 let latestRootUiWidget: UiWidget | undefined = undefined
 
-export function renderView(entrypointId: string, entrypointName: string, View: FC) {
-    latestRootUiWidget = render(entrypointId, entrypointName, "View", <View/>);
+export type EntrypointInput = { text?: string, image?: ArrayBuffer }
+
+type ViewProps = { arguments: { [id: string]: string }, input?: EntrypointInput }
+
+export function renderView(entrypointId: string, entrypointName: string, View: FC<ViewProps>, args: { [id: string]: string } = {}, input?: EntrypointInput) {
+    latestRootUiWidget = render(entrypointId, entrypointName, "View", <View arguments={args} input={input}/>);
 }
 
 export function renderInlineView(entrypointId: string, entrypointName: string, Handler: FC<{ text: string }>, text: string) {
@@ -79,23 +83,7 @@ export function handleEvent(event: ViewEvent) {
             if (property) {
                 if (typeof property === "function") {
 
-                    const eventArgs = event.eventArguments
-                        .map(arg => {
-                            switch (arg.type) {
-                                case "Undefined": {
-                                    return undefined
-                                }
-                                case "String": {
-                                    return arg.value
-                                }
-                                case "Number": {
-                                    return arg.value
-                                }
-                                case "Bool": {
-                                    return arg.value
-                                }
-                            }
-                        });
+                    const eventArgs = event.eventArguments.map(propertyValueToJs);
 
                     op_log_trace("plugin_event_handler", `Calling handler with arguments ${Deno.inspect(eventArgs)}`);
 
@@ -117,6 +105,31 @@ export function handleEvent(event: ViewEvent) {
     }
 }
 
+function propertyValueToJs(arg: PropertyValue): any {
+    switch (arg.type) {
+        case "Undefined": {
+            return undefined
+        }
+        case "String": {
+            return arg.value
+        }
+        case "Number": {
+            return arg.value
+        }
+        case "Bool": {
+            return arg.value
+        }
+        case "Array": {
+            return arg.values.map(propertyValueToJs)
+        }
+        case "Object": {
+            return Object.fromEntries(
+                Object.entries(arg.values).map(([key, value]) => [key, propertyValueToJs(value)])
+            )
+        }
+    }
+}
+
 function findWidgetWithId(widget: UiWidget, widgetId: number): UiWidget | undefined {
     if (widget.widgetId === widgetId) {
         return widget
@@ -1,6 +1,47 @@
-import { generatedEntrypointSearchIndex } from "./entrypoint-generator";
-import { reload_search_index } from "ext:core/ops";
+import { generatedEntrypointSearchIndex, generatedEntrypointSearchItem } from "./entrypoint-generator";
+import { reload_search_index, update_search_index } from "ext:core/ops";
+
+let changedEntrypointIds = new Set<string>()
 
 export async function reloadSearchIndex(refreshSearchList: boolean) {
+    // full reload already includes all pending changes
+    changedEntrypointIds.clear()
+
     await reload_search_index(generatedEntrypointSearchIndex(), refreshSearchList);
-}
\ No newline at end of file
+}
+
+// changes done in the same tick, e.g. when generator adds entrypoints in a loop, are sent together
+export function updateSearchIndex(entrypointId: string) {
+    if (changedEntrypointIds.size === 0) {
+        queueMicrotask(flushSearchIndexUpdate)
+    }
+
+    changedEntrypointIds.add(entrypointId)
+}
+
+async function flushSearchIndexUpdate() {
+    const entrypointIds = [...changedEntrypointIds];
+    changedEntrypointIds.clear()
+
+    if (entrypointIds.length === 0) {
+        return
+    }
+
+    const upserted: GeneratedSearchItem[] = []
+    const removed: string[] = []
+
+    for (const entrypointId of entrypointIds) {
+        const searchItem = generatedEntrypointSearchItem(entrypointId);
+        if (searchItem) {
+            upserted.push(searchItem)
+        } else {
+            removed.push(entrypointId)
+        }
+    }
+
+    try {
+        await update_search_index(upserted, removed)
+    } catch (e) {
+        console.error("Error occurred when updating search index", e)
+    }
+}
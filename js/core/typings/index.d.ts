@@ -3,6 +3,7 @@ declare module "ext:gauntlet/renderer.js" {
 
     export const render: (entrypointId: string, entrypointName: string, renderLocation: RenderLocation, component: ReactNode) => UiWidget;
     export const clearRenderer: () => void;
+    export const notifyPreferencesChanged: (entrypointId: string | undefined) => void;
 }
 
 declare module "gauntlet:core" {
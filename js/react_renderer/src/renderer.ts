@@ -138,6 +138,55 @@ export function showHudWindow(display: string): void {
     show_hud(display)
 }
 
+type PreferencesChangedListener = (entrypointId: string | undefined) => void
+
+const preferencesChangedListeners = new Set<PreferencesChangedListener>()
+
+export function addPreferencesChangedListener(listener: PreferencesChangedListener): () => void {
+    preferencesChangedListeners.add(listener)
+
+    return () => {
+        preferencesChangedListeners.delete(listener)
+    }
+}
+
+export function notifyPreferencesChanged(entrypointId: string | undefined): void {
+    for (const listener of preferencesChangedListeners) {
+        try {
+            listener(entrypointId)
+        } catch (e) {
+            console.error("Error occurred when calling preferences changed listener", e)
+        }
+    }
+}
+
+type FileSystemWatchListener = (changes: FileSystemChange[]) => void
+
+const fileSystemWatchListeners = new Map<number, FileSystemWatchListener>()
+
+export function setFileSystemWatchListener(watchId: number, listener: FileSystemWatchListener | undefined): void {
+    if (listener) {
+        fileSystemWatchListeners.set(watchId, listener)
+    } else {
+        fileSystemWatchListeners.delete(watchId)
+    }
+}
+
+export function notifyFileSystemWatch(watchId: number, changes: FileSystemChange[]): void {
+    const listener = fileSystemWatchListeners.get(watchId);
+
+    // changes may still arrive shortly after path was unwatched
+    if (!listener) {
+        return
+    }
+
+    try {
+        listener(changes)
+    } catch (e) {
+        console.error("Error occurred when calling file system watch listener", e)
+    }
+}
+
 function createWidget(hostContext: HostContext, type: ComponentType, properties: Props, children: UiWidget[] = []): Instance {
     const props = Object.fromEntries(
         Object.entries(properties)
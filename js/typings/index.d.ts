@@ -45,7 +45,7 @@ type MacOSDesktopSettings13AndPostData = {
     icon: ArrayBuffer | undefined,
 }
 
-type PluginEvent = ViewEvent | NotReactsKeyboardEvent | RunCommand | RunGeneratedEntrypoint | OpenView | CloseView | OpenInlineView | RefreshSearchIndex
+type PluginEvent = ViewEvent | NotReactsKeyboardEvent | RunCommand | RunGeneratedEntrypoint | OpenView | CloseView | OpenInlineView | RefreshSearchIndex | PreferencesChanged | RunScheduledEntrypoint | CallPluginFunction | FileSystemWatch
 type RenderLocation = "InlineView" | "View"
 
 type ViewEvent = {
@@ -72,6 +72,8 @@ type NotReactsKeyboardEvent = {
 type OpenView = {
     type: "OpenView"
     entrypointId: string
+    arguments: { [id: string]: string }
+    input: EntrypointInputData | null
 }
 
 type CloseView = {
@@ -81,6 +83,13 @@ type CloseView = {
 type RunCommand = {
     type: "RunCommand"
     entrypointId: string
+    arguments: { [id: string]: string }
+    input: EntrypointInputData | null
+}
+
+type EntrypointInputData = {
+    text: string | null
+    png_data: number[] | null
 }
 
 type RunGeneratedEntrypoint = {
@@ -98,10 +107,52 @@ type RefreshSearchIndex = {
     type: "RefreshSearchIndex"
 }
 
-type PropertyValue = PropertyValueString | PropertyValueNumber | PropertyValueBool | PropertyValueUndefined
+type PreferencesChanged = {
+    type: "PreferencesChanged"
+    entrypointId: string | null
+}
+
+type RunScheduledEntrypoint = {
+    type: "RunScheduledEntrypoint"
+    entrypointId: string
+}
+
+type CallPluginFunction = {
+    type: "CallPluginFunction"
+    callId: string
+    callerPluginId: string
+    entrypointId: string
+    functionId: string
+    args: string
+}
+
+type FileSystemWatch = {
+    type: "FileSystemWatch"
+    watchId: number
+    changes: FileSystemChange[]
+}
+
+type FileSystemChange = {
+    path: string
+    kind: "Create" | "Modify" | "Delete"
+}
+
+type ProcessEvent = ProcessEventStdout | ProcessEventStderr | ProcessEventExit
+type ProcessEventStdout = { type: "Stdout", data: string }
+type ProcessEventStderr = { type: "Stderr", data: string }
+type ProcessEventExit = { type: "Exit", code: number | null }
+
+type StorageEntry = { key: string, value: string }
+type StorageOperation = StorageOperationSet | StorageOperationDelete
+type StorageOperationSet = { type: "Set", key: string, value: string, ttlSeconds: number | undefined }
+type StorageOperationDelete = { type: "Delete", key: string }
+
+type PropertyValue = PropertyValueString | PropertyValueNumber | PropertyValueBool | PropertyValueArray | PropertyValueObject | PropertyValueUndefined
 type PropertyValueString = { type: "String", value: string }
 type PropertyValueNumber = { type: "Number", value: number }
 type PropertyValueBool = { type: "Bool", value: boolean }
+type PropertyValueArray = { type: "Array", values: PropertyValue[] }
+type PropertyValueObject = { type: "Object", values: { [key: string]: PropertyValue } }
 type PropertyValueUndefined = { type: "Undefined" }
 
 type UiWidget = {
@@ -230,6 +281,22 @@ declare module "ext:core/ops" {
     function hide_window(): void;
 
     function get_entrypoint_generator_entrypoint_ids(): Promise<string[]>
+    function scheduled_run_finished(entrypointId: string, error: string | undefined): Promise<void>
+
+    function call_plugin_function(pluginId: string, functionId: string, args: string): Promise<string>
+    function plugin_function_call_finished(callId: string, result: string | undefined, error: string | undefined): Promise<void>
+
+    function process_spawn(command: string, args: string[], cwd: string | undefined): Promise<number>
+    function process_next_event(id: number): Promise<ProcessEvent | null>
+    function process_kill(id: number): void
+
+    function storage_get(namespace: string, key: string): Promise<string | null>
+    function storage_list(namespace: string, prefix: string): Promise<StorageEntry[]>
+    function storage_clear(namespace: string): Promise<void>
+    function storage_apply(namespace: string, operations: StorageOperation[]): Promise<void>
+
+    function fs_watch(path: string, recursive: boolean): number
+    function fs_unwatch(id: number): void
 
     function get_plugin_preferences(): Record<string, any>;
     function get_entrypoint_preferences(entrypointId: string): Record<string, any>;
@@ -238,6 +305,7 @@ declare module "ext:core/ops" {
     function show_preferences_required_view(entrypointId: string, pluginPreferencesRequired: boolean, entrypointPreferencesRequired: boolean): void;
 
     function reload_search_index(searchItems: GeneratedSearchItem[], refreshSearchList: boolean): Promise<void>;
+    function update_search_index(upsertedSearchItems: GeneratedSearchItem[], removedEntrypointIds: string[]): Promise<void>;
 
     function show_hud(display: string): void;
     function update_loading_bar(entrypoint_id: string, show: boolean): void;
@@ -255,6 +323,7 @@ declare module "ext:core/ops" {
 
     function environment_gauntlet_version(): number;
     function environment_is_development(): boolean;
+    function environment_plugin_id(): string;
     function environment_plugin_data_dir(): string;
     function environment_plugin_cache_dir(): string;
 }
@@ -331,7 +400,7 @@ type ComponentRef = {
     componentName: string,
 }
 
-type PropertyType = TypeString | TypeNumber | TypeBoolean | TypeComponent | TypeFunction | TypeSharedTypeRef | TypeImageArray | TypeImageUnion
+type PropertyType = TypeString | TypeNumber | TypeBoolean | TypeComponent | TypeFunction | TypeSharedTypeRef | TypeImageArray | TypeImageUnion | TypeRecord
 
 type TypeString = {
     type: "string"
@@ -362,6 +431,10 @@ type TypeImageArray = {
     type: "array"
     item: PropertyType
 }
+type TypeRecord = {
+    type: "record"
+    value: PropertyType
+}
 
 type WaylandApplicationEvent = WaylandApplicationEventWindowOpened
     | WaylandApplicationEventWindowClosed
@@ -93,6 +93,7 @@
         if isLinux
         then ''
           install -Dm644 assets/linux/gauntlet.desktop $out/share/applications/gauntlet.desktop
+          install -Dm644 assets/linux/gauntlet-url-handler.desktop $out/share/applications/gauntlet-url-handler.desktop
           install -Dm644 assets/linux/gauntlet.service $out/lib/systemd/user/gauntlet.service
           install -Dm644 assets/linux/icon_256.png $out/share/icons/hicolor/256x256/apps/gauntlet.png
         ''
@@ -5,6 +5,7 @@ use anyhow::Context;
 use clap::Parser;
 use gauntlet_client::open_window;
 use gauntlet_management_client::start_management_client;
+use gauntlet_server::open_deep_link;
 use gauntlet_server::run_action;
 use gauntlet_server::start;
 
@@ -28,7 +29,12 @@ struct Cli {
 #[derive(Debug, clap::Subcommand)]
 enum Commands {
     /// Open Gauntlet window
-    Open,
+    Open {
+        /// Text passed as input to entrypoints which accept it, those entrypoints are listed first in search results.
+        /// If not specified, primary selection (on X11) or clipboard content is used instead
+        #[arg(long)]
+        input: Option<String>,
+    },
     /// Open Gauntlet settings
     Settings,
     /// Run action (only ones visible in main window search results) of specific entrypoint of specific plugin
@@ -45,6 +51,12 @@ enum Commands {
         /// `:secondary` (action run with Shift+Enter shortcut)
         action_id: String,
     },
+    /// Open deep link, which opens view of specific entrypoint or shows its arguments, filled with link parameters, for user to confirm.
+    /// Used by operating system when handling `gauntlet://` links
+    OpenLink {
+        /// Link in format `gauntlet://<plugin-id>/<entrypoint-id>?key=value`, where plugin id is percent-encoded
+        link: String,
+    },
 }
 
 pub fn init() {
@@ -79,7 +91,7 @@ pub fn init() {
         }
         Some(command) => {
             match command {
-                Commands::Open => open_window(),
+                Commands::Open { input } => open_window(input),
                 Commands::Settings => start_management_client(),
                 Commands::Run {
                     plugin_id,
@@ -88,6 +100,9 @@ pub fn init() {
                 } => {
                     run_action(plugin_id, entrypoint_id, action_id);
                 }
+                Commands::OpenLink { link } => {
+                    open_deep_link(link);
+                }
             };
         }
     }
@@ -76,6 +76,30 @@ fn main() -> anyhow::Result<()> {
                                     ));
                                 }
                             }
+                            PropertyType::Array { ref item } => {
+                                let PropertyType::String = item.as_ref() else {
+                                    panic!("not yet supported")
+                                };
+
+                                if arg.optional {
+                                    output.push_str(&format!("            {}.map(|{}| gauntlet_common::model::UiPropertyValue::Array({}.into_iter().map(gauntlet_common::model::UiPropertyValue::String).collect())).unwrap_or_else(|| gauntlet_common::model::UiPropertyValue::Undefined),\n", arg.name, arg.name, arg.name));
+                                } else {
+                                    output.push_str(&format!(
+                                        "            gauntlet_common::model::UiPropertyValue::Array({}.into_iter().map(gauntlet_common::model::UiPropertyValue::String).collect()),\n",
+                                        arg.name
+                                    ));
+                                }
+                            }
+                            PropertyType::Record { .. } => {
+                                if arg.optional {
+                                    output.push_str(&format!("            {}.map(gauntlet_common::model::UiPropertyValue::Object).unwrap_or_else(|| gauntlet_common::model::UiPropertyValue::Undefined),\n", arg.name));
+                                } else {
+                                    output.push_str(&format!(
+                                        "            gauntlet_common::model::UiPropertyValue::Object({}),\n",
+                                        arg.name
+                                    ));
+                                }
+                            }
                             _ => {
                                 panic!("not yet supported")
                             }
@@ -138,5 +162,9 @@ fn generate_required_type(property_type: &PropertyType, union_name: Option<Strin
             }
         }
         PropertyType::Array { item } => format!("Vec<{}>", generate_required_type(item, union_name)),
+        // values of the record are collected from the widget state so their type is only known at runtime
+        PropertyType::Record { .. } => {
+            "std::collections::HashMap<String, gauntlet_common::model::UiPropertyValue>".to_owned()
+        }
     }
 }
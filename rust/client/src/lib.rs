@@ -21,7 +21,7 @@ pub fn start_client(
     ui::run(minimized, frontend_receiver, backend_sender);
 }
 
-pub fn open_window() {
+pub fn open_window(input: Option<String>) {
     tokio::runtime::Builder::new_current_thread()
         .enable_all()
         .build()
@@ -33,7 +33,7 @@ pub fn open_window() {
                 Ok(mut backend_api) => {
                     tracing::info!("Server is already running, opening window...");
 
-                    backend_api.show_window().await.expect("Unknown error")
+                    backend_api.show_window(input).await.expect("Unknown error")
                 }
                 Err(_) => {
                     tracing::error!("Unable to connect to server. Please check if you have Gauntlet running on your PC")
@@ -160,6 +160,22 @@ impl ClientContext {
         self.view.focus_search_bar(widget_id)
     }
 
+    pub fn focus_form_field(&self, widget_id: UiWidgetId) -> Task<AppMsg> {
+        self.view.focus_form_field(widget_id)
+    }
+
+    pub fn submit_form(&self) -> Task<AppMsg> {
+        self.view.submit_form()
+    }
+
+    pub fn has_search_bar_dropdown(&self) -> bool {
+        self.view.has_search_bar_dropdown()
+    }
+
+    pub fn select_next_search_bar_dropdown_item(&self, reverse: bool) -> Task<AppMsg> {
+        self.view.select_next_search_bar_dropdown_item(reverse)
+    }
+
     pub fn toggle_action_panel(&mut self) {
         self.view.toggle_action_panel()
     }
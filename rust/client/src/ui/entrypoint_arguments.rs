@@ -0,0 +1,93 @@
+use std::fmt::Display;
+
+use gauntlet_common::model::SearchResultEntrypointArgument;
+use gauntlet_common::model::SearchResultEntrypointArgumentKind;
+use iced::widget::pick_list;
+use iced::widget::row;
+use iced::widget::text_input;
+use iced::Alignment;
+use iced::Length;
+
+use crate::ui::theme::pick_list::PickListStyle;
+use crate::ui::theme::text_input::TextInputStyle;
+use crate::ui::theme::Element;
+use crate::ui::theme::ThemableWidget;
+
+const ARGUMENT_FIELD_WIDTH: f32 = 140.0;
+
+#[derive(Debug, Clone)]
+pub enum EntrypointArgumentEvent {
+    Changed { index: usize, value: String },
+    Submit,
+}
+
+pub fn entrypoint_arguments<'a>(
+    arguments: &'a [SearchResultEntrypointArgument],
+    values: &'a [String],
+    field_ids: &[text_input::Id],
+) -> Element<'a, EntrypointArgumentEvent> {
+    let fields: Vec<Element<_>> = arguments
+        .iter()
+        .zip(values)
+        .zip(field_ids)
+        .enumerate()
+        .map(|(index, ((argument, value), field_id))| {
+            let placeholder = if argument.required {
+                argument.name.clone()
+            } else {
+                format!("{} (optional)", argument.name)
+            };
+
+            match &argument.kind {
+                SearchResultEntrypointArgumentKind::Text | SearchResultEntrypointArgumentKind::Password => {
+                    let secure = matches!(argument.kind, SearchResultEntrypointArgumentKind::Password);
+
+                    text_input(&placeholder, value)
+                        .on_input(move |value| EntrypointArgumentEvent::Changed { index, value })
+                        .on_submit(EntrypointArgumentEvent::Submit)
+                        .secure(secure)
+                        .id(field_id.clone())
+                        .width(Length::Fixed(ARGUMENT_FIELD_WIDTH))
+                        .themed(TextInputStyle::FormInput)
+                }
+                SearchResultEntrypointArgumentKind::Dropdown { values: items } => {
+                    let items: Vec<_> = items
+                        .iter()
+                        .map(|item| {
+                            DropdownItem {
+                                value: item.value.clone(),
+                                label: item.label.clone(),
+                            }
+                        })
+                        .collect();
+
+                    let selected = items.iter().find(|item| &item.value == value).cloned();
+
+                    pick_list(items, selected, move |item| {
+                        EntrypointArgumentEvent::Changed {
+                            index,
+                            value: item.value,
+                        }
+                    })
+                    .placeholder(placeholder)
+                    .width(Length::Fixed(ARGUMENT_FIELD_WIDTH))
+                    .themed(PickListStyle::Default)
+                }
+            }
+        })
+        .collect();
+
+    row(fields).spacing(8.0).align_y(Alignment::Center).into()
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct DropdownItem {
+    value: String,
+    label: String,
+}
+
+impl Display for DropdownItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.label)
+    }
+}
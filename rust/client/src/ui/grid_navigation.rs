@@ -192,6 +192,27 @@ pub fn grid_down_offset(
     }
 }
 
+pub fn grid_rows_remaining(current_index: usize, amount_per_section_total: &[GridSectionData]) -> usize {
+    let total_rows = amount_per_section_total
+        .iter()
+        .map(|section| section.start_row_index + usize::div_ceil(section.amount_in_section, section.width))
+        .max()
+        .unwrap_or(0);
+
+    let current_section = amount_per_section_total
+        .iter()
+        .find(|section| section.start_index + section.amount_in_section >= (current_index + 1));
+
+    match current_section {
+        None => 0,
+        Some(section) => {
+            let row_index = section.start_row_index + usize::div(current_index - section.start_index, section.width);
+
+            total_rows.saturating_sub(row_index + 1)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -547,4 +568,29 @@ mod tests {
             })
         )
     }
+
+    #[test]
+    fn grid_rows_remaining_last_row() {
+        let sections_amount_width = prepare_sections(vec![vec![
+            vec![1, 1, 1],
+            //             V
+            vec![1, 1, 0],
+        ]]);
+
+        assert_eq!(grid_rows_remaining(4, &sections_amount_width), 0)
+    }
+
+    #[test]
+    fn grid_rows_remaining_across_sections() {
+        let sections_amount_width = prepare_sections(vec![
+            vec![
+                //    V
+                vec![1, 1, 1],
+                vec![1, 1, 1],
+            ],
+            vec![vec![1, 1], vec![1, 0]],
+        ]);
+
+        assert_eq!(grid_rows_remaining(1, &sections_amount_width), 3)
+    }
 }
@@ -14,9 +14,11 @@ use gauntlet_common::model::BackendRequestData;
 use gauntlet_common::model::BackendResponseData;
 use gauntlet_common::model::EntrypointId;
 use gauntlet_common::model::KeyboardEventOrigin;
+use gauntlet_common::model::PermissionPromptDecision;
 use gauntlet_common::model::PhysicalKey;
 use gauntlet_common::model::PhysicalShortcut;
 use gauntlet_common::model::PluginId;
+use gauntlet_common::model::PluginPermission;
 use gauntlet_common::model::RootWidget;
 use gauntlet_common::model::RootWidgetMembers;
 use gauntlet_common::model::SearchResult;
@@ -98,6 +100,8 @@ use tokio::sync::Mutex as TokioMutex;
 use tokio::sync::RwLock as TokioRwLock;
 
 use crate::model::UiViewEvent;
+use crate::ui::entrypoint_arguments::entrypoint_arguments;
+use crate::ui::entrypoint_arguments::EntrypointArgumentEvent;
 use crate::ui::search_list::search_list;
 use crate::ui::theme::container::ContainerStyle;
 use crate::ui::theme::container::ContainerStyleInner;
@@ -107,6 +111,7 @@ use crate::ui::theme::ThemableWidget;
 
 mod client_context;
 mod custom_widgets;
+mod entrypoint_arguments;
 mod grid_navigation;
 mod hud;
 mod scroll_handle;
@@ -172,6 +177,13 @@ pub struct AppModel {
     search_results: Vec<SearchResult>,
     loading_bar_state: HashMap<(PluginId, EntrypointId), ()>,
     hud_display: Option<String>,
+    permission_prompts: Vec<PermissionPromptData>,
+}
+
+struct PermissionPromptData {
+    plugin_name: String,
+    permission: PluginPermission,
+    responder: Responder<UiResponseData>,
 }
 
 #[cfg(target_os = "linux")]
@@ -188,6 +200,7 @@ pub enum AppMsg {
         plugin_name: String,
         entrypoint_id: EntrypointId,
         entrypoint_name: String,
+        arguments: HashMap<String, String>,
     },
     OpenGeneratedView {
         plugin_id: PluginId,
@@ -201,6 +214,11 @@ pub enum AppMsg {
         plugin_name: String,
         entrypoint_id: EntrypointId,
         entrypoint_name: String,
+        arguments: HashMap<String, String>,
+    },
+    ShowEntrypointArguments {
+        search_result: SearchResult,
+        arguments: HashMap<String, String>,
     },
     ShowNewGeneratedView {
         plugin_id: PluginId,
@@ -212,6 +230,7 @@ pub enum AppMsg {
     RunCommand {
         plugin_id: PluginId,
         entrypoint_id: EntrypointId,
+        arguments: HashMap<String, String>,
     },
     RunGeneratedEntrypoint {
         plugin_id: PluginId,
@@ -219,6 +238,11 @@ pub enum AppMsg {
         action_index: usize,
     },
     RunSearchItemAction(SearchResult, usize),
+    EntrypointArgumentChanged {
+        index: usize,
+        value: String,
+    },
+    SubmitEntrypointArguments,
     RunPluginAction {
         render_location: UiRenderLocation,
         plugin_id: PluginId,
@@ -228,6 +252,11 @@ pub enum AppMsg {
     PromptChanged(String),
     PromptSubmit,
     UpdateSearchResults,
+    UpdateSearchResultRows {
+        plugin_id: PluginId,
+        updated: Vec<SearchResult>,
+        removed: Vec<EntrypointId>,
+    },
     SetSearchResults(Vec<SearchResult>),
     RenderPluginUI {
         plugin_id: PluginId,
@@ -285,7 +314,7 @@ pub enum AppMsg {
     },
     ShowBackendError(BackendForFrontendApiError),
     ClosePluginView(PluginId),
-    OpenPluginView(PluginId, EntrypointId),
+    OpenPluginView(PluginId, EntrypointId, HashMap<String, String>),
     InlineViewShortcuts {
         shortcuts: HashMap<PluginId, HashMap<String, PhysicalShortcut>>,
     },
@@ -333,6 +362,14 @@ pub enum AppMsg {
         shortcut: Option<PhysicalShortcut>,
         responder: Arc<Mutex<Option<Responder<UiResponseData>>>>,
     },
+    ShowPermissionPrompt {
+        plugin_name: String,
+        permission: PluginPermission,
+        responder: Arc<Mutex<Option<Responder<UiResponseData>>>>,
+    },
+    PermissionPromptDecision {
+        decision: PermissionPromptDecision,
+    },
     UpdateLoadingBar {
         plugin_id: PluginId,
         entrypoint_id: EntrypointId,
@@ -343,6 +380,14 @@ pub enum AppMsg {
     FocusPluginViewSearchBar {
         widget_id: UiWidgetId,
     },
+    FocusPluginViewFormField {
+        widget_id: UiWidgetId,
+    },
+    ListPluginDirectory {
+        plugin_id: PluginId,
+        widget_id: UiWidgetId,
+        path: Option<String>,
+    },
     #[cfg(target_os = "linux")]
     LayerShell(layer_shell::LayerShellAppMsg),
     ClearInlineView {
@@ -650,6 +695,7 @@ fn new(
                                 entrypoint_id,
                                 entrypoint_name: gen_name,
                                 action_shortcuts: Default::default(),
+                                arguments: Default::default(),
                             },
                             true,
                         )
@@ -717,6 +763,7 @@ fn new(
             search_results: vec![],
             loading_bar_state: HashMap::new(),
             hud_display: None,
+            permission_prompts: vec![],
         },
         Task::batch(tasks),
     )
@@ -737,6 +784,7 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
             plugin_name,
             entrypoint_id,
             entrypoint_name,
+            arguments,
         } => {
             match &mut state.global_state {
                 GlobalState::MainView {
@@ -750,10 +798,11 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                         entrypoint_id: entrypoint_id.clone(),
                         entrypoint_name,
                         action_shortcuts: HashMap::new(),
+                        arguments: arguments.clone(),
                     });
 
                     Task::batch([
-                        Task::done(AppMsg::OpenPluginView(plugin_id, entrypoint_id)),
+                        Task::done(AppMsg::OpenPluginView(plugin_id, entrypoint_id, arguments)),
                         Task::done(AppMsg::PendingPluginViewLoadingBar),
                     ])
                 }
@@ -781,6 +830,7 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                         entrypoint_id: entrypoint_id.clone(),
                         entrypoint_name,
                         action_shortcuts: HashMap::new(),
+                        arguments: HashMap::new(),
                     });
 
                     Task::batch([
@@ -796,7 +846,13 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
         AppMsg::RunCommand {
             plugin_id,
             entrypoint_id,
-        } => Task::batch([state.hide_window(true), state.run_command(plugin_id, entrypoint_id)]),
+            arguments,
+        } => {
+            Task::batch([
+                state.hide_window(true),
+                state.run_command(plugin_id, entrypoint_id, arguments),
+            ])
+        }
         AppMsg::RunGeneratedEntrypoint {
             plugin_id,
             entrypoint_id,
@@ -839,22 +895,32 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
             match search_result.entrypoint_type {
                 SearchResultEntrypointType::Command => {
                     if action_index == 0 {
-                        Task::done(AppMsg::RunCommand {
-                            entrypoint_id: search_result.entrypoint_id.clone(),
-                            plugin_id: search_result.plugin_id.clone(),
-                        })
+                        if search_result.entrypoint_arguments.is_empty() {
+                            Task::done(AppMsg::RunCommand {
+                                entrypoint_id: search_result.entrypoint_id.clone(),
+                                plugin_id: search_result.plugin_id.clone(),
+                                arguments: HashMap::new(),
+                            })
+                        } else {
+                            state.show_entrypoint_arguments(search_result)
+                        }
                     } else {
                         Task::none()
                     }
                 }
                 SearchResultEntrypointType::View => {
                     if action_index == 0 {
-                        Task::done(AppMsg::OpenView {
-                            plugin_id: search_result.plugin_id.clone(),
-                            plugin_name: search_result.plugin_name.clone(),
-                            entrypoint_id: search_result.entrypoint_id.clone(),
-                            entrypoint_name: search_result.entrypoint_name.clone(),
-                        })
+                        if search_result.entrypoint_arguments.is_empty() {
+                            Task::done(AppMsg::OpenView {
+                                plugin_id: search_result.plugin_id.clone(),
+                                plugin_name: search_result.plugin_name.clone(),
+                                entrypoint_id: search_result.entrypoint_id.clone(),
+                                entrypoint_name: search_result.entrypoint_name.clone(),
+                                arguments: HashMap::new(),
+                            })
+                        } else {
+                            state.show_entrypoint_arguments(search_result)
+                        }
                     } else {
                         Task::none()
                     }
@@ -882,6 +948,76 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                 }
             }
         }
+        AppMsg::EntrypointArgumentChanged { index, value } => {
+            if let GlobalState::MainView {
+                sub_state: MainViewState::EntrypointArguments { argument_values, .. },
+                ..
+            } = &mut state.global_state
+            {
+                if let Some(argument_value) = argument_values.get_mut(index) {
+                    *argument_value = value;
+                }
+            }
+
+            Task::none()
+        }
+        AppMsg::SubmitEntrypointArguments => {
+            let GlobalState::MainView { sub_state, .. } = &mut state.global_state else {
+                return Task::none();
+            };
+
+            let MainViewState::EntrypointArguments {
+                argument_field_ids,
+                search_result,
+                argument_values,
+            } = sub_state
+            else {
+                return Task::none();
+            };
+
+            let missing_required_argument = search_result
+                .entrypoint_arguments
+                .iter()
+                .zip(argument_values.iter())
+                .position(|(argument, value)| argument.required && value.is_empty());
+
+            if let Some(index) = missing_required_argument {
+                return focus(argument_field_ids[index].clone());
+            }
+
+            // empty optional arguments are not passed to plugin
+            let arguments: HashMap<_, _> = search_result
+                .entrypoint_arguments
+                .iter()
+                .zip(argument_values.iter())
+                .filter(|(_, value)| !value.is_empty())
+                .map(|(argument, value)| (argument.id.clone(), value.clone()))
+                .collect();
+
+            let search_result = search_result.clone();
+
+            MainViewState::initial(sub_state);
+
+            match search_result.entrypoint_type {
+                SearchResultEntrypointType::Command => {
+                    Task::done(AppMsg::RunCommand {
+                        plugin_id: search_result.plugin_id,
+                        entrypoint_id: search_result.entrypoint_id,
+                        arguments,
+                    })
+                }
+                SearchResultEntrypointType::View => {
+                    Task::done(AppMsg::OpenView {
+                        plugin_id: search_result.plugin_id,
+                        plugin_name: search_result.plugin_name,
+                        entrypoint_id: search_result.entrypoint_id,
+                        entrypoint_name: search_result.entrypoint_name,
+                        arguments,
+                    })
+                }
+                SearchResultEntrypointType::Generated => Task::none(),
+            }
+        }
         AppMsg::PromptChanged(mut new_prompt) => {
             if cfg!(feature = "scenario_runner") {
                 Task::none()
@@ -914,6 +1050,44 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                 _ => Task::none(),
             }
         }
+        AppMsg::UpdateSearchResultRows {
+            plugin_id,
+            updated,
+            removed,
+        } => {
+            // rows are patched in place, so list doesn't jump around while generator is changing its entrypoints
+            state
+                .search_results
+                .retain(|result| result.plugin_id != plugin_id || !removed.contains(&result.entrypoint_id));
+
+            let mut search_required = false;
+
+            for updated_result in updated {
+                let existing_result = state.search_results.iter_mut().find(|result| {
+                    result.plugin_id == updated_result.plugin_id && result.entrypoint_id == updated_result.entrypoint_id
+                });
+
+                match existing_result {
+                    Some(result) => {
+                        // changed name may no longer match the prompt
+                        if result.entrypoint_name != updated_result.entrypoint_name {
+                            search_required = true;
+                        }
+
+                        *result = updated_result;
+                    }
+                    None => {
+                        // new entrypoint may match the prompt
+                        search_required = true;
+                    }
+                }
+            }
+
+            match &state.global_state {
+                GlobalState::MainView { .. } if search_required => state.search(state.prompt.clone(), false),
+                _ => Task::none(),
+            }
+        }
         AppMsg::PromptSubmit => state.global_state.primary(&state.client_context, &state.search_results),
         AppMsg::SetSearchResults(new_search_results) => {
             state.search_results = new_search_results;
@@ -1058,6 +1232,15 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                         Key::Named(Named::Tab) if modifiers.shift() => {
                             state.global_state.previous(&state.client_context)
                         }
+                        Key::Named(Named::Enter) if modifiers.command() && !modifiers.shift() && !modifiers.alt() => {
+                            match &state.global_state {
+                                GlobalState::PluginView {
+                                    sub_state: PluginViewState::None,
+                                    ..
+                                } => state.client_context.submit_form(),
+                                _ => Task::none(),
+                            }
+                        }
                         Key::Named(Named::Enter) => {
                             if modifiers.logo() || modifiers.alt() || modifiers.control() {
                                 Task::none() // to avoid not wanted "enter" presses
@@ -1085,6 +1268,7 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                                         }
                                         MainViewState::SearchResultActionPanel { .. } => Task::none(),
                                         MainViewState::InlineViewActionPanel { .. } => Task::none(),
+                                        MainViewState::EntrypointArguments { .. } => Task::none(),
                                     }
                                 }
                                 GlobalState::ErrorView { .. } => Task::none(),
@@ -1296,6 +1480,7 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                         MainViewState::InlineViewActionPanel { .. } => {
                             MainViewState::initial(sub_state);
                         }
+                        MainViewState::EntrypointArguments { .. } => {}
                     }
                 }
                 GlobalState::ErrorView { .. } => {}
@@ -1428,6 +1613,7 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                             }
                         }
                         MainViewState::InlineViewActionPanel { .. } => Task::none(),
+                        MainViewState::EntrypointArguments { .. } => Task::none(),
                     }
                 }
                 GlobalState::ErrorView { .. } => Task::none(),
@@ -1443,7 +1629,9 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                 id,
             })
         }
-        AppMsg::OpenPluginView(plugin_id, entrypoint_id) => state.open_plugin_view(plugin_id, entrypoint_id),
+        AppMsg::OpenPluginView(plugin_id, entrypoint_id, arguments) => {
+            state.open_plugin_view(plugin_id, entrypoint_id, arguments)
+        }
         AppMsg::ClosePluginView(plugin_id) => state.close_plugin_view(plugin_id),
         AppMsg::InlineViewShortcuts { shortcuts } => {
             state.client_context.set_inline_view_shortcuts(shortcuts);
@@ -1498,6 +1686,37 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
 
             Task::none()
         }
+        AppMsg::ShowPermissionPrompt {
+            plugin_name,
+            permission,
+            responder,
+        } => {
+            let responder = responder
+                .lock()
+                .expect("lock is poisoned")
+                .take()
+                .expect("there should always be a responder here");
+
+            state.permission_prompts.push(PermissionPromptData {
+                plugin_name,
+                permission,
+                responder,
+            });
+
+            state.show_window()
+        }
+        AppMsg::PermissionPromptDecision { decision } => {
+            if !state.permission_prompts.is_empty() {
+                let prompt = state.permission_prompts.remove(0);
+
+                // plugin could have been stopped while prompt was shown
+                prompt
+                    .responder
+                    .try_respond(UiResponseData::PermissionPrompt { decision });
+            }
+
+            Task::none()
+        }
         AppMsg::UpdateLoadingBar {
             plugin_id,
             entrypoint_id,
@@ -1543,6 +1762,12 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
             Task::none()
         }
         AppMsg::FocusPluginViewSearchBar { widget_id } => state.client_context.focus_search_bar(widget_id),
+        AppMsg::FocusPluginViewFormField { widget_id } => state.client_context.focus_form_field(widget_id),
+        AppMsg::ListPluginDirectory {
+            plugin_id,
+            widget_id,
+            path,
+        } => state.list_plugin_directory(plugin_id, widget_id, path),
         #[cfg(target_os = "linux")]
         AppMsg::LayerShell(_) => {
             // handled by library
@@ -1570,6 +1795,7 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
             plugin_name,
             entrypoint_id,
             entrypoint_name,
+            arguments,
         } => {
             Task::batch([
                 GlobalState::pending_plugin(
@@ -1581,9 +1807,22 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                         entrypoint_id: entrypoint_id.clone(),
                         entrypoint_name,
                         action_shortcuts: HashMap::new(),
+                        arguments: arguments.clone(),
                     },
                 ),
-                Task::done(AppMsg::OpenPluginView(plugin_id, entrypoint_id)),
+                Task::done(AppMsg::OpenPluginView(plugin_id, entrypoint_id, arguments)),
+                Task::done(AppMsg::ShowWindow),
+            ])
+        }
+        AppMsg::ShowEntrypointArguments {
+            search_result,
+            arguments,
+        } => {
+            // search for the entrypoint, so it is visible to user what is going to be run
+            state.prompt = search_result.entrypoint_name.clone();
+
+            Task::batch([
+                GlobalState::entrypoint_arguments(&mut state.global_state, search_result, arguments),
                 Task::done(AppMsg::ShowWindow),
             ])
         }
@@ -1604,6 +1843,7 @@ fn update(state: &mut AppModel, message: AppMsg) -> Task<AppMsg> {
                         entrypoint_id: entrypoint_id.clone(),
                         entrypoint_name,
                         action_shortcuts: HashMap::new(),
+                        arguments: HashMap::new(),
                     },
                 ),
                 state.run_generated_entrypoint(plugin_id, entrypoint_id, action_index),
@@ -1638,10 +1878,71 @@ fn view(state: &AppModel, window: window::Id) -> Element<'_, AppMsg> {
     if window != state.main_window_id {
         view_hud(state)
     } else {
-        view_main(state)
+        match state.permission_prompts.first() {
+            Some(prompt) => view_permission_prompt(prompt),
+            None => view_main(state),
+        }
     }
 }
 
+fn view_permission_prompt(prompt: &PermissionPromptData) -> Element<'_, AppMsg> {
+    let description = format!("Plugin \"{}\" is requesting access", prompt.plugin_name);
+
+    let description: Element<_> = text(description).shaping(Shaping::Advanced).into();
+
+    let description = container(description)
+        .width(Length::Fill)
+        .align_x(Horizontal::Center)
+        .themed(ContainerStyle::PluginErrorViewTitle);
+
+    let sub_description = format!("{}: {}", prompt.permission.kind, prompt.permission.resource);
+
+    let sub_description: Element<_> = text(sub_description).shaping(Shaping::Advanced).into();
+
+    let sub_description = container(sub_description)
+        .width(Length::Fill)
+        .align_x(Horizontal::Center)
+        .themed(ContainerStyle::PluginErrorViewDescription);
+
+    let deny_button: Element<_> = button(text("Deny"))
+        .on_press(AppMsg::PermissionPromptDecision {
+            decision: PermissionPromptDecision::Deny,
+        })
+        .into();
+
+    let allow_once_button: Element<_> = button(text("Allow Once"))
+        .on_press(AppMsg::PermissionPromptDecision {
+            decision: PermissionPromptDecision::AllowOnce,
+        })
+        .into();
+
+    let allow_always_button: Element<_> = button(text("Always Allow"))
+        .on_press(AppMsg::PermissionPromptDecision {
+            decision: PermissionPromptDecision::AllowAlways,
+        })
+        .into();
+
+    let buttons: Element<_> = row([deny_button, allow_once_button, allow_always_button])
+        .spacing(8.0)
+        .into();
+
+    let buttons = container(buttons)
+        .width(Length::Fill)
+        .align_x(Horizontal::Center)
+        .into();
+
+    let content: Element<_> = column([description, sub_description, buttons]).into();
+
+    let content: Element<_> = container(content)
+        .align_x(Horizontal::Center)
+        .align_y(Vertical::Center)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .themed(ContainerStyle::Main);
+
+    content
+}
+
 fn view_hud(state: &AppModel) -> Element<'_, AppMsg> {
     match &state.hud_display {
         Some(hud_display) => {
@@ -1866,6 +2167,30 @@ fn view_main(state: &AppModel) -> Element<'_, AppMsg> {
                 .width(Length::Fill)
                 .themed(TextInputStyle::MainSearch);
 
+            let input = match sub_state {
+                MainViewState::EntrypointArguments {
+                    argument_field_ids,
+                    search_result,
+                    argument_values,
+                } => {
+                    let arguments =
+                        entrypoint_arguments(&search_result.entrypoint_arguments, argument_values, argument_field_ids)
+                            .map(|event| {
+                                match event {
+                                    EntrypointArgumentEvent::Changed { index, value } => {
+                                        AppMsg::EntrypointArgumentChanged { index, value }
+                                    }
+                                    EntrypointArgumentEvent::Submit => AppMsg::SubmitEntrypointArguments,
+                                }
+                            });
+
+                    row([input, arguments]).spacing(8.0).align_y(Alignment::Center).into()
+                }
+                MainViewState::None
+                | MainViewState::SearchResultActionPanel { .. }
+                | MainViewState::InlineViewActionPanel { .. } => input,
+            };
+
             let search_list = search_list(&state.search_results, &focused_search_result)
                 .map(|search_result| AppMsg::OnPrimaryActionMainViewNoPanel { search_result });
 
@@ -2115,6 +2440,23 @@ fn view_main(state: &AppModel) -> Element<'_, AppMsg> {
                         || AppMsg::Noop,
                     )
                 }
+                MainViewState::EntrypointArguments { .. } => {
+                    render_root(
+                        false,
+                        input,
+                        separator,
+                        toast_text,
+                        content,
+                        primary_action,
+                        action_panel,
+                        None::<&ScrollHandle>,
+                        "",
+                        || AppMsg::Noop,
+                        |_widget_id| AppMsg::SubmitEntrypointArguments,
+                        |_widget_id| AppMsg::Noop,
+                        || AppMsg::Noop,
+                    )
+                }
             };
 
             let root: Element<_> = container(root)
@@ -2283,10 +2625,23 @@ impl AppModel {
         if self.opened {
             self.hide_window(false)
         } else {
-            self.show_window()
+            Task::batch([self.show_window(), self.capture_entrypoint_input()])
         }
     }
 
+    fn capture_entrypoint_input(&self) -> Task<AppMsg> {
+        let mut backend_api = self.backend_api.clone();
+
+        Task::perform(
+            async move {
+                backend_api.capture_entrypoint_input().await?;
+
+                Ok(())
+            },
+            |result| handle_backend_error(result, |()| AppMsg::UpdateSearchResults),
+        )
+    }
+
     fn hide_window(&mut self, reset_state: bool) -> Task<AppMsg> {
         if !self.opened {
             return Task::none();
@@ -2295,6 +2650,13 @@ impl AppModel {
         self.focused = false;
         self.opened = false;
 
+        // closing the window without a decision is treated as denial
+        for prompt in self.permission_prompts.drain(..) {
+            prompt.responder.try_respond(UiResponseData::PermissionPrompt {
+                decision: PermissionPromptDecision::Deny,
+            });
+        }
+
         let mut commands = vec![];
 
         self.pending_window_state_reset = reset_state;
@@ -2387,12 +2749,19 @@ impl AppModel {
         GlobalState::initial(&mut self.global_state)
     }
 
-    fn open_plugin_view(&self, plugin_id: PluginId, entrypoint_id: EntrypointId) -> Task<AppMsg> {
+    fn open_plugin_view(
+        &self,
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        arguments: HashMap<String, String>,
+    ) -> Task<AppMsg> {
         let mut backend_client = self.backend_api.clone();
 
         Task::perform(
             async move {
-                let result = backend_client.request_view_render(plugin_id, entrypoint_id).await?;
+                let result = backend_client
+                    .request_view_render(plugin_id, entrypoint_id, arguments)
+                    .await?;
 
                 Ok(result)
             },
@@ -2413,12 +2782,19 @@ impl AppModel {
         )
     }
 
-    fn run_command(&self, plugin_id: PluginId, entrypoint_id: EntrypointId) -> Task<AppMsg> {
+    fn run_command(
+        &self,
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        arguments: HashMap<String, String>,
+    ) -> Task<AppMsg> {
         let mut backend_client = self.backend_api.clone();
 
         Task::perform(
             async move {
-                backend_client.request_run_command(plugin_id, entrypoint_id).await?;
+                backend_client
+                    .request_run_command(plugin_id, entrypoint_id, arguments)
+                    .await?;
 
                 Ok(())
             },
@@ -2426,6 +2802,17 @@ impl AppModel {
         )
     }
 
+    fn show_entrypoint_arguments(&mut self, search_result: SearchResult) -> Task<AppMsg> {
+        match &mut self.global_state {
+            GlobalState::MainView { sub_state, .. } => {
+                MainViewState::entrypoint_arguments(sub_state, search_result, HashMap::new())
+            }
+            GlobalState::ErrorView { .. } => Task::none(),
+            GlobalState::PluginView { .. } => Task::none(),
+            GlobalState::PendingPluginView { .. } => Task::none(),
+        }
+    }
+
     fn run_generated_entrypoint(
         &self,
         plugin_id: PluginId,
@@ -2656,6 +3043,27 @@ impl AppModel {
         })
     }
 
+    fn list_plugin_directory(&self, plugin_id: PluginId, widget_id: UiWidgetId, path: Option<String>) -> Task<AppMsg> {
+        let mut backend_api = self.backend_api.clone();
+
+        Task::perform(
+            {
+                let plugin_id = plugin_id.clone();
+
+                async move { backend_api.list_plugin_directory(plugin_id, path).await }
+            },
+            move |result| {
+                handle_backend_error(result, |directory| {
+                    AppMsg::WidgetEvent {
+                        plugin_id,
+                        render_location: UiRenderLocation::View,
+                        widget_event: ComponentWidgetEvent::SetFilePickerDirectory { widget_id, directory },
+                    }
+                })
+            },
+        )
+    }
+
     fn handle_shortcut_key(
         &mut self,
         physical_key: Physical,
@@ -2804,6 +3212,7 @@ impl AppModel {
                             _ => Task::none(),
                         }
                     }
+                    MainViewState::EntrypointArguments { .. } => Task::none(),
                 }
             }
             GlobalState::ErrorView { .. } => Task::none(),
@@ -2816,6 +3225,21 @@ impl AppModel {
                         modifier_alt: true,
                         modifier_meta: false,
                     }) => Task::perform(async {}, |_| AppMsg::ToggleActionPanel { keyboard: true }),
+                    Some(PhysicalShortcut {
+                        physical_key: PhysicalKey::KeyP,
+                        modifier_shift,
+                        modifier_control: cfg!(any(target_os = "linux", target_os = "windows")),
+                        modifier_alt: false,
+                        modifier_meta: cfg!(target_os = "macos"),
+                    }) if self.client_context.has_search_bar_dropdown() => {
+                        // without dropdown shortcut is passed to plugin like any other
+                        match sub_state {
+                            PluginViewState::None => {
+                                self.client_context.select_next_search_bar_dropdown_item(modifier_shift)
+                            }
+                            PluginViewState::ActionPanel { .. } => Task::none(),
+                        }
+                    }
                     Some(PhysicalShortcut {
                         physical_key,
                         modifier_shift,
@@ -2974,6 +3398,19 @@ async fn request_loop(
 
                     AppMsg::UpdateSearchResults
                 }
+                UiRequestData::UpdateSearchResultRows {
+                    plugin_id,
+                    updated,
+                    removed,
+                } => {
+                    responder.respond(UiResponseData::Nothing);
+
+                    AppMsg::UpdateSearchResultRows {
+                        plugin_id,
+                        updated,
+                        removed,
+                    }
+                }
                 UiRequestData::ShowHud { display } => {
                     responder.respond(UiResponseData::Nothing);
 
@@ -3013,6 +3450,7 @@ async fn request_loop(
                     plugin_name,
                     entrypoint_id,
                     entrypoint_name,
+                    arguments,
                 } => {
                     responder.respond(UiResponseData::Nothing);
 
@@ -3021,6 +3459,18 @@ async fn request_loop(
                         plugin_name,
                         entrypoint_id,
                         entrypoint_name,
+                        arguments,
+                    }
+                }
+                UiRequestData::ShowEntrypointArguments {
+                    search_result,
+                    arguments,
+                } => {
+                    responder.respond(UiResponseData::Nothing);
+
+                    AppMsg::ShowEntrypointArguments {
+                        search_result,
+                        arguments,
                     }
                 }
                 UiRequestData::ShowGeneratedPluginView {
@@ -3041,6 +3491,17 @@ async fn request_loop(
                     }
                 }
                 UiRequestData::SynchronizeEvent { plugin_id } => AppMsg::EventHanded { plugin_id },
+                UiRequestData::ShowPermissionPrompt {
+                    plugin_name,
+                    permission,
+                    ..
+                } => {
+                    AppMsg::ShowPermissionPrompt {
+                        plugin_name,
+                        permission,
+                        responder: Arc::new(Mutex::new(Some(responder))),
+                    }
+                }
             }
         };
 
@@ -3,6 +3,7 @@ use std::marker::PhantomData;
 use iced::widget::scrollable::scroll_to;
 use iced::widget::scrollable::AbsoluteOffset;
 use iced::widget::scrollable::Id;
+use iced::widget::scrollable::Viewport;
 use iced::Task;
 
 use crate::ui::AppMsg;
@@ -111,6 +112,21 @@ impl ScrollHandle {
         }
     }
 
+    // true if the focused row is within one view from the last row
+    pub fn is_near_end(&self, rows_remaining: usize) -> bool {
+        match self.index {
+            None => false,
+            Some(_) => rows_remaining <= self.rows_per_view + 1,
+        }
+    }
+
+    pub fn is_viewport_near_end(&self, viewport: &Viewport) -> bool {
+        let distance_to_end =
+            viewport.content_bounds().height - viewport.bounds().height - viewport.absolute_offset().y;
+
+        distance_to_end <= self.item_height * (self.rows_per_view + 1) as f32
+    }
+
     pub fn scroll_to<Message: 'static>(&self, row_index: usize) -> Task<Message> {
         let pos_y = row_index as f32 * self.item_height - (self.offset as f32 * self.item_height);
 
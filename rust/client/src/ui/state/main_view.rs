@@ -1,5 +1,13 @@
+use std::collections::HashMap;
+
+use gauntlet_common::model::SearchResult;
+use iced::widget::text_input;
+use iced::widget::text_input::focus;
+use iced::Task;
+
 use crate::ui::scroll_handle::ScrollHandle;
 use crate::ui::scroll_handle::ESTIMATED_ACTION_ITEM_HEIGHT;
+use crate::ui::AppMsg;
 
 pub enum MainViewState {
     None,
@@ -11,6 +19,14 @@ pub enum MainViewState {
         // ephemeral state
         focused_action_item: ScrollHandle,
     },
+    EntrypointArguments {
+        // logic
+        argument_field_ids: Vec<text_input::Id>,
+
+        // state
+        search_result: SearchResult,
+        argument_values: Vec<String>,
+    },
 }
 
 impl MainViewState {
@@ -28,6 +44,37 @@ impl MainViewState {
         }
     }
 
+    pub fn entrypoint_arguments(
+        prev_state: &mut MainViewState,
+        search_result: SearchResult,
+        arguments: HashMap<String, String>,
+    ) -> Task<AppMsg> {
+        let argument_field_ids: Vec<_> = search_result
+            .entrypoint_arguments
+            .iter()
+            .map(|_| text_input::Id::unique())
+            .collect();
+
+        let argument_values = search_result
+            .entrypoint_arguments
+            .iter()
+            .map(|argument| arguments.get(&argument.id).cloned().unwrap_or_default())
+            .collect();
+
+        let first_field_id = argument_field_ids.first().cloned();
+
+        *prev_state = Self::EntrypointArguments {
+            argument_field_ids,
+            search_result,
+            argument_values,
+        };
+
+        match first_field_id {
+            Some(first_field_id) => focus(first_field_id),
+            None => Task::none(),
+        }
+    }
+
     pub fn inline_result_action_panel(prev_state: &mut MainViewState, focus_first: bool) {
         *prev_state = Self::InlineViewActionPanel {
             focused_action_item: ScrollHandle::new(focus_first, ESTIMATED_ACTION_ITEM_HEIGHT, 7),
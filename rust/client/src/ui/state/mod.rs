@@ -7,6 +7,8 @@ use gauntlet_common::model::EntrypointId;
 use gauntlet_common::model::PhysicalShortcut;
 use gauntlet_common::model::PluginId;
 use gauntlet_common::model::SearchResult;
+use iced::widget::focus_next;
+use iced::widget::focus_previous;
 use iced::widget::text_input;
 use iced::widget::text_input::focus;
 use iced::Task;
@@ -53,6 +55,8 @@ pub struct PluginViewData {
     pub entrypoint_id: EntrypointId,
     pub entrypoint_name: String,
     pub action_shortcuts: HashMap<String, PhysicalShortcut>,
+    // entrypoint arguments the view was opened with, used to re-render top level view
+    pub arguments: HashMap<String, String>,
 }
 
 pub enum ErrorViewData {
@@ -138,6 +142,25 @@ impl GlobalState {
 
         Task::none()
     }
+
+    pub fn entrypoint_arguments(
+        prev_global_state: &mut GlobalState,
+        search_result: SearchResult,
+        arguments: HashMap<String, String>,
+    ) -> Task<AppMsg> {
+        let search_field_id = text_input::Id::unique();
+
+        *prev_global_state = GlobalState::new(search_field_id);
+
+        let GlobalState::MainView { sub_state, .. } = prev_global_state else {
+            unreachable!()
+        };
+
+        Task::batch([
+            MainViewState::entrypoint_arguments(sub_state, search_result, arguments),
+            Task::done(AppMsg::UpdateSearchResults),
+        ])
+    }
 }
 
 pub trait Focus<T> {
@@ -195,6 +218,7 @@ impl Focus<SearchResult> for GlobalState {
                             }
                         }
                     }
+                    MainViewState::EntrypointArguments { .. } => Task::done(AppMsg::SubmitEntrypointArguments),
                 }
             }
             GlobalState::PluginView { sub_state, .. } => {
@@ -253,6 +277,7 @@ impl Focus<SearchResult> for GlobalState {
                         // secondary does nothing when action panel is opened
                         Task::none()
                     }
+                    MainViewState::EntrypointArguments { .. } => Task::none(),
                 }
             }
             GlobalState::PluginView { sub_state, .. } => {
@@ -284,7 +309,11 @@ impl Focus<SearchResult> for GlobalState {
 
     fn back(&mut self, _client_context: &ClientContext) -> Task<AppMsg> {
         match self {
-            GlobalState::MainView { sub_state, .. } => {
+            GlobalState::MainView {
+                sub_state,
+                search_field_id,
+                ..
+            } => {
                 match sub_state {
                     MainViewState::None => Task::done(AppMsg::HideWindow),
                     MainViewState::SearchResultActionPanel { .. } => {
@@ -295,6 +324,10 @@ impl Focus<SearchResult> for GlobalState {
                         MainViewState::initial(sub_state);
                         Task::none()
                     }
+                    MainViewState::EntrypointArguments { .. } => {
+                        MainViewState::initial(sub_state);
+                        focus(search_field_id.clone())
+                    }
                 }
             }
             GlobalState::PluginView {
@@ -303,6 +336,7 @@ impl Focus<SearchResult> for GlobalState {
                         top_level_view,
                         plugin_id,
                         entrypoint_id,
+                        arguments,
                         ..
                     },
                 sub_state,
@@ -327,7 +361,8 @@ impl Focus<SearchResult> for GlobalState {
                         } else {
                             let plugin_id = plugin_id.clone();
                             let entrypoint_id = entrypoint_id.clone();
-                            Task::done(AppMsg::OpenPluginView(plugin_id, entrypoint_id))
+                            let arguments = arguments.clone();
+                            Task::done(AppMsg::OpenPluginView(plugin_id, entrypoint_id, arguments))
                         }
                     }
                     PluginViewState::ActionPanel { .. } => Task::done(AppMsg::ToggleActionPanel { keyboard: true }),
@@ -339,7 +374,14 @@ impl Focus<SearchResult> for GlobalState {
     }
     fn next(&mut self, _client_context: &ClientContext) -> Task<AppMsg> {
         match self {
-            GlobalState::MainView { .. } => Task::none(),
+            GlobalState::MainView { sub_state, .. } => {
+                match sub_state {
+                    MainViewState::EntrypointArguments { .. } => focus_next(),
+                    MainViewState::None
+                    | MainViewState::SearchResultActionPanel { .. }
+                    | MainViewState::InlineViewActionPanel { .. } => Task::none(),
+                }
+            }
             GlobalState::PluginView { .. } => Task::none(),
             GlobalState::ErrorView { .. } => Task::none(),
             GlobalState::PendingPluginView { .. } => Task::none(),
@@ -347,7 +389,14 @@ impl Focus<SearchResult> for GlobalState {
     }
     fn previous(&mut self, _client_context: &ClientContext) -> Task<AppMsg> {
         match self {
-            GlobalState::MainView { .. } => Task::none(),
+            GlobalState::MainView { sub_state, .. } => {
+                match sub_state {
+                    MainViewState::EntrypointArguments { .. } => focus_previous(),
+                    MainViewState::None
+                    | MainViewState::SearchResultActionPanel { .. }
+                    | MainViewState::InlineViewActionPanel { .. } => Task::none(),
+                }
+            }
             GlobalState::PluginView { .. } => Task::none(),
             GlobalState::ErrorView { .. } => Task::none(),
             GlobalState::PendingPluginView { .. } => Task::none(),
@@ -362,6 +411,7 @@ impl Focus<SearchResult> for GlobalState {
             } => {
                 match sub_state {
                     MainViewState::None => focused_search_result.focus_previous().unwrap_or_else(|| Task::none()),
+                    MainViewState::EntrypointArguments { .. } => Task::none(),
                     MainViewState::SearchResultActionPanel { focused_action_item } => {
                         focused_action_item.focus_previous().unwrap_or_else(|| Task::none())
                     }
@@ -412,6 +462,7 @@ impl Focus<SearchResult> for GlobalState {
                             Task::none()
                         }
                     }
+                    MainViewState::EntrypointArguments { .. } => Task::none(),
                     MainViewState::InlineViewActionPanel { focused_action_item } => {
                         match client_context.get_first_inline_view_action_panel() {
                             Some(action_panel) => {
@@ -13,7 +13,7 @@ pub fn create_tray() -> tray_icon::TrayIcon {
 
     MenuEvent::set_event_handler(Some(|event: MenuEvent| {
         match event.id().as_ref() {
-            "GAUNTLET_OPEN_MAIN_WINDOW" => crate::open_window(),
+            "GAUNTLET_OPEN_MAIN_WINDOW" => crate::open_window(None),
             "GAUNTLET_OPEN_SETTING_WINDOW" => crate::open_settings_window(),
             _ => {}
         }
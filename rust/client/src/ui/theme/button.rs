@@ -33,6 +33,7 @@ pub enum ButtonStyle {
     RootBottomPanelPrimaryActionButton,
     RootTopPanelBackButton,
     MetadataTagItem,
+    TableHeaderCell,
 }
 
 impl ButtonStyle {
@@ -76,6 +77,7 @@ impl ButtonStyle {
                 theme.padding.to_iced()
             }
             ButtonStyle::MetadataLink => padding_all(0.0).to_iced(),
+            ButtonStyle::TableHeaderCell => padding_all(0.0).to_iced(),
             ButtonStyle::MetadataTagItem => {
                 let theme = &theme.metadata_tag_item_button;
                 theme.padding.to_iced()
@@ -239,6 +241,20 @@ impl ButtonStyle {
                     &Color::TRANSPARENT,
                 )
             }
+            ButtonStyle::TableHeaderCell => {
+                let text_color_hovered = &theme.list_item.text_color_hovered;
+                let theme = &theme.list_section_title;
+                (
+                    None,
+                    None,
+                    None,
+                    &theme.text_color,
+                    text_color_hovered,
+                    &0.0,
+                    &1.0,
+                    &Color::TRANSPARENT,
+                )
+            }
             ButtonStyle::MetadataTagItem => {
                 let theme = &theme.metadata_tag_item_button;
                 (
@@ -29,10 +29,13 @@ pub enum ContainerStyle {
     DetailMetadata,
     EmptyViewImage,
     FormInputLabel,
+    FormInputError,
+    FormInputInfo,
     Inline,
     ListItemSubtitle,
     ListItemTitle,
     ListItemIcon,
+    ListItemLoading,
     Main,
     MainList,
     MainListInner,
@@ -300,6 +303,8 @@ impl<'a, Message: 'a> ThemableWidget<'a, Message> for Container<'a, Message, Gau
             ContainerStyle::ListItemSubtitle => self.padding(theme.list_item_subtitle.padding.to_iced()),
             ContainerStyle::ListItemTitle => self.padding(theme.list_item_title.padding.to_iced()),
             ContainerStyle::ListItemIcon => self.padding(theme.list_item_icon.padding.to_iced()),
+            // same padding as list item button, so it lines up with items
+            ContainerStyle::ListItemLoading => self.padding(theme.list_item.padding.to_iced()).width(Length::Fill),
             ContainerStyle::ContentParagraph => self.padding(theme.content_paragraph.padding.to_iced()),
             ContainerStyle::ContentHorizontalBreak => self.padding(theme.content_horizontal_break.padding.to_iced()),
             ContainerStyle::ContentCodeBlock => self.padding(theme.content_code_block.padding.to_iced()),
@@ -317,6 +322,8 @@ impl<'a, Message: 'a> ThemableWidget<'a, Message> for Container<'a, Message, Gau
             ContainerStyle::DetailMetadata => self.padding(theme.detail_metadata.padding.to_iced()),
             ContainerStyle::DetailContent => self.padding(theme.detail_content.padding.to_iced()),
             ContainerStyle::FormInputLabel => self.padding(theme.form_input_label.padding.to_iced()),
+            ContainerStyle::FormInputError => self.padding(theme.form_input_error.padding.to_iced()),
+            ContainerStyle::FormInputInfo => self.padding(theme.form_input_info.padding.to_iced()),
             ContainerStyle::Inline => self.padding(theme.inline.padding.to_iced()),
             ContainerStyle::InlineInner => {
                 self.height(120)
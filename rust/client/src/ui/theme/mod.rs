@@ -17,12 +17,14 @@ pub mod date_picker;
 pub mod grid;
 pub mod image;
 mod loading_bar;
+pub mod number_input;
 pub mod pick_list;
 pub mod row;
 pub mod rule;
 pub mod scrollable;
 pub mod space;
 pub mod text;
+pub mod text_editor;
 pub mod text_input;
 pub mod tooltip;
 
@@ -44,6 +46,7 @@ pub struct GauntletComplexTheme {
     content_horizontal_break: ThemePaddingOnly,
     content_image: ThemeImage,
     content_paragraph: ThemePaddingOnly,
+    content_paragraph_link: ThemeTextColor,
     detail_content: ThemePaddingOnly,
     detail_metadata: ThemePaddingOnly,
     empty_view_image: ThemePaddingSize,
@@ -52,6 +55,8 @@ pub struct GauntletComplexTheme {
     form_inner: ThemePaddingOnly,
     form_input: ThemePaddingOnly,
     form_input_label: ThemePaddingOnly,
+    form_input_error: ThemePaddingTextColor,
+    form_input_info: ThemePaddingTextColor,
     form_input_date_picker: ThemeDatePicker,
     form_input_date_picker_buttons: ThemeButton,
     form_input_checkbox: ThemeCheckbox,
@@ -266,6 +271,7 @@ impl GauntletComplexTheme {
             content_paragraph: ThemePaddingOnly {
                 padding: padding_all(8.0),
             },
+            content_paragraph_link: ThemeTextColor { text_color: text_200 },
             content_code_block: ThemePaddingOnly {
                 padding: padding_all(0.0),
             },
@@ -420,6 +426,17 @@ impl GauntletComplexTheme {
             form_input_label: ThemePaddingOnly {
                 padding: padding_axis(4.0, 12.0),
             },
+            form_input_error: ThemePaddingTextColor {
+                padding: padding(4.0, 0.0, 0.0, 0.0),
+                text_color: match mode {
+                    UiThemeMode::Light => Color::from_rgb8(0xC6, 0x28, 0x28),
+                    UiThemeMode::Dark => Color::from_rgb8(0xEF, 0x53, 0x50),
+                },
+            },
+            form_input_info: ThemePaddingTextColor {
+                padding: padding(4.0, 0.0, 0.0, 0.0),
+                text_color: text_300,
+            },
             list_section_title: ThemePaddingTextColorSpacing {
                 padding: padding(12.0, 8.0, 4.0, 8.0),
                 text_color: text_200,
@@ -0,0 +1,57 @@
+use iced_aw::number_input::number_input;
+use iced_aw::number_input::Style;
+use iced_aw::style::Status;
+
+use crate::ui::theme::GauntletComplexTheme;
+
+impl number_input::ExtendedCatalog for GauntletComplexTheme {
+    fn style(&self, class: &(), status: Status) -> Style {
+        number_input::Catalog::style(self, class, status)
+    }
+}
+
+impl number_input::Catalog for GauntletComplexTheme {
+    type Class<'a> = ();
+
+    fn default<'a>() -> Self::Class<'a> {
+        ()
+    }
+
+    fn style(&self, _class: &Self::Class<'_>, status: Status) -> Style {
+        match status {
+            Status::Active => active(self),
+            Status::Hovered => hovered(self),
+            Status::Pressed => hovered(self),
+            Status::Disabled => disabled(self),
+            Status::Focused => active(self),   // TODO proper style
+            Status::Selected => hovered(self), // TODO proper style
+        }
+    }
+}
+
+fn active(theme: &GauntletComplexTheme) -> Style {
+    let theme = &theme.form_input_date_picker_buttons;
+
+    Style {
+        button_background: Some(theme.background_color.into()),
+        icon_color: theme.text_color,
+    }
+}
+
+fn hovered(theme: &GauntletComplexTheme) -> Style {
+    let theme = &theme.form_input_date_picker_buttons;
+
+    Style {
+        button_background: Some(theme.background_color_hovered.into()),
+        icon_color: theme.text_color_hovered,
+    }
+}
+
+fn disabled(theme: &GauntletComplexTheme) -> Style {
+    let theme = &theme.form_input_date_picker_buttons;
+
+    Style {
+        button_background: None,
+        icon_color: theme.text_color,
+    }
+}
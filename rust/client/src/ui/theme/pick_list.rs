@@ -10,7 +10,6 @@ use crate::ui::theme::get_theme;
 use crate::ui::theme::Element;
 use crate::ui::theme::GauntletComplexTheme;
 use crate::ui::theme::ThemableWidget;
-use crate::ui::theme::NOT_INTENDED_TO_BE_USED;
 
 #[derive(Clone, Default)]
 pub enum PickListStyle {
@@ -33,6 +32,7 @@ impl pick_list::Catalog for GauntletComplexTheme {
 
     fn style(&self, _class: &<Self as pick_list::Catalog>::Class<'_>, status: Status) -> pick_list::Style {
         let theme = get_theme();
+        let placeholder_color = theme.form_input_text_field.text_color_placeholder;
         let theme = &theme.form_input_select;
 
         let background_color = match status {
@@ -48,7 +48,7 @@ impl pick_list::Catalog for GauntletComplexTheme {
         pick_list::Style {
             text_color,
             background: background_color.into(),
-            placeholder_color: NOT_INTENDED_TO_BE_USED,
+            placeholder_color,
             handle_color: text_color,
             border: Border {
                 radius: theme.border_radius.into(),
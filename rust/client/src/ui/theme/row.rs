@@ -17,6 +17,8 @@ pub enum RowStyle {
     GridItemTitle,
     RootBottomPanel,
     RootTopPanel,
+    TableHeader,
+    TableRow,
 }
 
 impl<'a, Message: 'a> ThemableWidget<'a, Message> for Row<'a, Message, GauntletComplexTheme, Renderer> {
@@ -59,6 +61,11 @@ impl<'a, Message: 'a> ThemableWidget<'a, Message> for Row<'a, Message, GauntletC
             RowStyle::GridItemTitle => self.padding(theme.grid_item_title.padding.to_iced()),
             RowStyle::RootBottomPanel => self.spacing(theme.root_bottom_panel.spacing),
             RowStyle::RootTopPanel => self.spacing(theme.root_top_panel.spacing),
+            RowStyle::TableHeader => {
+                self.padding(theme.list_item.padding.to_iced())
+                    .spacing(theme.list_section_title.spacing)
+            }
+            RowStyle::TableRow => self.spacing(theme.list_section_title.spacing),
         }
         .into()
     }
@@ -1,6 +1,8 @@
 use iced::widget::text;
+use iced::widget::text::Span;
 use iced::widget::text::Style;
 use iced::widget::Text;
+use iced::Font;
 use iced::Renderer;
 
 use crate::ui::theme::get_theme;
@@ -15,6 +17,8 @@ pub enum TextStyle {
 
     ActionSectionTitle,
     EmptyViewSubtitle,
+    FormInputError,
+    FormInputInfo,
     ListItemSubtitle,
     ListSectionTitle,
     ListSectionSubtitle,
@@ -44,11 +48,35 @@ impl<'a, Message: 'a> ThemableWidget<'a, Message> for Text<'a, GauntletComplexTh
             }
             TextStyle::InlineName => self.size(15).class(kind).into(),
             TextStyle::MainListItemSubtext => self.size(15).class(kind).into(),
+            TextStyle::FormInputError | TextStyle::FormInputInfo => self.size(14).class(kind).into(),
             _ => self.class(kind).into(),
         }
     }
 }
 
+pub enum TextSpanStyle {
+    ContentParagraphLink,
+    ContentParagraphCode,
+}
+
+pub trait ThemableTextSpan {
+    fn themed(self, kind: TextSpanStyle) -> Self;
+}
+
+impl<'a, Link> ThemableTextSpan for Span<'a, Link> {
+    fn themed(self, kind: TextSpanStyle) -> Self {
+        let theme = get_theme();
+
+        match kind {
+            TextSpanStyle::ContentParagraphLink => self.color(theme.content_paragraph_link.text_color).underline(true),
+            TextSpanStyle::ContentParagraphCode => {
+                self.font(Font::MONOSPACE)
+                    .background(theme.content_code_block_text.background_color)
+            }
+        }
+    }
+}
+
 impl text::Catalog for GauntletComplexTheme {
     type Class<'a> = TextStyle;
 
@@ -69,6 +97,16 @@ impl text::Catalog for GauntletComplexTheme {
                     color: Some(self.empty_view_subtitle.text_color),
                 }
             }
+            TextStyle::FormInputError => {
+                Style {
+                    color: Some(self.form_input_error.text_color),
+                }
+            }
+            TextStyle::FormInputInfo => {
+                Style {
+                    color: Some(self.form_input_info.text_color),
+                }
+            }
             TextStyle::ListItemSubtitle => {
                 Style {
                     color: Some(self.list_item_subtitle.text_color),
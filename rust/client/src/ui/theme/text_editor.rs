@@ -0,0 +1,122 @@
+use iced::advanced::text::highlighter::PlainText;
+use iced::widget::text_editor;
+use iced::widget::text_editor::Status;
+use iced::widget::text_editor::Style;
+use iced::widget::TextEditor;
+use iced::Border;
+use iced::Color;
+use iced::Renderer;
+
+use crate::ui::theme::Element;
+use crate::ui::theme::GauntletComplexTheme;
+use crate::ui::theme::ThemableWidget;
+use crate::ui::theme::NOT_INTENDED_TO_BE_USED;
+
+pub enum TextEditorStyle {
+    ShouldNotBeUsed,
+
+    FormInput,
+}
+
+impl text_editor::Catalog for GauntletComplexTheme {
+    type Class<'a> = TextEditorStyle;
+
+    fn default<'a>() -> Self::Class<'a> {
+        TextEditorStyle::ShouldNotBeUsed
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        match status {
+            Status::Active => active(self, class),
+            Status::Hovered => focused(self, class), // TODO proper style
+            Status::Focused => focused(self, class),
+            Status::Disabled => disabled(),
+        }
+    }
+}
+
+fn active(theme: &GauntletComplexTheme, style: &TextEditorStyle) -> Style {
+    match style {
+        TextEditorStyle::ShouldNotBeUsed => not_intended_to_be_used(),
+        TextEditorStyle::FormInput => {
+            let theme = &theme.form_input_text_field;
+
+            Style {
+                background: theme.background_color.into(),
+                border: Border {
+                    radius: theme.border_radius.into(),
+                    width: theme.border_width,
+                    color: theme.border_color.into(),
+                },
+                icon: NOT_INTENDED_TO_BE_USED,
+                placeholder: theme.text_color_placeholder,
+                value: theme.text_color,
+                selection: theme.selection_color,
+            }
+        }
+    }
+}
+
+fn focused(theme: &GauntletComplexTheme, style: &TextEditorStyle) -> Style {
+    match style {
+        TextEditorStyle::ShouldNotBeUsed => not_intended_to_be_used(),
+        TextEditorStyle::FormInput => {
+            let theme = &theme.form_input_text_field;
+
+            Style {
+                background: theme.background_color_hovered.into(),
+                border: Border {
+                    radius: theme.border_radius.into(),
+                    width: theme.border_width,
+                    color: theme.border_color_hovered.into(),
+                },
+                icon: NOT_INTENDED_TO_BE_USED,
+                placeholder: theme.text_color_placeholder,
+                value: theme.text_color,
+                selection: theme.selection_color,
+            }
+        }
+    }
+}
+
+fn not_intended_to_be_used() -> Style {
+    Style {
+        background: NOT_INTENDED_TO_BE_USED.into(),
+        border: Border {
+            color: NOT_INTENDED_TO_BE_USED.into(),
+            ..Border::default()
+        },
+        icon: NOT_INTENDED_TO_BE_USED,
+        placeholder: NOT_INTENDED_TO_BE_USED,
+        value: NOT_INTENDED_TO_BE_USED,
+        selection: NOT_INTENDED_TO_BE_USED,
+    }
+}
+
+fn disabled() -> Style {
+    Style {
+        background: NOT_INTENDED_TO_BE_USED.into(),
+        border: Border {
+            radius: 2.0.into(),
+            width: 1.0,
+            color: Color::TRANSPARENT,
+        },
+        icon: NOT_INTENDED_TO_BE_USED,
+        placeholder: NOT_INTENDED_TO_BE_USED,
+        value: NOT_INTENDED_TO_BE_USED,
+        selection: NOT_INTENDED_TO_BE_USED,
+    }
+}
+
+impl<'a, Message: 'a + Clone> ThemableWidget<'a, Message>
+    for TextEditor<'a, PlainText, Message, GauntletComplexTheme, Renderer>
+{
+    type Kind = TextEditorStyle;
+
+    fn themed(self, kind: TextEditorStyle) -> Element<'a, Message> {
+        match kind {
+            TextEditorStyle::FormInput => self.class(kind).height(120).into(),
+            TextEditorStyle::ShouldNotBeUsed => self.class(kind).into(),
+        }
+    }
+}
@@ -23,7 +23,9 @@ impl text_input::Catalog for GauntletComplexTheme {
     type Class<'a> = TextInputStyle;
 
     fn default<'a>() -> Self::Class<'a> {
-        TextInputStyle::ShouldNotBeUsed
+        // TODO Not supposed to be default but unable to customize number input text field right now
+        // TextInputStyle::ShouldNotBeUsed
+        TextInputStyle::FormInput
     }
 
     fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
@@ -9,20 +9,38 @@ use gauntlet_common::model::H5Widget;
 use gauntlet_common::model::H6Widget;
 use gauntlet_common::model::HorizontalBreakWidget;
 use gauntlet_common::model::ImageWidget;
+use gauntlet_common::model::MarkdownWidget;
 use gauntlet_common::model::ParagraphWidget;
+use gauntlet_common::model::ParagraphWidgetOrderedMembers;
+use gauntlet_common::model::UiWidgetId;
 use iced::alignment::Horizontal;
 use iced::alignment::Vertical;
+use iced::font::Style;
+use iced::font::Weight;
 use iced::widget::column;
 use iced::widget::container;
 use iced::widget::horizontal_rule;
+use iced::widget::rich_text;
+use iced::widget::row;
+use iced::widget::span;
+use iced::widget::text::Span;
+use iced::Font;
 use iced::Length;
+use iced_fonts::Bootstrap;
+use iced_fonts::BOOTSTRAP_FONT;
+use pulldown_cmark::HeadingLevel;
 
 use crate::ui::theme::container::ContainerStyle;
+use crate::ui::theme::text::TextSpanStyle;
+use crate::ui::theme::text::ThemableTextSpan;
 use crate::ui::theme::Element;
 use crate::ui::theme::ThemableWidget;
 use crate::ui::widget::data::ComponentWidgets;
 use crate::ui::widget::events::ComponentWidgetEvent;
 use crate::ui::widget::images::render_image;
+use crate::ui::widget::markdown::parse_markdown;
+use crate::ui::widget::markdown::MarkdownBlock;
+use crate::ui::widget::markdown::MarkdownSpan;
 use crate::ui::widget::text::TextRenderType;
 
 impl<'b> ComponentWidgets<'b> {
@@ -31,7 +49,40 @@ impl<'b> ComponentWidgets<'b> {
         widget: &ParagraphWidget,
         centered: bool,
     ) -> Element<'a, ComponentWidgetEvent> {
-        let paragraph: Element<_> = self.render_text(&widget.content.text, TextRenderType::None);
+        let widget_id = widget.__id__;
+
+        let spans: Vec<Span<'a, String>> = widget
+            .content
+            .ordered_members
+            .iter()
+            .map(|members| {
+                match members {
+                    ParagraphWidgetOrderedMembers::Text(value) => span(value.to_owned()),
+                    ParagraphWidgetOrderedMembers::Link(widget) => {
+                        span(widget.content.text.join(""))
+                            .link(widget.href.to_owned())
+                            .themed(TextSpanStyle::ContentParagraphLink)
+                    }
+                    ParagraphWidgetOrderedMembers::Code(widget) => {
+                        span(widget.content.text.join("")).themed(TextSpanStyle::ContentParagraphCode)
+                    }
+                }
+            })
+            .collect();
+
+        self.render_paragraph(spans, widget_id, centered)
+    }
+
+    fn render_paragraph<'a>(
+        &self,
+        spans: Vec<Span<'a, String>>,
+        widget_id: UiWidgetId,
+        centered: bool,
+    ) -> Element<'a, ComponentWidgetEvent> {
+        // rich text produces link of the clicked span as a message
+        let paragraph: Element<'a, String> = rich_text(spans).into();
+
+        let paragraph: Element<_> = paragraph.map(move |href| ComponentWidgetEvent::LinkClick { widget_id, href });
 
         let mut content = container(paragraph).width(Length::Fill);
 
@@ -80,6 +131,10 @@ impl<'b> ComponentWidgets<'b> {
     }
 
     fn render_horizontal_break_widget<'a>(&self, _widget: &HorizontalBreakWidget) -> Element<'a, ComponentWidgetEvent> {
+        self.render_horizontal_break()
+    }
+
+    fn render_horizontal_break<'a>(&self) -> Element<'a, ComponentWidgetEvent> {
         let separator: Element<_> = horizontal_rule(1).into();
 
         container(separator)
@@ -88,7 +143,11 @@ impl<'b> ComponentWidgets<'b> {
     }
 
     fn render_code_block_widget<'a>(&self, widget: &CodeBlockWidget) -> Element<'a, ComponentWidgetEvent> {
-        let content: Element<_> = self.render_text(&widget.content.text, TextRenderType::None);
+        self.render_code_block(&widget.content.text)
+    }
+
+    fn render_code_block<'a>(&self, value: &[String]) -> Element<'a, ComponentWidgetEvent> {
+        let content: Element<_> = self.render_text(value, TextRenderType::None);
 
         let content = container(content)
             .width(Length::Fill)
@@ -99,6 +158,96 @@ impl<'b> ComponentWidgets<'b> {
             .themed(ContainerStyle::ContentCodeBlock)
     }
 
+    fn render_markdown_widget<'a>(&self, widget: &MarkdownWidget, centered: bool) -> Element<'a, ComponentWidgetEvent> {
+        let widget_id = widget.__id__;
+
+        let content: Vec<_> = parse_markdown(&widget.content.text.join(""))
+            .into_iter()
+            .map(|block| {
+                match block {
+                    MarkdownBlock::Heading { level, spans } => {
+                        let value = spans
+                            .into_iter()
+                            .filter_map(|span| {
+                                match span {
+                                    MarkdownSpan::Text { value, .. } => Some(value),
+                                    MarkdownSpan::TaskMarker { .. } => None,
+                                }
+                            })
+                            .collect::<Vec<_>>();
+
+                        let render_type = match level {
+                            HeadingLevel::H1 => TextRenderType::H1,
+                            HeadingLevel::H2 => TextRenderType::H2,
+                            HeadingLevel::H3 => TextRenderType::H3,
+                            HeadingLevel::H4 => TextRenderType::H4,
+                            HeadingLevel::H5 => TextRenderType::H5,
+                            HeadingLevel::H6 => TextRenderType::H6,
+                        };
+
+                        self.render_text(&value, render_type)
+                    }
+                    MarkdownBlock::Paragraph { spans } => {
+                        self.render_paragraph(markdown_spans(spans), widget_id, centered)
+                    }
+                    MarkdownBlock::CodeBlock { value } => self.render_code_block(&[value]),
+                    MarkdownBlock::HorizontalBreak => self.render_horizontal_break(),
+                    MarkdownBlock::Table { head, rows } => {
+                        let head: Element<_> = self.render_markdown_table_row(head, widget_id, true);
+
+                        let rows: Vec<_> = rows
+                            .into_iter()
+                            .map(|row| self.render_markdown_table_row(row, widget_id, false))
+                            .collect();
+
+                        let table: Element<_> = column([head, horizontal_rule(1).into()]).extend(rows).into();
+
+                        container(table)
+                            .width(Length::Fill)
+                            .themed(ContainerStyle::ContentParagraph)
+                    }
+                }
+            })
+            .collect();
+
+        column(content).into()
+    }
+
+    fn render_markdown_table_row<'a>(
+        &self,
+        cells: Vec<Vec<MarkdownSpan>>,
+        widget_id: UiWidgetId,
+        head: bool,
+    ) -> Element<'a, ComponentWidgetEvent> {
+        let cells: Vec<Element<_>> = cells
+            .into_iter()
+            .map(|spans| {
+                let mut spans = markdown_spans(spans);
+
+                if head {
+                    spans = spans
+                        .into_iter()
+                        .map(|span| {
+                            span.font(Font {
+                                weight: Weight::Bold,
+                                ..Font::DEFAULT
+                            })
+                        })
+                        .collect();
+                }
+
+                // rich text produces link of the clicked span as a message
+                let cell: Element<'a, String> = rich_text(spans).into();
+
+                let cell: Element<_> = cell.map(move |href| ComponentWidgetEvent::LinkClick { widget_id, href });
+
+                container(cell).width(Length::Fill).into()
+            })
+            .collect();
+
+        row(cells).spacing(8).into()
+    }
+
     pub fn render_content_widget<'a>(
         &self,
         widget: &ContentWidget,
@@ -120,6 +269,7 @@ impl<'b> ComponentWidgets<'b> {
                     ContentWidgetOrderedMembers::H6(widget) => self.render_h6_widget(widget),
                     ContentWidgetOrderedMembers::HorizontalBreak(widget) => self.render_horizontal_break_widget(widget),
                     ContentWidgetOrderedMembers::CodeBlock(widget) => self.render_code_block_widget(widget),
+                    ContentWidgetOrderedMembers::Markdown(widget) => self.render_markdown_widget(widget, centered),
                 }
             })
             .collect();
@@ -138,3 +288,47 @@ impl<'b> ComponentWidgets<'b> {
         }
     }
 }
+
+fn markdown_spans<'a>(spans: Vec<MarkdownSpan>) -> Vec<Span<'a, String>> {
+    spans
+        .into_iter()
+        .map(|markdown_span| {
+            match markdown_span {
+                MarkdownSpan::Text {
+                    value,
+                    strong,
+                    emphasis,
+                    code,
+                    href,
+                } => {
+                    let mut result = span(value);
+
+                    if code {
+                        result = result.themed(TextSpanStyle::ContentParagraphCode)
+                    } else if strong || emphasis {
+                        result = result.font(Font {
+                            weight: if strong { Weight::Bold } else { Weight::Normal },
+                            style: if emphasis { Style::Italic } else { Style::Normal },
+                            ..Font::DEFAULT
+                        })
+                    }
+
+                    if let Some(href) = href {
+                        result = result.link(href).themed(TextSpanStyle::ContentParagraphLink)
+                    }
+
+                    result
+                }
+                MarkdownSpan::TaskMarker { checked } => {
+                    let icon = if checked {
+                        Bootstrap::CheckSquare
+                    } else {
+                        Bootstrap::Square
+                    };
+
+                    span(icon.to_string()).font(BOOTSTRAP_FONT)
+                }
+            }
+        })
+        .collect()
+}
@@ -1,8 +1,13 @@
+use std::cmp::Ordering;
 use std::collections::HashMap;
 use std::sync::Arc;
 
 use gauntlet_common::model::ActionPanelSectionWidgetOrderedMembers;
+use gauntlet_common::model::ActionPanelWidget;
 use gauntlet_common::model::ActionPanelWidgetOrderedMembers;
+use gauntlet_common::model::DatePickerMode;
+use gauntlet_common::model::FormWidget;
+use gauntlet_common::model::FormWidgetOrderedMembers;
 use gauntlet_common::model::GridSectionWidgetOrderedMembers;
 use gauntlet_common::model::GridWidget;
 use gauntlet_common::model::GridWidgetOrderedMembers;
@@ -13,22 +18,40 @@ use gauntlet_common::model::PhysicalShortcut;
 use gauntlet_common::model::PluginId;
 use gauntlet_common::model::RootWidget;
 use gauntlet_common::model::RootWidgetMembers;
+use gauntlet_common::model::SearchBarDropdownWidget;
+use gauntlet_common::model::TableRowWidget;
+use gauntlet_common::model::TableRowWidgetOrderedMembers;
+use gauntlet_common::model::TableWidget;
+use gauntlet_common::model::TableWidgetOrderedMembers;
+use gauntlet_common::model::UiPropertyValue;
 use gauntlet_common::model::UiRenderLocation;
 use gauntlet_common::model::UiWidgetId;
 use iced::widget::text_input;
 use iced::Task;
 
+use crate::model::UiViewEvent;
+use crate::ui::grid_navigation::grid_rows_remaining;
 use crate::ui::grid_navigation::GridSectionData;
 use crate::ui::scroll_handle::ScrollHandle;
 use crate::ui::widget::action_panel::convert_action_panel;
 use crate::ui::widget::action_panel::ActionPanel;
+use crate::ui::widget::events::date_picker_value;
+use crate::ui::widget::events::text_area_value;
 use crate::ui::widget::events::ComponentWidgetEvent;
 use crate::ui::widget::grid::grid_width;
+use crate::ui::widget::search_bar::search_bar_dropdown_items;
 use crate::ui::widget::state::CheckboxState;
 use crate::ui::widget::state::ComponentWidgetState;
 use crate::ui::widget::state::DatePickerState;
+use crate::ui::widget::state::FilePickerState;
+use crate::ui::widget::state::MultiSelectState;
+use crate::ui::widget::state::NumberFieldState;
 use crate::ui::widget::state::RootState;
+use crate::ui::widget::state::SearchBarDropdownState;
 use crate::ui::widget::state::SelectState;
+use crate::ui::widget::state::TableColumnState;
+use crate::ui::widget::state::TableSortDirection;
+use crate::ui::widget::state::TextAreaState;
 use crate::ui::widget::state::TextFieldState;
 use crate::ui::AppMsg;
 
@@ -67,6 +90,30 @@ impl<'b> ComponentWidgets<'b> {
         }
     }
 
+    pub fn number_field_state(&self, widget_id: UiWidgetId) -> &NumberFieldState {
+        let state = self.state.get(&widget_id).expect(&format!(
+            "requested state should always be present for id: {}",
+            widget_id
+        ));
+
+        match state {
+            ComponentWidgetState::NumberField(state) => state,
+            _ => panic!("NumberFieldState expected, {:?} found", state),
+        }
+    }
+
+    pub fn text_area_state(&self, widget_id: UiWidgetId) -> &TextAreaState {
+        let state = self.state.get(&widget_id).expect(&format!(
+            "requested state should always be present for id: {}",
+            widget_id
+        ));
+
+        match state {
+            ComponentWidgetState::TextArea(state) => state,
+            _ => panic!("TextAreaState expected, {:?} found", state),
+        }
+    }
+
     pub fn checkbox_state(&self, widget_id: UiWidgetId) -> &CheckboxState {
         let state = self.state.get(&widget_id).expect(&format!(
             "requested state should always be present for id: {}",
@@ -103,6 +150,54 @@ impl<'b> ComponentWidgets<'b> {
         }
     }
 
+    pub fn multi_select_state(&self, widget_id: UiWidgetId) -> &MultiSelectState {
+        let state = self.state.get(&widget_id).expect(&format!(
+            "requested state should always be present for id: {}",
+            widget_id
+        ));
+
+        match state {
+            ComponentWidgetState::MultiSelect(state) => state,
+            _ => panic!("MultiSelectState expected, {:?} found", state),
+        }
+    }
+
+    pub fn file_picker_state(&self, widget_id: UiWidgetId) -> &FilePickerState {
+        let state = self.state.get(&widget_id).expect(&format!(
+            "requested state should always be present for id: {}",
+            widget_id
+        ));
+
+        match state {
+            ComponentWidgetState::FilePicker(state) => state,
+            _ => panic!("FilePickerState expected, {:?} found", state),
+        }
+    }
+
+    pub fn search_bar_dropdown_state(&self, widget_id: UiWidgetId) -> &SearchBarDropdownState {
+        let state = self.state.get(&widget_id).expect(&format!(
+            "requested state should always be present for id: {}",
+            widget_id
+        ));
+
+        match state {
+            ComponentWidgetState::SearchBarDropdown(state) => state,
+            _ => panic!("SearchBarDropdownState expected, {:?} found", state),
+        }
+    }
+
+    pub fn table_column_state(&self, widget_id: UiWidgetId) -> &TableColumnState {
+        let state = self.state.get(&widget_id).expect(&format!(
+            "requested state should always be present for id: {}",
+            widget_id
+        ));
+
+        match state {
+            ComponentWidgetState::TableColumn(state) => state,
+            _ => panic!("TableColumnState expected, {:?} found", state),
+        }
+    }
+
     pub fn root_state(&self, widget_id: UiWidgetId) -> &RootState {
         let state = self.state.get(&widget_id).expect(&format!(
             "requested state should always be present for id: {}",
@@ -132,8 +227,21 @@ impl<'b> ComponentWidgets<'b> {
             RootWidgetMembers::Inline(widget) => &widget.content.actions,
             RootWidgetMembers::List(widget) => &widget.content.actions,
             RootWidgetMembers::Grid(widget) => &widget.content.actions,
+            RootWidgetMembers::Table(widget) => {
+                let RootState { focused_item, .. } = self.root_state(widget.__id__);
+
+                let sort = ComponentWidgets::table_sort(self.state, widget);
+
+                let focused_row = ComponentWidgets::table_focused_row(focused_item, widget, &sort);
+
+                ComponentWidgets::table_actions(focused_row, widget)
+            }
         };
 
+        ComponentWidgets::action_ids(actions)
+    }
+
+    pub fn action_ids(actions: &Option<ActionPanelWidget>) -> Vec<UiWidgetId> {
         let mut result = vec![];
         match actions {
             None => {}
@@ -181,6 +289,13 @@ impl<'b> ComponentWidgets<'b> {
 
                 ComponentWidgets::grid_focused_item_id(focused_item, widget)
             }
+            RootWidgetMembers::Table(widget) => {
+                let RootState { focused_item, .. } = self.root_state(widget.__id__);
+
+                let sort = ComponentWidgets::table_sort(self.state, widget);
+
+                ComponentWidgets::table_focused_row(focused_item, widget, &sort).map(|row| row.id.to_string())
+            }
         }
     }
 
@@ -285,12 +400,202 @@ impl<'b> ComponentWidgets<'b> {
                     Some(widget) => widget.__id__,
                 }
             }
+            RootWidgetMembers::Table(widget) => {
+                match &widget.content.search_bar {
+                    None => return AppMsg::Noop,
+                    Some(widget) => widget.__id__,
+                }
+            }
             _ => return AppMsg::Noop,
         };
 
         AppMsg::FocusPluginViewSearchBar { widget_id }
     }
 
+    fn search_bar_dropdown(&self) -> Option<&SearchBarDropdownWidget> {
+        let content = self.root_widget.as_ref()?.content.as_ref()?;
+
+        let search_bar = match content {
+            RootWidgetMembers::List(widget) => &widget.content.search_bar,
+            RootWidgetMembers::Grid(widget) => &widget.content.search_bar,
+            RootWidgetMembers::Table(widget) => &widget.content.search_bar,
+            _ => return None,
+        };
+
+        search_bar.as_ref()?.content.dropdown.as_ref()
+    }
+
+    pub fn has_search_bar_dropdown(&self) -> bool {
+        self.search_bar_dropdown().is_some()
+    }
+
+    pub fn select_next_search_bar_dropdown_item(&self, reverse: bool) -> Task<AppMsg> {
+        let Some(widget) = self.search_bar_dropdown() else {
+            return Task::none();
+        };
+
+        let SearchBarDropdownState { state_value } = self.search_bar_dropdown_state(widget.__id__);
+
+        let items = search_bar_dropdown_items(widget);
+
+        if items.is_empty() {
+            return Task::none();
+        }
+
+        let current_index = state_value
+            .as_ref()
+            .and_then(|value| items.iter().position(|item| &item.value == value));
+
+        let next_index = match (current_index, reverse) {
+            (None, false) => 0,
+            (None, true) => items.len() - 1,
+            (Some(index), false) => (index + 1) % items.len(),
+            (Some(index), true) => (index + items.len() - 1) % items.len(),
+        };
+
+        let widget_event = ComponentWidgetEvent::SelectSearchBarDropdownItem {
+            widget_id: widget.__id__,
+            value: items[next_index].value.clone(),
+        };
+
+        Task::done(AppMsg::WidgetEvent {
+            plugin_id: self.plugin_id.clone(),
+            render_location: UiRenderLocation::View,
+            widget_event,
+        })
+    }
+
+    pub fn submit_form(&self) -> Task<AppMsg> {
+        let Some(root_widget) = &self.root_widget else {
+            return Task::none();
+        };
+
+        let Some(RootWidgetMembers::Form(widget)) = &root_widget.content else {
+            return Task::none();
+        };
+
+        let widget_event = ComponentWidgetEvent::SubmitForm {
+            widget_id: widget.__id__,
+            values: self.form_values(widget),
+        };
+
+        Task::done(AppMsg::WidgetEvent {
+            plugin_id: self.plugin_id.clone(),
+            render_location: UiRenderLocation::View,
+            widget_event,
+        })
+    }
+
+    fn form_values(&self, widget: &FormWidget) -> HashMap<String, UiPropertyValue> {
+        let mut values = HashMap::new();
+
+        for members in &widget.content.ordered_members {
+            let (id, value) = match members {
+                FormWidgetOrderedMembers::TextField(widget) => {
+                    let TextFieldState { state_value, .. } = self.text_field_state(widget.__id__);
+
+                    (&widget.id, Some(UiPropertyValue::String(state_value.clone())))
+                }
+                FormWidgetOrderedMembers::PasswordField(widget) => {
+                    let TextFieldState { state_value, .. } = self.text_field_state(widget.__id__);
+
+                    (&widget.id, Some(UiPropertyValue::String(state_value.clone())))
+                }
+                FormWidgetOrderedMembers::NumberField(widget) => {
+                    let NumberFieldState { state_value } = self.number_field_state(widget.__id__);
+
+                    (&widget.id, Some(UiPropertyValue::Number(*state_value)))
+                }
+                FormWidgetOrderedMembers::TextArea(widget) => {
+                    let TextAreaState { state_value } = self.text_area_state(widget.__id__);
+
+                    (&widget.id, Some(UiPropertyValue::String(text_area_value(state_value))))
+                }
+                FormWidgetOrderedMembers::Checkbox(widget) => {
+                    let CheckboxState { state_value } = self.checkbox_state(widget.__id__);
+
+                    (&widget.id, Some(UiPropertyValue::Bool(*state_value)))
+                }
+                FormWidgetOrderedMembers::DatePicker(widget) => {
+                    let state = self.date_picker_state(widget.__id__);
+
+                    let mode = widget.mode.as_ref().unwrap_or(&DatePickerMode::Date);
+
+                    (
+                        &widget.id,
+                        Some(UiPropertyValue::String(date_picker_value(mode, state))),
+                    )
+                }
+                FormWidgetOrderedMembers::Select(widget) => {
+                    let SelectState { state_value } = self.select_state(widget.__id__);
+
+                    (&widget.id, state_value.clone().map(UiPropertyValue::String))
+                }
+                FormWidgetOrderedMembers::MultiSelect(widget) => {
+                    let MultiSelectState { state_value, .. } = self.multi_select_state(widget.__id__);
+
+                    let value = state_value.iter().cloned().map(UiPropertyValue::String).collect();
+
+                    (&widget.id, Some(UiPropertyValue::Array(value)))
+                }
+                FormWidgetOrderedMembers::FilePicker(widget) => {
+                    let FilePickerState { state_value, .. } = self.file_picker_state(widget.__id__);
+
+                    let value = state_value.iter().cloned().map(UiPropertyValue::String).collect();
+
+                    (&widget.id, Some(UiPropertyValue::Array(value)))
+                }
+                FormWidgetOrderedMembers::Separator(_) => continue,
+            };
+
+            if let (Some(id), Some(value)) = (id, value) {
+                values.insert(id.clone(), value);
+            }
+        }
+
+        values
+    }
+
+    pub fn first_invalid_form_field(&self) -> AppMsg {
+        let Some(root_widget) = &self.root_widget else {
+            return AppMsg::Noop;
+        };
+
+        let Some(RootWidgetMembers::Form(widget)) = &root_widget.content else {
+            return AppMsg::Noop;
+        };
+
+        let widget_id = widget.content.ordered_members.iter().find_map(|members| {
+            match members {
+                FormWidgetOrderedMembers::TextField(widget) => widget.error.as_ref().map(|_| widget.__id__),
+                FormWidgetOrderedMembers::PasswordField(widget) => widget.error.as_ref().map(|_| widget.__id__),
+                FormWidgetOrderedMembers::NumberField(widget) => widget.error.as_ref().map(|_| widget.__id__),
+                FormWidgetOrderedMembers::TextArea(widget) => widget.error.as_ref().map(|_| widget.__id__),
+                FormWidgetOrderedMembers::Checkbox(widget) => widget.error.as_ref().map(|_| widget.__id__),
+                FormWidgetOrderedMembers::DatePicker(widget) => widget.error.as_ref().map(|_| widget.__id__),
+                FormWidgetOrderedMembers::Select(widget) => widget.error.as_ref().map(|_| widget.__id__),
+                FormWidgetOrderedMembers::MultiSelect(widget) => widget.error.as_ref().map(|_| widget.__id__),
+                FormWidgetOrderedMembers::FilePicker(widget) => widget.error.as_ref().map(|_| widget.__id__),
+                FormWidgetOrderedMembers::Separator(_) => None,
+            }
+        });
+
+        match widget_id {
+            None => AppMsg::Noop,
+            Some(widget_id) => AppMsg::FocusPluginViewFormField { widget_id },
+        }
+    }
+
+    pub fn focus_form_field(&self, widget_id: UiWidgetId) -> Task<AppMsg> {
+        match self.state.get(&widget_id) {
+            Some(ComponentWidgetState::TextField(TextFieldState { text_input_id, .. })) => {
+                text_input::focus(text_input_id.clone())
+            }
+            // other inputs cannot hold keyboard focus
+            _ => Task::none(),
+        }
+    }
+
     pub fn list_focused_item_id(focused_item: &ScrollHandle, widget: &ListWidget) -> Option<String> {
         let mut items = vec![];
 
@@ -397,6 +702,201 @@ impl<'b> ComponentWidgets<'b> {
         })
     }
 
+    pub fn list_item_count(widget: &ListWidget) -> usize {
+        widget
+            .content
+            .ordered_members
+            .iter()
+            .map(|members| {
+                match members {
+                    ListWidgetOrderedMembers::ListItem(_) => 1,
+                    ListWidgetOrderedMembers::ListSection(widget) => widget.content.ordered_members.len(),
+                }
+            })
+            .sum()
+    }
+
+    pub fn grid_item_count(widget: &GridWidget) -> usize {
+        ComponentWidgets::grid_section_sizes(widget)
+            .iter()
+            .map(|data| data.amount_in_section)
+            .sum()
+    }
+
+    pub fn list_load_more_event(plugin_id: PluginId, focused_item: &ScrollHandle, widget: &ListWidget) -> Task<AppMsg> {
+        if !widget.has_more.unwrap_or(false) {
+            return Task::none();
+        }
+
+        let Some(index) = focused_item.index else {
+            return Task::none();
+        };
+
+        let item_count = ComponentWidgets::list_item_count(widget);
+
+        if !focused_item.is_near_end(item_count.saturating_sub(index + 1)) {
+            return Task::none();
+        }
+
+        Task::done(AppMsg::WidgetEvent {
+            plugin_id,
+            render_location: UiRenderLocation::View,
+            widget_event: ComponentWidgetEvent::LoadMoreListItems {
+                list_widget_id: widget.__id__,
+                item_count,
+            },
+        })
+    }
+
+    pub fn grid_load_more_event(plugin_id: PluginId, focused_item: &ScrollHandle, widget: &GridWidget) -> Task<AppMsg> {
+        if !widget.has_more.unwrap_or(false) {
+            return Task::none();
+        }
+
+        let Some(index) = focused_item.index else {
+            return Task::none();
+        };
+
+        let amount_per_section_total = ComponentWidgets::grid_section_sizes(widget);
+
+        if !focused_item.is_near_end(grid_rows_remaining(index, &amount_per_section_total)) {
+            return Task::none();
+        }
+
+        let item_count = amount_per_section_total.iter().map(|data| data.amount_in_section).sum();
+
+        Task::done(AppMsg::WidgetEvent {
+            plugin_id,
+            render_location: UiRenderLocation::View,
+            widget_event: ComponentWidgetEvent::LoadMoreGridItems {
+                grid_widget_id: widget.__id__,
+                item_count,
+            },
+        })
+    }
+
+    /// index of the column and direction for every column rows are sorted by, in order of columns
+    pub fn table_sort(
+        state: &HashMap<UiWidgetId, ComponentWidgetState>,
+        widget: &TableWidget,
+    ) -> Vec<(usize, TableSortDirection)> {
+        widget
+            .content
+            .ordered_members
+            .iter()
+            .filter_map(|members| {
+                match members {
+                    TableWidgetOrderedMembers::TableColumn(widget) => Some(widget),
+                    TableWidgetOrderedMembers::TableRow(_) => None,
+                }
+            })
+            .enumerate()
+            .filter(|(_, column)| column.sortable.unwrap_or(false))
+            .filter_map(|(index, column)| {
+                match state.get(&column.__id__) {
+                    Some(ComponentWidgetState::TableColumn(TableColumnState { sort: Some(direction) })) => {
+                        Some((index, *direction))
+                    }
+                    _ => None,
+                }
+            })
+            .collect()
+    }
+
+    pub fn table_rows<'a>(widget: &'a TableWidget, sort: &[(usize, TableSortDirection)]) -> Vec<&'a TableRowWidget> {
+        let mut rows: Vec<_> = widget
+            .content
+            .ordered_members
+            .iter()
+            .filter_map(|members| {
+                match members {
+                    TableWidgetOrderedMembers::TableColumn(_) => None,
+                    TableWidgetOrderedMembers::TableRow(widget) => Some(widget),
+                }
+            })
+            .collect();
+
+        if !sort.is_empty() {
+            rows.sort_by(|row_a, row_b| {
+                sort.iter()
+                    .map(|(index, direction)| {
+                        let ordering =
+                            compare_table_cells(table_cell_value(row_a, *index), table_cell_value(row_b, *index));
+
+                        match direction {
+                            TableSortDirection::Ascending => ordering,
+                            TableSortDirection::Descending => ordering.reverse(),
+                        }
+                    })
+                    .find(|ordering| ordering.is_ne())
+                    .unwrap_or(Ordering::Equal)
+            });
+        }
+
+        rows
+    }
+
+    pub fn table_focused_row<'a>(
+        focused_item: &ScrollHandle,
+        widget: &'a TableWidget,
+        sort: &[(usize, TableSortDirection)],
+    ) -> Option<&'a TableRowWidget> {
+        let rows = ComponentWidgets::table_rows(widget, sort);
+
+        focused_item.get(&rows).copied()
+    }
+
+    /// focused row can override action panel of the table
+    pub fn table_actions<'a>(
+        focused_row: Option<&'a TableRowWidget>,
+        widget: &'a TableWidget,
+    ) -> &'a Option<ActionPanelWidget> {
+        match focused_row {
+            Some(row) if row.content.actions.is_some() => &row.content.actions,
+            _ => &widget.content.actions,
+        }
+    }
+
+    pub fn table_row_focus_event(
+        plugin_id: PluginId,
+        focused_item: &ScrollHandle,
+        widget: &TableWidget,
+        sort: &[(usize, TableSortDirection)],
+    ) -> Task<AppMsg> {
+        let row_id = ComponentWidgets::table_focused_row(focused_item, widget, sort).map(|row| row.id.to_string());
+
+        let widget_event = ComponentWidgetEvent::FocusTableRow {
+            table_widget_id: widget.__id__,
+            row_id,
+        };
+
+        Task::done(AppMsg::WidgetEvent {
+            plugin_id,
+            render_location: UiRenderLocation::View,
+            widget_event,
+        })
+    }
+
+    // sorting reorders rows while focused index stays the same, so row under focus changes
+    pub fn table_sort_focus_event(&self) -> Option<UiViewEvent> {
+        let Some(RootWidgetMembers::Table(widget)) = self.root_widget.as_ref()?.content.as_ref() else {
+            return None;
+        };
+
+        let widget_event = ComponentWidgetEvent::FocusTableRow {
+            table_widget_id: widget.__id__,
+            row_id: self.get_focused_item_id(),
+        };
+
+        Some(UiViewEvent::AppEvent {
+            event: AppMsg::WidgetEvent {
+                plugin_id: self.plugin_id.clone(),
+                render_location: UiRenderLocation::View,
+                widget_event,
+            },
+        })
+    }
+
     pub fn get_action_panel(&self, action_shortcuts: &HashMap<String, PhysicalShortcut>) -> Option<ActionPanel> {
         let Some(root_widget) = &self.root_widget else {
             return None;
@@ -412,6 +912,40 @@ impl<'b> ComponentWidgets<'b> {
             RootWidgetMembers::Inline(widget) => convert_action_panel(&widget.content.actions, action_shortcuts),
             RootWidgetMembers::List(widget) => convert_action_panel(&widget.content.actions, action_shortcuts),
             RootWidgetMembers::Grid(widget) => convert_action_panel(&widget.content.actions, action_shortcuts),
+            RootWidgetMembers::Table(widget) => {
+                let RootState { focused_item, .. } = self.root_state(widget.__id__);
+
+                let sort = ComponentWidgets::table_sort(self.state, widget);
+
+                let focused_row = ComponentWidgets::table_focused_row(focused_item, widget, &sort);
+
+                convert_action_panel(ComponentWidgets::table_actions(focused_row, widget), action_shortcuts)
+            }
+        }
+    }
+}
+
+pub fn table_cell_value(row: &TableRowWidget, index: usize) -> Option<String> {
+    row.content
+        .ordered_members
+        .iter()
+        .map(|members| {
+            match members {
+                TableRowWidgetOrderedMembers::TableCell(widget) => widget.content.text.join(""),
+            }
+        })
+        .nth(index)
+}
+
+// numbers are compared by value, everything else as case-insensitive text
+fn compare_table_cells(value_a: Option<String>, value_b: Option<String>) -> Ordering {
+    match (value_a, value_b) {
+        (Some(value_a), Some(value_b)) => {
+            match (value_a.trim().parse::<f64>(), value_b.trim().parse::<f64>()) {
+                (Ok(number_a), Ok(number_b)) => number_a.total_cmp(&number_b),
+                _ => value_a.to_lowercase().cmp(&value_b.to_lowercase()),
+            }
         }
+        (value_a, value_b) => value_a.is_some().cmp(&value_b.is_some()),
     }
 }
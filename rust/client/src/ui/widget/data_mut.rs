@@ -98,6 +98,7 @@ impl<'b> ComponentWidgetsMut<'b> {
             RootWidgetMembers::Inline(widget) => widget.__id__,
             RootWidgetMembers::List(widget) => widget.__id__,
             RootWidgetMembers::Grid(widget) => widget.__id__,
+            RootWidgetMembers::Table(widget) => widget.__id__,
         };
 
         let state = self.root_state_mut(widget_id);
@@ -127,6 +128,12 @@ impl<'b> ComponentWidgetsMut<'b> {
                     Some(widget) => widget.__id__,
                 }
             }
+            RootWidgetMembers::Table(widget) => {
+                match &widget.content.search_bar {
+                    None => return Task::none(),
+                    Some(widget) => widget.__id__,
+                }
+            }
             _ => return Task::none(),
         };
 
@@ -166,6 +173,12 @@ impl<'b> ComponentWidgetsMut<'b> {
                     Some(widget) => widget.__id__,
                 }
             }
+            RootWidgetMembers::Table(widget) => {
+                match &widget.content.search_bar {
+                    None => return Task::none(),
+                    Some(widget) => widget.__id__,
+                }
+            }
             _ => return Task::none(),
         };
 
@@ -230,6 +243,19 @@ impl<'b> ComponentWidgetsMut<'b> {
 
                 Task::batch([item_focus_event, focus_task])
             }
+            RootWidgetMembers::Table(table_widget) => {
+                let sort = ComponentWidgets::table_sort(&self.state, table_widget);
+
+                let RootState { focused_item, .. } =
+                    ComponentWidgetsMut::root_state_mut_on_field(&mut self.state, table_widget.__id__);
+
+                let focus_task = focused_item.focus_previous().unwrap_or_else(|| Task::none());
+
+                let row_focus_event =
+                    ComponentWidgets::table_row_focus_event(self.plugin_id.clone(), focused_item, table_widget, &sort);
+
+                Task::batch([row_focus_event, focus_task])
+            }
         }
     }
 
@@ -278,7 +304,10 @@ impl<'b> ComponentWidgetsMut<'b> {
                 let item_focus_event =
                     ComponentWidgets::list_item_focus_event(self.plugin_id.clone(), focused_item, widget);
 
-                Task::batch([item_focus_event, focus_task])
+                let load_more_event =
+                    ComponentWidgets::list_load_more_event(self.plugin_id.clone(), focused_item, widget);
+
+                Task::batch([item_focus_event, focus_task, load_more_event])
             }
             RootWidgetMembers::Grid(grid_widget) => {
                 let RootState { focused_item, .. } =
@@ -302,7 +331,10 @@ impl<'b> ComponentWidgetsMut<'b> {
                     let item_focus_event =
                         ComponentWidgets::grid_item_focus_event(self.plugin_id.clone(), focused_item, grid_widget);
 
-                    return Task::batch([unfocus, focused_item.scroll_to(0), item_focus_event]);
+                    let load_more_event =
+                        ComponentWidgets::grid_load_more_event(self.plugin_id.clone(), focused_item, grid_widget);
+
+                    return Task::batch([unfocus, focused_item.scroll_to(0), item_focus_event, load_more_event]);
                 };
 
                 let focus_task = match grid_down_offset(*current_index, amount_per_section_total) {
@@ -318,7 +350,25 @@ impl<'b> ComponentWidgetsMut<'b> {
                 let item_focus_event =
                     ComponentWidgets::grid_item_focus_event(self.plugin_id.clone(), focused_item, grid_widget);
 
-                Task::batch([item_focus_event, focus_task])
+                let load_more_event =
+                    ComponentWidgets::grid_load_more_event(self.plugin_id.clone(), focused_item, grid_widget);
+
+                Task::batch([item_focus_event, focus_task, load_more_event])
+            }
+            RootWidgetMembers::Table(table_widget) => {
+                let sort = ComponentWidgets::table_sort(&self.state, table_widget);
+
+                let total = ComponentWidgets::table_rows(table_widget, &sort).len();
+
+                let RootState { focused_item, .. } =
+                    ComponentWidgetsMut::root_state_mut_on_field(&mut self.state, table_widget.__id__);
+
+                let focus_task = focused_item.focus_next(total).unwrap_or_else(|| Task::none());
+
+                let row_focus_event =
+                    ComponentWidgets::table_row_focus_event(self.plugin_id.clone(), focused_item, table_widget, &sort);
+
+                Task::batch([row_focus_event, focus_task])
             }
         }
     }
@@ -337,6 +387,7 @@ impl<'b> ComponentWidgetsMut<'b> {
             RootWidgetMembers::Form(_) => Task::none(),
             RootWidgetMembers::Inline(_) => Task::none(),
             RootWidgetMembers::List(_) => Task::none(),
+            RootWidgetMembers::Table(_) => Task::none(),
             RootWidgetMembers::Grid(grid_widget) => {
                 let RootState { focused_item, .. } =
                     ComponentWidgetsMut::root_state_mut_on_field(&mut self.state, grid_widget.__id__);
@@ -364,6 +415,7 @@ impl<'b> ComponentWidgetsMut<'b> {
             RootWidgetMembers::Form(_) => Task::none(),
             RootWidgetMembers::Inline(_) => Task::none(),
             RootWidgetMembers::List(_) => Task::none(),
+            RootWidgetMembers::Table(_) => Task::none(),
             RootWidgetMembers::Grid(grid_widget) => {
                 let RootState { focused_item, .. } =
                     ComponentWidgetsMut::root_state_mut_on_field(&mut self.state, grid_widget.__id__);
@@ -395,7 +447,13 @@ impl<'b> ComponentWidgetsMut<'b> {
 
                 // focused_item.scroll_to(0)
 
-                ComponentWidgets::grid_item_focus_event(self.plugin_id.clone(), focused_item, grid_widget)
+                let item_focus_event =
+                    ComponentWidgets::grid_item_focus_event(self.plugin_id.clone(), focused_item, grid_widget);
+
+                let load_more_event =
+                    ComponentWidgets::grid_load_more_event(self.plugin_id.clone(), focused_item, grid_widget);
+
+                Task::batch([item_focus_event, load_more_event])
             }
         }
     }
@@ -1,11 +1,26 @@
+use std::collections::HashMap;
+
+use gauntlet_common::model::DatePickerMode;
+use gauntlet_common::model::FilePickerDirectory;
 use gauntlet_common::model::PluginId;
+use gauntlet_common::model::UiPropertyValue;
 use gauntlet_common::model::UiWidgetId;
+use iced::widget::text_editor;
+use iced_aw::date_picker::Date;
 
 use crate::model::UiViewEvent;
 use crate::ui::widget::state::CheckboxState;
 use crate::ui::widget::state::ComponentWidgetState;
 use crate::ui::widget::state::DatePickerState;
+use crate::ui::widget::state::FilePickerState;
+use crate::ui::widget::state::MultiSelectState;
+use crate::ui::widget::state::NumberFieldState;
+use crate::ui::widget::state::RootState;
+use crate::ui::widget::state::SearchBarDropdownState;
 use crate::ui::widget::state::SelectState;
+use crate::ui::widget::state::TableColumnState;
+use crate::ui::widget::state::TableSortDirection;
+use crate::ui::widget::state::TextAreaState;
 use crate::ui::widget::state::TextFieldState;
 use crate::ui::AppMsg;
 
@@ -39,14 +54,33 @@ pub enum ComponentWidgetEvent {
         widget_id: UiWidgetId,
         value: String,
     },
+    OnChangeNumberField {
+        widget_id: UiWidgetId,
+        value: f64,
+    },
+    EditTextArea {
+        widget_id: UiWidgetId,
+        action: text_editor::Action,
+    },
     OnChangeSearchBar {
         widget_id: UiWidgetId,
         value: String,
     },
-    SubmitDatePicker {
+    SelectSearchBarDropdownItem {
         widget_id: UiWidgetId,
         value: String,
     },
+    SubmitDatePicker {
+        widget_id: UiWidgetId,
+        mode: DatePickerMode,
+        value: Date,
+    },
+    OnChangeDatePickerTime {
+        widget_id: UiWidgetId,
+        mode: DatePickerMode,
+        hour: u32,
+        minute: u32,
+    },
     CancelDatePicker {
         widget_id: UiWidgetId,
     },
@@ -58,6 +92,34 @@ pub enum ComponentWidgetEvent {
         widget_id: UiWidgetId,
         value: String,
     },
+    OnChangeMultiSelectSearch {
+        widget_id: UiWidgetId,
+        value: String,
+    },
+    SubmitMultiSelectSearch {
+        widget_id: UiWidgetId,
+        value: String,
+    },
+    ToggleMultiSelectItem {
+        widget_id: UiWidgetId,
+        value: String,
+    },
+    ToggleFilePicker {
+        widget_id: UiWidgetId,
+    },
+    OpenFilePickerDirectory {
+        widget_id: UiWidgetId,
+        path: Option<String>,
+    },
+    SetFilePickerDirectory {
+        widget_id: UiWidgetId,
+        directory: FilePickerDirectory,
+    },
+    ToggleFilePickerPath {
+        widget_id: UiWidgetId,
+        path: String,
+        allow_multiple: bool,
+    },
     ToggleActionPanel {
         widget_id: UiWidgetId,
     },
@@ -69,6 +131,25 @@ pub enum ComponentWidgetEvent {
         grid_widget_id: UiWidgetId,
         item_id: Option<String>,
     },
+    FocusTableRow {
+        table_widget_id: UiWidgetId,
+        row_id: Option<String>,
+    },
+    LoadMoreListItems {
+        list_widget_id: UiWidgetId,
+        item_count: usize,
+    },
+    LoadMoreGridItems {
+        grid_widget_id: UiWidgetId,
+        item_count: usize,
+    },
+    ToggleTableSort {
+        widget_id: UiWidgetId,
+    },
+    SubmitForm {
+        widget_id: UiWidgetId,
+        values: HashMap<String, UiPropertyValue>,
+    },
     PreviousView,
     RunPrimaryAction {
         widget_id: UiWidgetId,
@@ -78,7 +159,7 @@ pub enum ComponentWidgetEvent {
 }
 
 impl ComponentWidgetEvent {
-    pub fn handle(self, _plugin_id: PluginId, state: Option<&mut ComponentWidgetState>) -> Option<UiViewEvent> {
+    pub fn handle(self, plugin_id: PluginId, state: Option<&mut ComponentWidgetState>) -> Option<UiViewEvent> {
         match self {
             ComponentWidgetEvent::LinkClick { widget_id: _, href } => Some(UiViewEvent::Open { href }),
             ComponentWidgetEvent::TagClick { widget_id } => Some(create_metadata_tag_item_on_click_event(widget_id)),
@@ -111,18 +192,44 @@ impl ComponentWidgetEvent {
 
                 None
             }
-            ComponentWidgetEvent::SubmitDatePicker { widget_id, value } => {
+            ComponentWidgetEvent::SubmitDatePicker { widget_id, mode, value } => {
                 let Some(state) = state else {
                     return None;
                 };
 
-                let ComponentWidgetState::DatePicker(DatePickerState { show_picker, .. }) = state else {
+                let ComponentWidgetState::DatePicker(date_picker_state) = state else {
                     panic!("unexpected state kind, widget_id: {:?} state: {:?}", widget_id, state)
                 };
 
-                *show_picker = false;
+                date_picker_state.show_picker = false;
+                date_picker_state.state_value = value;
+
+                Some(create_date_picker_on_change_event(
+                    widget_id,
+                    Some(date_picker_value(&mode, date_picker_state)),
+                ))
+            }
+            ComponentWidgetEvent::OnChangeDatePickerTime {
+                widget_id,
+                mode,
+                hour,
+                minute,
+            } => {
+                let Some(state) = state else {
+                    return None;
+                };
 
-                Some(create_date_picker_on_change_event(widget_id, Some(value)))
+                let ComponentWidgetState::DatePicker(date_picker_state) = state else {
+                    panic!("unexpected state kind, widget_id: {:?} state: {:?}", widget_id, state)
+                };
+
+                date_picker_state.hour = hour;
+                date_picker_state.minute = minute;
+
+                Some(create_date_picker_on_change_event(
+                    widget_id,
+                    Some(date_picker_value(&mode, date_picker_state)),
+                ))
             }
             ComponentWidgetEvent::ToggleCheckbox { widget_id, value } => {
                 let Some(state) = state else {
@@ -150,6 +257,133 @@ impl ComponentWidgetEvent {
 
                 Some(create_select_on_change_event(widget_id, Some(value)))
             }
+            ComponentWidgetEvent::OnChangeMultiSelectSearch { widget_id, value } => {
+                let Some(state) = state else {
+                    return None;
+                };
+
+                let ComponentWidgetState::MultiSelect(MultiSelectState { search_value, .. }) = state else {
+                    panic!("unexpected state kind, widget_id: {:?} state: {:?}", widget_id, state)
+                };
+
+                *search_value = value;
+
+                None
+            }
+            ComponentWidgetEvent::SubmitMultiSelectSearch { widget_id, value } => {
+                let Some(state) = state else {
+                    return None;
+                };
+
+                let ComponentWidgetState::MultiSelect(MultiSelectState {
+                    state_value,
+                    search_value,
+                }) = state
+                else {
+                    panic!("unexpected state kind, widget_id: {:?} state: {:?}", widget_id, state)
+                };
+
+                *search_value = "".to_string();
+
+                toggle_multi_select_item(state_value, value);
+
+                Some(create_multi_select_on_change_event(widget_id, state_value.clone()))
+            }
+            ComponentWidgetEvent::ToggleMultiSelectItem { widget_id, value } => {
+                let Some(state) = state else {
+                    return None;
+                };
+
+                let ComponentWidgetState::MultiSelect(MultiSelectState { state_value, .. }) = state else {
+                    panic!("unexpected state kind, widget_id: {:?} state: {:?}", widget_id, state)
+                };
+
+                toggle_multi_select_item(state_value, value);
+
+                Some(create_multi_select_on_change_event(widget_id, state_value.clone()))
+            }
+            ComponentWidgetEvent::ToggleFilePicker { widget_id } => {
+                let Some(state) = state else {
+                    return None;
+                };
+
+                let ComponentWidgetState::FilePicker(FilePickerState {
+                    show_browser,
+                    directory,
+                    ..
+                }) = state
+                else {
+                    panic!("unexpected state kind, widget_id: {:?} state: {:?}", widget_id, state)
+                };
+
+                *show_browser = !*show_browser;
+
+                if *show_browser && directory.is_none() {
+                    Some(UiViewEvent::AppEvent {
+                        event: AppMsg::ListPluginDirectory {
+                            plugin_id,
+                            widget_id,
+                            path: None,
+                        },
+                    })
+                } else {
+                    None
+                }
+            }
+            ComponentWidgetEvent::OpenFilePickerDirectory { widget_id, path } => {
+                Some(UiViewEvent::AppEvent {
+                    event: AppMsg::ListPluginDirectory {
+                        plugin_id,
+                        widget_id,
+                        path,
+                    },
+                })
+            }
+            ComponentWidgetEvent::SetFilePickerDirectory {
+                widget_id,
+                directory: new_directory,
+            } => {
+                let Some(state) = state else {
+                    return None;
+                };
+
+                let ComponentWidgetState::FilePicker(FilePickerState { directory, .. }) = state else {
+                    panic!("unexpected state kind, widget_id: {:?} state: {:?}", widget_id, state)
+                };
+
+                *directory = Some(new_directory);
+
+                None
+            }
+            ComponentWidgetEvent::ToggleFilePickerPath {
+                widget_id,
+                path,
+                allow_multiple,
+            } => {
+                let Some(state) = state else {
+                    return None;
+                };
+
+                let ComponentWidgetState::FilePicker(FilePickerState {
+                    state_value,
+                    show_browser,
+                    ..
+                }) = state
+                else {
+                    panic!("unexpected state kind, widget_id: {:?} state: {:?}", widget_id, state)
+                };
+
+                if state_value.contains(&path) {
+                    state_value.retain(|selected| selected != &path);
+                } else if allow_multiple {
+                    state_value.push(path);
+                } else {
+                    *state_value = vec![path];
+                    *show_browser = false;
+                }
+
+                Some(create_file_picker_on_change_event(widget_id, state_value.clone()))
+            }
             ComponentWidgetEvent::OnChangeTextField { widget_id, value } => {
                 let Some(state) = state else {
                     return None;
@@ -176,6 +410,41 @@ impl ComponentWidgetEvent {
 
                 Some(create_password_field_on_change_event(widget_id, Some(value)))
             }
+            ComponentWidgetEvent::OnChangeNumberField { widget_id, value } => {
+                let Some(state) = state else {
+                    return None;
+                };
+
+                let ComponentWidgetState::NumberField(NumberFieldState { state_value }) = state else {
+                    panic!("unexpected state kind, widget_id: {:?} state: {:?}", widget_id, state)
+                };
+
+                *state_value = value;
+
+                Some(create_number_field_on_change_event(widget_id, value))
+            }
+            ComponentWidgetEvent::EditTextArea { widget_id, action } => {
+                let Some(state) = state else {
+                    return None;
+                };
+
+                let ComponentWidgetState::TextArea(TextAreaState { state_value }) = state else {
+                    panic!("unexpected state kind, widget_id: {:?} state: {:?}", widget_id, state)
+                };
+
+                let is_edit = action.is_edit();
+
+                state_value.perform(action);
+
+                if !is_edit {
+                    // cursor movement, selection, scrolling
+                    return None;
+                }
+
+                let value = text_area_value(state_value);
+
+                Some(create_text_area_on_change_event(widget_id, Some(value)))
+            }
             ComponentWidgetEvent::OnChangeSearchBar { widget_id, value } => {
                 let Some(state) = state else {
                     return None;
@@ -189,6 +458,19 @@ impl ComponentWidgetEvent {
 
                 Some(create_search_bar_on_change_event(widget_id, Some(value)))
             }
+            ComponentWidgetEvent::SelectSearchBarDropdownItem { widget_id, value } => {
+                let Some(state) = state else {
+                    return None;
+                };
+
+                let ComponentWidgetState::SearchBarDropdown(SearchBarDropdownState { state_value }) = state else {
+                    panic!("unexpected state kind, widget_id: {:?} state: {:?}", widget_id, state)
+                };
+
+                *state_value = Some(value.clone());
+
+                Some(create_search_bar_dropdown_on_change_event(widget_id, value))
+            }
             ComponentWidgetEvent::ToggleActionPanel { .. } => {
                 Some(UiViewEvent::AppEvent {
                     event: AppMsg::ToggleActionPanel { keyboard: false },
@@ -202,6 +484,50 @@ impl ComponentWidgetEvent {
                 grid_widget_id,
                 item_id,
             } => Some(create_grid_on_item_focus_change_event(grid_widget_id, item_id)),
+            ComponentWidgetEvent::FocusTableRow {
+                table_widget_id,
+                row_id,
+            } => Some(create_table_on_row_focus_change_event(table_widget_id, row_id)),
+            ComponentWidgetEvent::LoadMoreListItems {
+                list_widget_id,
+                item_count,
+            } => {
+                if !request_load_more(list_widget_id, item_count, state) {
+                    return None;
+                }
+
+                Some(create_list_on_load_more_event(list_widget_id))
+            }
+            ComponentWidgetEvent::LoadMoreGridItems {
+                grid_widget_id,
+                item_count,
+            } => {
+                if !request_load_more(grid_widget_id, item_count, state) {
+                    return None;
+                }
+
+                Some(create_grid_on_load_more_event(grid_widget_id))
+            }
+            ComponentWidgetEvent::ToggleTableSort { widget_id } => {
+                let Some(state) = state else {
+                    return None;
+                };
+
+                let ComponentWidgetState::TableColumn(TableColumnState { sort }) = state else {
+                    panic!("unexpected state kind, widget_id: {:?} state: {:?}", widget_id, state)
+                };
+
+                *sort = match sort {
+                    None => Some(TableSortDirection::Ascending),
+                    Some(TableSortDirection::Ascending) => Some(TableSortDirection::Descending),
+                    Some(TableSortDirection::Descending) => None,
+                };
+
+                None
+            }
+            ComponentWidgetEvent::SubmitForm { widget_id, values } => {
+                Some(create_form_on_submit_event(widget_id, values))
+            }
             ComponentWidgetEvent::Noop | ComponentWidgetEvent::PreviousView => {
                 panic!("widget_id on these events is not supposed to be called")
             }
@@ -221,15 +547,31 @@ impl ComponentWidgetEvent {
             ComponentWidgetEvent::TagClick { widget_id, .. } => widget_id,
             ComponentWidgetEvent::ToggleDatePicker { widget_id, .. } => widget_id,
             ComponentWidgetEvent::SubmitDatePicker { widget_id, .. } => widget_id,
+            ComponentWidgetEvent::OnChangeDatePickerTime { widget_id, .. } => widget_id,
             ComponentWidgetEvent::CancelDatePicker { widget_id, .. } => widget_id,
             ComponentWidgetEvent::ToggleCheckbox { widget_id, .. } => widget_id,
             ComponentWidgetEvent::SelectPickList { widget_id, .. } => widget_id,
+            ComponentWidgetEvent::OnChangeMultiSelectSearch { widget_id, .. } => widget_id,
+            ComponentWidgetEvent::SubmitMultiSelectSearch { widget_id, .. } => widget_id,
+            ComponentWidgetEvent::ToggleMultiSelectItem { widget_id, .. } => widget_id,
             ComponentWidgetEvent::OnChangeTextField { widget_id, .. } => widget_id,
             ComponentWidgetEvent::OnChangePasswordField { widget_id, .. } => widget_id,
+            ComponentWidgetEvent::OnChangeNumberField { widget_id, .. } => widget_id,
+            ComponentWidgetEvent::EditTextArea { widget_id, .. } => widget_id,
             ComponentWidgetEvent::OnChangeSearchBar { widget_id, .. } => widget_id,
+            ComponentWidgetEvent::SelectSearchBarDropdownItem { widget_id, .. } => widget_id,
+            ComponentWidgetEvent::ToggleFilePicker { widget_id } => widget_id,
+            ComponentWidgetEvent::OpenFilePickerDirectory { widget_id, .. } => widget_id,
+            ComponentWidgetEvent::SetFilePickerDirectory { widget_id, .. } => widget_id,
+            ComponentWidgetEvent::ToggleFilePickerPath { widget_id, .. } => widget_id,
             ComponentWidgetEvent::ToggleActionPanel { widget_id } => widget_id,
             ComponentWidgetEvent::FocusListItem { list_widget_id, .. } => list_widget_id,
             ComponentWidgetEvent::FocusGridItem { grid_widget_id, .. } => grid_widget_id,
+            ComponentWidgetEvent::FocusTableRow { table_widget_id, .. } => table_widget_id,
+            ComponentWidgetEvent::LoadMoreListItems { list_widget_id, .. } => list_widget_id,
+            ComponentWidgetEvent::LoadMoreGridItems { grid_widget_id, .. } => grid_widget_id,
+            ComponentWidgetEvent::ToggleTableSort { widget_id } => widget_id,
+            ComponentWidgetEvent::SubmitForm { widget_id, .. } => widget_id,
             ComponentWidgetEvent::RunPrimaryAction { widget_id, .. } => widget_id,
             ComponentWidgetEvent::Noop | ComponentWidgetEvent::PreviousView => {
                 panic!("widget_id on these events is not supposed to be called")
@@ -238,3 +580,56 @@ impl ComponentWidgetEvent {
         .to_owned()
     }
 }
+
+// returns false if next page was already requested for the current amount of items
+fn request_load_more(widget_id: UiWidgetId, item_count: usize, state: Option<&mut ComponentWidgetState>) -> bool {
+    let Some(state) = state else {
+        return false;
+    };
+
+    let ComponentWidgetState::Root(RootState {
+        load_more_requested_at, ..
+    }) = state
+    else {
+        panic!("unexpected state kind, widget_id: {:?} state: {:?}", widget_id, state)
+    };
+
+    if *load_more_requested_at == Some(item_count) {
+        return false;
+    }
+
+    *load_more_requested_at = Some(item_count);
+
+    true
+}
+
+pub fn text_area_value(state_value: &text_editor::Content) -> String {
+    state_value
+        .lines()
+        .map(|line| line.to_string())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub fn date_picker_value(mode: &DatePickerMode, state: &DatePickerState) -> String {
+    let DatePickerState {
+        state_value,
+        hour,
+        minute,
+        ..
+    } = state;
+
+    match mode {
+        DatePickerMode::Date => state_value.to_string(),
+        DatePickerMode::Time => format!("{:02}:{:02}", hour, minute),
+        DatePickerMode::DateTime => format!("{}T{:02}:{:02}", state_value, hour, minute),
+    }
+}
+
+fn toggle_multi_select_item(state_value: &mut Vec<String>, value: String) {
+    if state_value.contains(&value) {
+        state_value.retain(|selected| selected != &value);
+    } else {
+        state_value.push(value);
+    }
+}
@@ -1,15 +1,22 @@
 use std::collections::HashMap;
 use std::fmt::Display;
+use std::path::Path;
 
 use gauntlet_common::model::CheckboxWidget;
+use gauntlet_common::model::DatePickerMode;
 use gauntlet_common::model::DatePickerWidget;
+use gauntlet_common::model::FilePickerWidget;
 use gauntlet_common::model::FormWidget;
 use gauntlet_common::model::FormWidgetOrderedMembers;
+use gauntlet_common::model::MultiSelectWidget;
+use gauntlet_common::model::MultiSelectWidgetOrderedMembers;
+use gauntlet_common::model::NumberFieldWidget;
 use gauntlet_common::model::PasswordFieldWidget;
 use gauntlet_common::model::PhysicalShortcut;
 use gauntlet_common::model::SelectWidget;
 use gauntlet_common::model::SelectWidgetOrderedMembers;
 use gauntlet_common::model::SeparatorWidget;
+use gauntlet_common::model::TextAreaWidget;
 use gauntlet_common::model::TextFieldWidget;
 use iced::advanced::text::Shaping;
 use iced::alignment::Horizontal;
@@ -22,17 +29,25 @@ use iced::widget::pick_list;
 use iced::widget::row;
 use iced::widget::scrollable;
 use iced::widget::text;
+use iced::widget::text_editor;
 use iced::widget::text_input;
+use iced::widget::value;
 use iced::widget::Space;
 use iced::Alignment;
 use iced::Length;
 use iced_aw::date_picker;
+use iced_aw::number_input;
+use iced_fonts::Bootstrap;
+use iced_fonts::BOOTSTRAP_FONT;
 
 use crate::ui::state::PluginViewState;
+use crate::ui::theme::button::ButtonStyle;
 use crate::ui::theme::container::ContainerStyle;
 use crate::ui::theme::date_picker::DatePickerStyle;
 use crate::ui::theme::pick_list::PickListStyle;
 use crate::ui::theme::row::RowStyle;
+use crate::ui::theme::text::TextStyle;
+use crate::ui::theme::text_editor::TextEditorStyle;
 use crate::ui::theme::text_input::TextInputStyle;
 use crate::ui::theme::Element;
 use crate::ui::theme::ThemableWidget;
@@ -40,8 +55,12 @@ use crate::ui::widget::data::ComponentWidgets;
 use crate::ui::widget::events::ComponentWidgetEvent;
 use crate::ui::widget::state::CheckboxState;
 use crate::ui::widget::state::DatePickerState;
+use crate::ui::widget::state::FilePickerState;
+use crate::ui::widget::state::MultiSelectState;
+use crate::ui::widget::state::NumberFieldState;
 use crate::ui::widget::state::RootState;
 use crate::ui::widget::state::SelectState;
+use crate::ui::widget::state::TextAreaState;
 use crate::ui::widget::state::TextFieldState;
 
 impl<'b> ComponentWidgets<'b> {
@@ -64,6 +83,34 @@ impl<'b> ComponentWidgets<'b> {
             .themed(TextInputStyle::FormInput)
     }
 
+    fn render_number_field_widget<'a>(&self, widget: &NumberFieldWidget) -> Element<'a, ComponentWidgetEvent> {
+        let widget_id = widget.__id__;
+        let NumberFieldState { state_value } = self.number_field_state(widget_id);
+
+        let min = widget.min.unwrap_or(f64::MIN);
+        let max = widget.max.unwrap_or(f64::MAX);
+        let precision = widget.precision;
+
+        number_input(*state_value, min..=max, move |value| {
+            ComponentWidgetEvent::OnChangeNumberField {
+                widget_id,
+                value: round_to_precision(value, precision),
+            }
+        })
+        .step(widget.step.unwrap_or(1.0))
+        .width(Length::Fill)
+        .into()
+    }
+
+    fn render_text_area_widget<'a>(&self, widget: &TextAreaWidget) -> Element<'a, ComponentWidgetEvent> {
+        let widget_id = widget.__id__;
+        let TextAreaState { state_value } = self.text_area_state(widget_id);
+
+        text_editor(state_value)
+            .on_action(move |action| ComponentWidgetEvent::EditTextArea { widget_id, action })
+            .themed(TextEditorStyle::FormInput)
+    }
+
     fn render_checkbox_widget<'a>(&self, widget: &CheckboxWidget) -> Element<'a, ComponentWidgetEvent> {
         let widget_id = widget.__id__;
         let CheckboxState { state_value } = self.checkbox_state(widget_id);
@@ -78,31 +125,82 @@ impl<'b> ComponentWidgets<'b> {
         let DatePickerState {
             state_value,
             show_picker,
+            hour,
+            minute,
         } = self.date_picker_state(widget.__id__);
 
-        let button_text = text(state_value.to_string()).shaping(Shaping::Advanced);
+        let mode = widget.mode.clone().unwrap_or(DatePickerMode::Date);
 
-        let button = button(button_text).on_press(ComponentWidgetEvent::ToggleDatePicker {
-            widget_id: widget.__id__,
-        });
+        let time = {
+            let hour = *hour;
+            let minute = *minute;
 
-        // TODO unable to customize buttons here, split to separate button styles
-        //     DatePickerUnderlay,
-        //     DatePickerOverlay,
+            let hour_mode = mode.clone();
+            let hour_input: Element<_> = number_input(hour, 0..=23, move |hour| {
+                ComponentWidgetEvent::OnChangeDatePickerTime {
+                    widget_id,
+                    mode: hour_mode.clone(),
+                    hour,
+                    minute,
+                }
+            })
+            .into();
 
-        date_picker(
-            show_picker.to_owned(),
-            state_value.to_owned(),
-            button,
-            ComponentWidgetEvent::CancelDatePicker { widget_id },
-            move |date| {
-                ComponentWidgetEvent::SubmitDatePicker {
+            let minute_mode = mode.clone();
+            let minute_input: Element<_> = number_input(minute, 0..=59, move |minute| {
+                ComponentWidgetEvent::OnChangeDatePickerTime {
                     widget_id,
-                    value: date.to_string(),
+                    mode: minute_mode.clone(),
+                    hour,
+                    minute,
                 }
-            },
-        )
-        .themed(DatePickerStyle::Default)
+            })
+            .into();
+
+            let separator: Element<_> = text(":").into();
+
+            let time: Element<_> = row([hour_input, separator, minute_input])
+                .align_y(Alignment::Center)
+                .spacing(4)
+                .into();
+
+            time
+        };
+
+        let date = {
+            let date_mode = mode.clone();
+
+            let button_text = text(state_value.to_string()).shaping(Shaping::Advanced);
+
+            let button = button(button_text).on_press(ComponentWidgetEvent::ToggleDatePicker {
+                widget_id: widget.__id__,
+            });
+
+            // TODO unable to customize buttons here, split to separate button styles
+            //     DatePickerUnderlay,
+            //     DatePickerOverlay,
+
+            date_picker(
+                show_picker.to_owned(),
+                state_value.to_owned(),
+                button,
+                ComponentWidgetEvent::CancelDatePicker { widget_id },
+                move |date| {
+                    ComponentWidgetEvent::SubmitDatePicker {
+                        widget_id,
+                        mode: date_mode.clone(),
+                        value: date,
+                    }
+                },
+            )
+            .themed(DatePickerStyle::Default)
+        };
+
+        match mode {
+            DatePickerMode::Date => date,
+            DatePickerMode::Time => time,
+            DatePickerMode::DateTime => row([date, time]).align_y(Alignment::Center).spacing(8).into(),
+        }
     }
 
     fn render_select_widget<'a>(&self, widget: &SelectWidget) -> Element<'a, ComponentWidgetEvent> {
@@ -140,6 +238,266 @@ impl<'b> ComponentWidgets<'b> {
         .themed(PickListStyle::Default)
     }
 
+    fn render_multi_select_widget<'a>(&self, widget: &MultiSelectWidget) -> Element<'a, ComponentWidgetEvent> {
+        let widget_id = widget.__id__;
+        let MultiSelectState {
+            state_value,
+            search_value,
+        } = self.multi_select_state(widget_id);
+
+        let items: Vec<_> = widget
+            .content
+            .ordered_members
+            .iter()
+            .map(|members| {
+                match members {
+                    MultiSelectWidgetOrderedMembers::SelectItem(widget) => {
+                        SelectItem {
+                            value: widget.value.to_owned(),
+                            label: widget.content.text.join(""),
+                        }
+                    }
+                }
+            })
+            .collect();
+
+        let chips: Vec<Element<_>> = state_value
+            .iter()
+            .map(|selected| {
+                // value which is not present in items is still shown to be able to deselect it
+                let label = items
+                    .iter()
+                    .find(|item| &item.value == selected)
+                    .map(|item| item.label.clone())
+                    .unwrap_or_else(|| selected.clone());
+
+                let label: Element<_> = text(label).shaping(Shaping::Advanced).into();
+
+                let icon: Element<_> = value(Bootstrap::X).font(BOOTSTRAP_FONT).into();
+
+                let content: Element<_> = row([label, icon]).align_y(Alignment::Center).into();
+
+                let chip: Element<_> = button(content)
+                    .on_press(ComponentWidgetEvent::ToggleMultiSelectItem {
+                        widget_id,
+                        value: selected.clone(),
+                    })
+                    .themed(ButtonStyle::MetadataTagItem);
+
+                container(chip).themed(ContainerStyle::MetadataTagItem)
+            })
+            .collect();
+
+        let search_query = search_value.to_lowercase();
+
+        let filtered_items: Vec<_> = items
+            .iter()
+            .filter(|item| item.label.to_lowercase().contains(&search_query))
+            .collect();
+
+        let first_filtered_value = filtered_items.first().map(|item| item.value.clone());
+
+        let search: Element<_> = text_input("Search...", search_value)
+            .on_input(move |value| ComponentWidgetEvent::OnChangeMultiSelectSearch { widget_id, value })
+            .on_submit_maybe(
+                first_filtered_value.map(|value| ComponentWidgetEvent::SubmitMultiSelectSearch { widget_id, value }),
+            )
+            .themed(TextInputStyle::FormInput);
+
+        let options: Vec<Element<_>> = filtered_items
+            .into_iter()
+            .map(|item| {
+                let item_value = item.value.clone();
+
+                checkbox(item.label.clone(), state_value.contains(&item.value))
+                    .on_toggle(move |_| {
+                        ComponentWidgetEvent::ToggleMultiSelectItem {
+                            widget_id,
+                            value: item_value.clone(),
+                        }
+                    })
+                    .into()
+            })
+            .collect();
+
+        let mut content = vec![];
+
+        if !chips.is_empty() {
+            content.push(row(chips).wrap().into());
+        }
+
+        content.push(search);
+        content.push(column(options).into());
+
+        column(content).into()
+    }
+
+    fn render_file_picker_widget<'a>(&self, widget: &FilePickerWidget) -> Element<'a, ComponentWidgetEvent> {
+        let widget_id = widget.__id__;
+        let FilePickerState {
+            state_value,
+            show_browser,
+            directory,
+        } = self.file_picker_state(widget_id);
+
+        let allow_multiple = widget.allow_multiple.unwrap_or(false);
+        let can_choose_directories = widget.can_choose_directories.unwrap_or(false);
+
+        let chips: Vec<Element<_>> = state_value
+            .iter()
+            .map(|selected| {
+                let label = Path::new(selected)
+                    .file_name()
+                    .map(|name| name.to_string_lossy().to_string())
+                    .unwrap_or_else(|| selected.clone());
+
+                let label: Element<_> = text(label).shaping(Shaping::Advanced).into();
+
+                let icon: Element<_> = value(Bootstrap::X).font(BOOTSTRAP_FONT).into();
+
+                let content: Element<_> = row([label, icon]).align_y(Alignment::Center).into();
+
+                let chip: Element<_> = button(content)
+                    .on_press(ComponentWidgetEvent::ToggleFilePickerPath {
+                        widget_id,
+                        path: selected.clone(),
+                        allow_multiple,
+                    })
+                    .themed(ButtonStyle::MetadataTagItem);
+
+                container(chip).themed(ContainerStyle::MetadataTagItem)
+            })
+            .collect();
+
+        let browse_text = if *show_browser { "Close" } else { "Browse..." };
+
+        let browse: Element<_> = button(text(browse_text))
+            .on_press(ComponentWidgetEvent::ToggleFilePicker { widget_id })
+            .themed(ButtonStyle::DatePicker);
+
+        let mut content = vec![];
+
+        if !chips.is_empty() {
+            content.push(row(chips).wrap().into());
+        }
+
+        content.push(browse);
+
+        if *show_browser {
+            let browser: Element<_> = match directory {
+                None => text("Loading...").into(),
+                Some(directory) => {
+                    let mut header = vec![];
+
+                    // root is list of read paths, there is nothing to go up to from there
+                    if directory.path.is_some() {
+                        let up: Element<_> = value(Bootstrap::ArrowUp).font(BOOTSTRAP_FONT).into();
+
+                        let up: Element<_> = button(up)
+                            .on_press(ComponentWidgetEvent::OpenFilePickerDirectory {
+                                widget_id,
+                                path: directory.parent.clone(),
+                            })
+                            .themed(ButtonStyle::DatePicker);
+
+                        header.push(up);
+                    }
+
+                    let current_path = directory.path.as_deref().unwrap_or("Allowed locations");
+
+                    let current_path: Element<_> = text(current_path.to_string())
+                        .shaping(Shaping::Advanced)
+                        .width(Length::Fill)
+                        .into();
+
+                    header.push(current_path);
+
+                    if let (true, Some(path)) = (can_choose_directories, &directory.path) {
+                        let select: Element<_> = button(text("Select"))
+                            .on_press(ComponentWidgetEvent::ToggleFilePickerPath {
+                                widget_id,
+                                path: path.clone(),
+                                allow_multiple,
+                            })
+                            .themed(ButtonStyle::DatePicker);
+
+                        header.push(select);
+                    }
+
+                    let header: Element<_> = row(header).align_y(Alignment::Center).spacing(8).into();
+
+                    let extensions: Vec<_> = widget
+                        .extensions
+                        .iter()
+                        .flatten()
+                        .map(|extension| extension.trim_start_matches('.').to_lowercase())
+                        .collect();
+
+                    let entries: Vec<Element<_>> = directory
+                        .entries
+                        .iter()
+                        .filter(|entry| {
+                            if entry.is_directory || extensions.is_empty() {
+                                return true;
+                            }
+
+                            Path::new(&entry.name)
+                                .extension()
+                                .map(|extension| extensions.contains(&extension.to_string_lossy().to_lowercase()))
+                                .unwrap_or(false)
+                        })
+                        .map(|entry| {
+                            let icon = if entry.is_directory {
+                                Bootstrap::Folder
+                            } else {
+                                Bootstrap::FileEarmark
+                            };
+
+                            let icon: Element<_> = value(icon).font(BOOTSTRAP_FONT).into();
+                            let icon: Element<_> = container(icon).themed(ContainerStyle::ListItemIcon);
+
+                            let title: Element<_> = text(entry.name.to_string()).shaping(Shaping::Advanced).into();
+                            let title: Element<_> = container(title).themed(ContainerStyle::ListItemTitle);
+
+                            let content: Element<_> = row([icon, title]).align_y(Alignment::Center).into();
+
+                            let style = if state_value.contains(&entry.path) {
+                                ButtonStyle::ListItemFocused
+                            } else {
+                                ButtonStyle::ListItem
+                            };
+
+                            let event = if entry.is_directory {
+                                ComponentWidgetEvent::OpenFilePickerDirectory {
+                                    widget_id,
+                                    path: Some(entry.path.clone()),
+                                }
+                            } else {
+                                ComponentWidgetEvent::ToggleFilePickerPath {
+                                    widget_id,
+                                    path: entry.path.clone(),
+                                    allow_multiple,
+                                }
+                            };
+
+                            button(content).width(Length::Fill).on_press(event).themed(style)
+                        })
+                        .collect();
+
+                    let entries: Element<_> = column(entries).into();
+
+                    let entries: Element<_> = scrollable(entries).height(Length::Fixed(200.0)).into();
+
+                    column([header, entries]).into()
+                }
+            };
+
+            content.push(browser);
+        }
+
+        column(content).into()
+    }
+
     fn render_separator_widget<'a>(&self, _widget: &SeparatorWidget) -> Element<'a, ComponentWidgetEvent> {
         horizontal_rule(1).into()
     }
@@ -162,6 +520,8 @@ impl<'b> ComponentWidgets<'b> {
                 fn render_field<'c, 'd>(
                     field: Element<'c, ComponentWidgetEvent>,
                     label: &'d Option<String>,
+                    error: &'d Option<String>,
+                    info: &'d Option<String>,
                 ) -> Element<'c, ComponentWidgetEvent> {
                     let before_or_label: Element<_> = match label {
                         None => Space::with_width(Length::FillPortion(2)).into(),
@@ -178,7 +538,27 @@ impl<'b> ComponentWidgets<'b> {
                         }
                     };
 
-                    let form_input = container(field).width(Length::FillPortion(3)).into();
+                    let mut form_input = vec![field];
+
+                    if let Some(error) = error {
+                        let error: Element<_> = text(error.to_string())
+                            .shaping(Shaping::Advanced)
+                            .themed(TextStyle::FormInputError);
+
+                        form_input.push(container(error).themed(ContainerStyle::FormInputError));
+                    }
+
+                    if let Some(info) = info {
+                        let info: Element<_> = text(info.to_string())
+                            .shaping(Shaping::Advanced)
+                            .themed(TextStyle::FormInputInfo);
+
+                        form_input.push(container(info).themed(ContainerStyle::FormInputInfo));
+                    }
+
+                    let form_input: Element<_> = column(form_input).into();
+
+                    let form_input = container(form_input).width(Length::FillPortion(3)).into();
 
                     let after = Space::with_width(Length::FillPortion(2)).into();
 
@@ -192,19 +572,76 @@ impl<'b> ComponentWidgets<'b> {
                 match members {
                     FormWidgetOrderedMembers::Separator(widget) => self.render_separator_widget(widget),
                     FormWidgetOrderedMembers::TextField(widget) => {
-                        render_field(self.render_text_field_widget(widget), &widget.label)
+                        render_field(
+                            self.render_text_field_widget(widget),
+                            &widget.label,
+                            &widget.error,
+                            &widget.info,
+                        )
                     }
                     FormWidgetOrderedMembers::PasswordField(widget) => {
-                        render_field(self.render_password_field_widget(widget), &widget.label)
+                        render_field(
+                            self.render_password_field_widget(widget),
+                            &widget.label,
+                            &widget.error,
+                            &widget.info,
+                        )
+                    }
+                    FormWidgetOrderedMembers::NumberField(widget) => {
+                        render_field(
+                            self.render_number_field_widget(widget),
+                            &widget.label,
+                            &widget.error,
+                            &widget.info,
+                        )
+                    }
+                    FormWidgetOrderedMembers::TextArea(widget) => {
+                        render_field(
+                            self.render_text_area_widget(widget),
+                            &widget.label,
+                            &widget.error,
+                            &widget.info,
+                        )
                     }
                     FormWidgetOrderedMembers::Checkbox(widget) => {
-                        render_field(self.render_checkbox_widget(widget), &widget.label)
+                        render_field(
+                            self.render_checkbox_widget(widget),
+                            &widget.label,
+                            &widget.error,
+                            &widget.info,
+                        )
                     }
                     FormWidgetOrderedMembers::DatePicker(widget) => {
-                        render_field(self.render_date_picker_widget(widget), &widget.label)
+                        render_field(
+                            self.render_date_picker_widget(widget),
+                            &widget.label,
+                            &widget.error,
+                            &widget.info,
+                        )
                     }
                     FormWidgetOrderedMembers::Select(widget) => {
-                        render_field(self.render_select_widget(widget), &widget.label)
+                        render_field(
+                            self.render_select_widget(widget),
+                            &widget.label,
+                            &widget.error,
+                            &widget.info,
+                        )
+                    }
+                    FormWidgetOrderedMembers::MultiSelect(widget) => {
+                        render_field(
+                            self.render_multi_select_widget(widget),
+                            &widget.label,
+                            &widget.error,
+                            &widget.info,
+                        )
+                    }
+                    FormWidgetOrderedMembers::FilePicker(widget) => {
+                        render_field(
+                            self.render_file_picker_widget(widget),
+                            &widget.label,
+                            &widget.error,
+                            &widget.info,
+                        )
                     }
                 }
             })
@@ -244,3 +681,14 @@ impl Display for SelectItem {
         write!(f, "{}", self.label)
     }
 }
+
+fn round_to_precision(value: f64, precision: Option<f64>) -> f64 {
+    match precision {
+        None => value,
+        Some(precision) => {
+            let factor = 10f64.powi(precision as i32);
+
+            (value * factor).round() / factor
+        }
+    }
+}
@@ -33,6 +33,7 @@ use crate::ui::theme::ThemableWidget;
 use crate::ui::widget::accessories::render_icon_accessory;
 use crate::ui::widget::data::ComponentWidgets;
 use crate::ui::widget::events::ComponentWidgetEvent;
+use crate::ui::widget::list::render_loading_row;
 use crate::ui::widget::state::RootState;
 
 impl<'b> ComponentWidgets<'b> {
@@ -43,10 +44,14 @@ impl<'b> ComponentWidgets<'b> {
         entrypoint_name: &str,
         action_shortcuts: &HashMap<String, PhysicalShortcut>,
     ) -> Element<'a, ComponentWidgetEvent> {
+        let widget_id = grid_widget.__id__;
         let RootState {
             show_action_panel,
             focused_item,
-        } = self.root_state(grid_widget.__id__);
+            ..
+        } = self.root_state(widget_id);
+
+        let has_more = grid_widget.has_more.unwrap_or(false);
 
         let content = if grid_widget.content.ordered_members.is_empty() {
             match &grid_widget.content.empty_view {
@@ -93,12 +98,29 @@ impl<'b> ComponentWidgets<'b> {
                 items.push(content);
             }
 
+            if has_more && !items.is_empty() {
+                items.push(render_loading_row());
+            }
+
             let content: Element<_> = column(items).into();
 
             let content: Element<_> = container(content).width(Length::Fill).themed(ContainerStyle::GridInner);
 
+            let item_count = ComponentWidgets::grid_item_count(grid_widget);
+            let scroll_handle = focused_item.clone();
+
             let content: Element<_> = scrollable(content)
                 .id(focused_item.scrollable_id.clone())
+                .on_scroll(move |viewport| {
+                    if has_more && scroll_handle.is_viewport_near_end(&viewport) {
+                        ComponentWidgetEvent::LoadMoreGridItems {
+                            grid_widget_id: widget_id,
+                            item_count,
+                        }
+                    } else {
+                        ComponentWidgetEvent::Noop
+                    }
+                })
                 .width(Length::Fill)
                 .into();
 
@@ -111,7 +133,7 @@ impl<'b> ComponentWidgets<'b> {
 
         self.render_plugin_root(
             *show_action_panel,
-            grid_widget.__id__,
+            widget_id,
             focused_item_id,
             &grid_widget.content.search_bar,
             &grid_widget.content.actions,
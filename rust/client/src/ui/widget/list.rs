@@ -47,6 +47,7 @@ impl<'b> ComponentWidgets<'b> {
         let RootState {
             show_action_panel,
             focused_item,
+            ..
         } = self.root_state(widget_id);
 
         let mut pending: Vec<&ListItemWidget> = vec![];
@@ -97,6 +98,12 @@ impl<'b> ComponentWidgets<'b> {
             items.push(content);
         }
 
+        let has_more = list_widget.has_more.unwrap_or(false);
+
+        if has_more && !items.is_empty() {
+            items.push(render_loading_row());
+        }
+
         let content = if items.is_empty() {
             match &list_widget.content.empty_view {
                 Some(widget) => self.render_empty_view_widget(widget),
@@ -107,8 +114,21 @@ impl<'b> ComponentWidgets<'b> {
 
             let content: Element<_> = container(content).width(Length::Fill).themed(ContainerStyle::ListInner);
 
+            let item_count = ComponentWidgets::list_item_count(list_widget);
+            let scroll_handle = focused_item.clone();
+
             let content: Element<_> = scrollable(content)
                 .id(focused_item.scrollable_id.clone())
+                .on_scroll(move |viewport| {
+                    if has_more && scroll_handle.is_viewport_near_end(&viewport) {
+                        ComponentWidgetEvent::LoadMoreListItems {
+                            list_widget_id: widget_id,
+                            item_count,
+                        }
+                    } else {
+                        ComponentWidgetEvent::Noop
+                    }
+                })
                 .width(Length::Fill)
                 .into();
 
@@ -272,3 +292,11 @@ impl<'b> ComponentWidgets<'b> {
         button(content).on_press(on_press_msg).width(Length::Fill).themed(style)
     }
 }
+
+// not a button, so it cannot be focused or clicked
+pub fn render_loading_row<'a>() -> Element<'a, ComponentWidgetEvent> {
+    let content: Element<_> = text("Loading...").themed(TextStyle::ListItemSubtitle);
+    let content: Element<_> = container(content).themed(ContainerStyle::ListItemTitle);
+
+    container(content).themed(ContainerStyle::ListItemLoading)
+}
@@ -0,0 +1,241 @@
+use std::mem;
+
+use pulldown_cmark::Event;
+use pulldown_cmark::HeadingLevel;
+use pulldown_cmark::Options;
+use pulldown_cmark::Parser;
+use pulldown_cmark::Tag;
+use pulldown_cmark::TagEnd;
+
+#[derive(Debug)]
+pub enum MarkdownBlock {
+    Heading {
+        level: HeadingLevel,
+        spans: Vec<MarkdownSpan>,
+    },
+    Paragraph {
+        spans: Vec<MarkdownSpan>,
+    },
+    CodeBlock {
+        value: String,
+    },
+    HorizontalBreak,
+    Table {
+        head: Vec<Vec<MarkdownSpan>>,
+        rows: Vec<Vec<Vec<MarkdownSpan>>>,
+    },
+}
+
+#[derive(Debug)]
+pub enum MarkdownSpan {
+    Text {
+        value: String,
+        strong: bool,
+        emphasis: bool,
+        code: bool,
+        href: Option<String>,
+    },
+    TaskMarker {
+        checked: bool,
+    },
+}
+
+#[derive(Default)]
+struct MarkdownTable {
+    head: Vec<Vec<MarkdownSpan>>,
+    rows: Vec<Vec<Vec<MarkdownSpan>>>,
+    row: Vec<Vec<MarkdownSpan>>,
+}
+
+#[derive(Default)]
+struct MarkdownParser {
+    blocks: Vec<MarkdownBlock>,
+    spans: Vec<MarkdownSpan>,
+    strong: usize,
+    emphasis: usize,
+    href: Option<String>,
+    heading: Option<HeadingLevel>,
+    code_block: Option<String>,
+    table: Option<MarkdownTable>,
+    // next item number for ordered lists, None for bullet lists
+    lists: Vec<Option<u64>>,
+    // marker of the list item which hasn't been written yet because it may turn out to be a task list item
+    pending_item_marker: Option<String>,
+}
+
+pub fn parse_markdown(source: &str) -> Vec<MarkdownBlock> {
+    let mut parser = MarkdownParser::default();
+
+    for event in Parser::new_ext(source, Options::ENABLE_TABLES | Options::ENABLE_TASKLISTS) {
+        parser.event(event);
+    }
+
+    parser.flush();
+
+    parser.blocks
+}
+
+impl MarkdownParser {
+    fn event(&mut self, event: Event) {
+        match event {
+            Event::Start(tag) => self.start(tag),
+            Event::End(tag) => self.end(tag),
+            Event::Text(value) => {
+                match &mut self.code_block {
+                    Some(code_block) => code_block.push_str(&value),
+                    None => self.text(value.to_string(), false),
+                }
+            }
+            Event::Code(value) => self.text(value.to_string(), true),
+            Event::SoftBreak => self.text(" ".to_string(), false),
+            Event::HardBreak => self.text("\n".to_string(), false),
+            Event::Rule => {
+                self.flush();
+                self.blocks.push(MarkdownBlock::HorizontalBreak);
+            }
+            Event::TaskListMarker(checked) => {
+                let indent = self.list_indent();
+
+                self.pending_item_marker = None;
+                self.spans.push(Self::plain_span(indent));
+                self.spans.push(MarkdownSpan::TaskMarker { checked });
+                self.spans.push(Self::plain_span(" ".to_string()));
+            }
+            // raw html, footnotes and math are not supported
+            _ => {}
+        }
+    }
+
+    fn start(&mut self, tag: Tag) {
+        match tag {
+            Tag::Heading { level, .. } => {
+                self.flush();
+                self.heading = Some(level);
+            }
+            Tag::CodeBlock(_) => {
+                self.flush();
+                self.code_block = Some(String::new());
+            }
+            Tag::List(start) => {
+                // text of the parent item in tight lists
+                self.flush();
+                self.lists.push(start);
+            }
+            Tag::Item => {
+                self.flush();
+
+                let marker = match self.lists.last_mut() {
+                    Some(Some(number)) => {
+                        let marker = format!("{}. ", number);
+                        *number += 1;
+                        marker
+                    }
+                    _ => "• ".to_string(),
+                };
+
+                self.pending_item_marker = Some(format!("{}{}", self.list_indent(), marker));
+            }
+            Tag::Table(_) => {
+                self.flush();
+                self.table = Some(MarkdownTable::default());
+            }
+            Tag::Emphasis => self.emphasis += 1,
+            Tag::Strong => self.strong += 1,
+            Tag::Link { dest_url, .. } | Tag::Image { dest_url, .. } => self.href = Some(dest_url.to_string()),
+            _ => {}
+        }
+    }
+
+    fn end(&mut self, tag: TagEnd) {
+        match tag {
+            TagEnd::Paragraph | TagEnd::Item => self.flush(),
+            TagEnd::Heading(_) => {
+                if let Some(level) = self.heading.take() {
+                    let spans = mem::take(&mut self.spans);
+
+                    self.blocks.push(MarkdownBlock::Heading { level, spans })
+                }
+            }
+            TagEnd::CodeBlock => {
+                if let Some(value) = self.code_block.take() {
+                    let value = value.trim_end_matches('\n').to_string();
+
+                    self.blocks.push(MarkdownBlock::CodeBlock { value })
+                }
+            }
+            TagEnd::List(_) => {
+                self.flush();
+                self.lists.pop();
+            }
+            TagEnd::TableCell => {
+                let cell = mem::take(&mut self.spans);
+
+                if let Some(table) = &mut self.table {
+                    table.row.push(cell)
+                }
+            }
+            TagEnd::TableHead => {
+                if let Some(table) = &mut self.table {
+                    table.head = mem::take(&mut table.row)
+                }
+            }
+            TagEnd::TableRow => {
+                if let Some(table) = &mut self.table {
+                    let row = mem::take(&mut table.row);
+
+                    table.rows.push(row)
+                }
+            }
+            TagEnd::Table => {
+                if let Some(MarkdownTable { head, rows, .. }) = self.table.take() {
+                    self.blocks.push(MarkdownBlock::Table { head, rows })
+                }
+            }
+            TagEnd::Emphasis => self.emphasis = self.emphasis.saturating_sub(1),
+            TagEnd::Strong => self.strong = self.strong.saturating_sub(1),
+            TagEnd::Link | TagEnd::Image => self.href = None,
+            _ => {}
+        }
+    }
+
+    fn text(&mut self, value: String, code: bool) {
+        if let Some(marker) = self.pending_item_marker.take() {
+            self.spans.push(Self::plain_span(marker));
+        }
+
+        self.spans.push(MarkdownSpan::Text {
+            value,
+            strong: self.strong > 0,
+            emphasis: self.emphasis > 0,
+            code,
+            href: self.href.clone(),
+        });
+    }
+
+    fn plain_span(value: String) -> MarkdownSpan {
+        MarkdownSpan::Text {
+            value,
+            strong: false,
+            emphasis: false,
+            code: false,
+            href: None,
+        }
+    }
+
+    fn list_indent(&self) -> String {
+        "    ".repeat(self.lists.len().saturating_sub(1))
+    }
+
+    fn flush(&mut self) {
+        // table cells are collected separately when the cell ends
+        if self.table.is_some() || self.heading.is_some() {
+            return;
+        }
+
+        if !self.spans.is_empty() {
+            let spans = mem::take(&mut self.spans);
+
+            self.blocks.push(MarkdownBlock::Paragraph { spans })
+        }
+    }
+}
@@ -11,8 +11,10 @@ mod grid;
 mod images;
 mod inline;
 mod list;
+mod markdown;
 mod metadata;
 pub mod root;
 mod search_bar;
 pub mod state;
+mod table;
 mod text;
@@ -86,6 +86,9 @@ impl<'b> ComponentWidgets<'b> {
                             RootWidgetMembers::Grid(widget) => {
                                 self.render_grid_widget(widget, plugin_view_state, entrypoint_name, action_shortcuts)
                             }
+                            RootWidgetMembers::Table(widget) => {
+                                self.render_table_widget(widget, plugin_view_state, entrypoint_name, action_shortcuts)
+                            }
                             _ => {
                                 panic!("used inline widget in non-inline place")
                             }
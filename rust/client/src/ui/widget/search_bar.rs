@@ -1,11 +1,21 @@
+use std::fmt::Display;
+
+use gauntlet_common::model::SearchBarDropdownSectionWidgetOrderedMembers;
+use gauntlet_common::model::SearchBarDropdownWidget;
+use gauntlet_common::model::SearchBarDropdownWidgetOrderedMembers;
 use gauntlet_common::model::SearchBarWidget;
+use iced::widget::pick_list;
+use iced::widget::row;
 use iced::widget::text_input;
+use iced::Alignment;
 
+use crate::ui::theme::pick_list::PickListStyle;
 use crate::ui::theme::text_input::TextInputStyle;
 use crate::ui::theme::Element;
 use crate::ui::theme::ThemableWidget;
 use crate::ui::widget::data::ComponentWidgets;
 use crate::ui::widget::events::ComponentWidgetEvent;
+use crate::ui::widget::state::SearchBarDropdownState;
 use crate::ui::widget::state::TextFieldState;
 
 impl<'b> ComponentWidgets<'b> {
@@ -16,10 +26,91 @@ impl<'b> ComponentWidgets<'b> {
             text_input_id,
         } = self.text_field_state(widget_id);
 
-        text_input(widget.placeholder.as_deref().unwrap_or_default(), state_value)
+        let search_bar = text_input(widget.placeholder.as_deref().unwrap_or_default(), state_value)
             .id(text_input_id.clone())
             .ignore_with_modifiers(true)
             .on_input(move |value| ComponentWidgetEvent::OnChangeSearchBar { widget_id, value })
-            .themed(TextInputStyle::PluginSearchBar)
+            .themed(TextInputStyle::PluginSearchBar);
+
+        match &widget.content.dropdown {
+            None => search_bar,
+            Some(widget) => {
+                let dropdown = self.render_search_bar_dropdown_widget(widget);
+
+                row([search_bar, dropdown]).align_y(Alignment::Center).into()
+            }
+        }
+    }
+
+    fn render_search_bar_dropdown_widget<'a>(
+        &self,
+        widget: &SearchBarDropdownWidget,
+    ) -> Element<'a, ComponentWidgetEvent> {
+        let widget_id = widget.__id__;
+        let SearchBarDropdownState { state_value } = self.search_bar_dropdown_state(widget_id);
+
+        let items = search_bar_dropdown_items(widget);
+
+        let state_value = state_value
+            .as_ref()
+            .and_then(|value| items.iter().find(|item| &item.value == value))
+            .cloned();
+
+        pick_list(items, state_value, move |item| {
+            ComponentWidgetEvent::SelectSearchBarDropdownItem {
+                widget_id,
+                value: item.value,
+            }
+        })
+        .placeholder(widget.placeholder.as_deref().unwrap_or_default())
+        .themed(PickListStyle::Default)
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SearchBarDropdownItem {
+    pub value: String,
+    pub label: String,
+}
+
+impl Display for SearchBarDropdownItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.label)
     }
 }
+
+// pick list doesn't support headers, so section title is shown as a prefix of each item in the section
+pub fn search_bar_dropdown_items(widget: &SearchBarDropdownWidget) -> Vec<SearchBarDropdownItem> {
+    widget
+        .content
+        .ordered_members
+        .iter()
+        .flat_map(|members| {
+            match members {
+                SearchBarDropdownWidgetOrderedMembers::SearchBarDropdownItem(widget) => {
+                    vec![SearchBarDropdownItem {
+                        value: widget.value.to_owned(),
+                        label: widget.title.to_owned(),
+                    }]
+                }
+                SearchBarDropdownWidgetOrderedMembers::SearchBarDropdownSection(section) => {
+                    section
+                        .content
+                        .ordered_members
+                        .iter()
+                        .map(|members| {
+                            match members {
+                                SearchBarDropdownSectionWidgetOrderedMembers::SearchBarDropdownItem(widget) => {
+                                    SearchBarDropdownItem {
+                                        value: widget.value.to_owned(),
+                                        label: format!("{} › {}", section.title, widget.title),
+                                    }
+                                }
+                            }
+                        })
+                        .collect()
+                }
+            }
+        })
+        .collect()
+}
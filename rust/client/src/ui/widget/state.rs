@@ -1,17 +1,23 @@
 use std::collections::HashMap;
 
+use gauntlet_common::model::FilePickerDirectory;
 use gauntlet_common::model::FormWidgetOrderedMembers;
 use gauntlet_common::model::GridSectionWidgetOrderedMembers;
 use gauntlet_common::model::GridWidgetOrderedMembers;
 use gauntlet_common::model::RootWidget;
 use gauntlet_common::model::RootWidgetMembers;
+use gauntlet_common::model::SearchBarDropdownWidget;
+use gauntlet_common::model::SearchBarWidget;
+use gauntlet_common::model::TableWidgetOrderedMembers;
 use gauntlet_common::model::UiWidgetId;
+use iced::widget::text_editor;
 use iced::widget::text_input;
 use iced_aw::date_picker::Date;
 
 use crate::ui::scroll_handle::ScrollHandle;
 use crate::ui::scroll_handle::ESTIMATED_MAIN_LIST_ITEM_HEIGHT;
 use crate::ui::widget::grid::grid_width;
+use crate::ui::widget::search_bar::search_bar_dropdown_items;
 
 pub fn create_state(root_widget: &RootWidget) -> HashMap<UiWidgetId, ComponentWidgetState> {
     let mut result = HashMap::new();
@@ -34,6 +40,15 @@ pub fn create_state(root_widget: &RootWidget) -> HashMap<UiWidgetId, ComponentWi
                             FormWidgetOrderedMembers::PasswordField(widget) => {
                                 result.insert(widget.__id__, ComponentWidgetState::text_field(&widget.value));
                             }
+                            FormWidgetOrderedMembers::NumberField(widget) => {
+                                result.insert(
+                                    widget.__id__,
+                                    ComponentWidgetState::number_field(&widget.value, &widget.min),
+                                );
+                            }
+                            FormWidgetOrderedMembers::TextArea(widget) => {
+                                result.insert(widget.__id__, ComponentWidgetState::text_area(&widget.value));
+                            }
                             FormWidgetOrderedMembers::Checkbox(widget) => {
                                 result.insert(widget.__id__, ComponentWidgetState::checkbox(&widget.value));
                             }
@@ -43,6 +58,12 @@ pub fn create_state(root_widget: &RootWidget) -> HashMap<UiWidgetId, ComponentWi
                             FormWidgetOrderedMembers::Select(widget) => {
                                 result.insert(widget.__id__, ComponentWidgetState::select(&widget.value));
                             }
+                            FormWidgetOrderedMembers::MultiSelect(widget) => {
+                                result.insert(widget.__id__, ComponentWidgetState::multi_select(&widget.value));
+                            }
+                            FormWidgetOrderedMembers::FilePicker(widget) => {
+                                result.insert(widget.__id__, ComponentWidgetState::file_picker(&widget.value));
+                            }
                             FormWidgetOrderedMembers::Separator(_) => {}
                         }
                     }
@@ -54,7 +75,7 @@ pub fn create_state(root_widget: &RootWidget) -> HashMap<UiWidgetId, ComponentWi
                     );
 
                     if let Some(widget) = &widget.content.search_bar {
-                        result.insert(widget.__id__, ComponentWidgetState::text_field(&widget.value));
+                        create_search_bar_state(&mut result, widget);
                     }
                 }
                 RootWidgetMembers::Grid(widget) => {
@@ -97,7 +118,26 @@ pub fn create_state(root_widget: &RootWidget) -> HashMap<UiWidgetId, ComponentWi
                     result.insert(widget.__id__, ComponentWidgetState::root(height, rows_per_view));
 
                     if let Some(widget) = &widget.content.search_bar {
-                        result.insert(widget.__id__, ComponentWidgetState::text_field(&widget.value));
+                        create_search_bar_state(&mut result, widget);
+                    }
+                }
+                RootWidgetMembers::Table(widget) => {
+                    result.insert(
+                        widget.__id__,
+                        ComponentWidgetState::root(ESTIMATED_MAIN_LIST_ITEM_HEIGHT, 7),
+                    );
+
+                    if let Some(widget) = &widget.content.search_bar {
+                        create_search_bar_state(&mut result, widget);
+                    }
+
+                    for members in &widget.content.ordered_members {
+                        match members {
+                            TableWidgetOrderedMembers::TableColumn(widget) => {
+                                result.insert(widget.__id__, ComponentWidgetState::table_column());
+                            }
+                            TableWidgetOrderedMembers::TableRow(_) => {}
+                        }
                     }
                 }
                 RootWidgetMembers::Inline(_) => {}
@@ -108,12 +148,26 @@ pub fn create_state(root_widget: &RootWidget) -> HashMap<UiWidgetId, ComponentWi
     result
 }
 
-#[derive(Debug, Clone)]
+fn create_search_bar_state(result: &mut HashMap<UiWidgetId, ComponentWidgetState>, widget: &SearchBarWidget) {
+    result.insert(widget.__id__, ComponentWidgetState::text_field(&widget.value));
+
+    if let Some(widget) = &widget.content.dropdown {
+        result.insert(widget.__id__, ComponentWidgetState::search_bar_dropdown(widget));
+    }
+}
+
+#[derive(Debug)]
 pub enum ComponentWidgetState {
     TextField(TextFieldState),
+    NumberField(NumberFieldState),
+    TextArea(TextAreaState),
     Checkbox(CheckboxState),
     DatePicker(DatePickerState),
     Select(SelectState),
+    MultiSelect(MultiSelectState),
+    FilePicker(FilePickerState),
+    SearchBarDropdown(SearchBarDropdownState),
+    TableColumn(TableColumnState),
     Root(RootState),
 }
 
@@ -123,6 +177,16 @@ pub struct TextFieldState {
     pub state_value: String,
 }
 
+#[derive(Debug, Clone)]
+pub struct NumberFieldState {
+    pub state_value: f64,
+}
+
+#[derive(Debug)]
+pub struct TextAreaState {
+    pub state_value: text_editor::Content,
+}
+
 #[derive(Debug, Clone)]
 pub struct CheckboxState {
     pub state_value: bool,
@@ -132,6 +196,8 @@ pub struct CheckboxState {
 pub struct DatePickerState {
     pub show_picker: bool,
     pub state_value: Date,
+    pub hour: u32,
+    pub minute: u32,
 }
 
 #[derive(Debug, Clone)]
@@ -139,10 +205,42 @@ pub struct SelectState {
     pub state_value: Option<String>,
 }
 
+#[derive(Debug, Clone)]
+pub struct MultiSelectState {
+    pub state_value: Vec<String>,
+    pub search_value: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct FilePickerState {
+    pub state_value: Vec<String>,
+    pub show_browser: bool,
+    // None until backend responds with directory listing
+    pub directory: Option<FilePickerDirectory>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SearchBarDropdownState {
+    pub state_value: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct TableColumnState {
+    pub sort: Option<TableSortDirection>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableSortDirection {
+    Ascending,
+    Descending,
+}
+
 #[derive(Debug, Clone)]
 pub struct RootState {
     pub show_action_panel: bool,
     pub focused_item: ScrollHandle,
+    // amount of items at the moment when next page was requested, so the same page is not requested twice
+    pub load_more_requested_at: Option<usize>,
 }
 
 impl ComponentWidgetState {
@@ -150,6 +248,7 @@ impl ComponentWidgetState {
         ComponentWidgetState::Root(RootState {
             show_action_panel: false,
             focused_item: ScrollHandle::new(false, item_height, rows_per_view),
+            load_more_requested_at: None,
         })
     }
 
@@ -160,6 +259,18 @@ impl ComponentWidgetState {
         })
     }
 
+    fn number_field(value: &Option<f64>, min: &Option<f64>) -> ComponentWidgetState {
+        ComponentWidgetState::NumberField(NumberFieldState {
+            state_value: value.or(*min).unwrap_or(0.0),
+        })
+    }
+
+    fn text_area(value: &Option<String>) -> ComponentWidgetState {
+        ComponentWidgetState::TextArea(TextAreaState {
+            state_value: text_editor::Content::with_text(value.as_deref().unwrap_or_default()),
+        })
+    }
+
     fn checkbox(value: &Option<bool>) -> ComponentWidgetState {
         ComponentWidgetState::Checkbox(CheckboxState {
             state_value: value.to_owned().unwrap_or(false),
@@ -167,16 +278,31 @@ impl ComponentWidgetState {
     }
 
     fn date_picker(value: &Option<String>) -> ComponentWidgetState {
-        let value = value
-            .to_owned()
-            .map(|value| parse_date(&value))
+        // value is either "YYYY-MM-DD", "HH:MM" or "YYYY-MM-DDTHH:MM" depending on the mode
+        let (date, time) = match value.as_deref() {
+            None => (None, None),
+            Some(value) => {
+                match value.split_once("T") {
+                    Some((date, time)) => (Some(date), Some(time)),
+                    None if value.contains(":") => (None, Some(value)),
+                    None => (Some(value), None),
+                }
+            }
+        };
+
+        let date = date
+            .map(|value| parse_date(value))
             .flatten()
             .map(|(year, month, day)| Date::from_ymd(year, month, day))
             .unwrap_or(Date::today());
 
+        let (hour, minute) = time.map(|value| parse_time(value)).flatten().unwrap_or((0, 0));
+
         ComponentWidgetState::DatePicker(DatePickerState {
-            state_value: value,
+            state_value: date,
             show_picker: false,
+            hour,
+            minute,
         })
     }
 
@@ -185,6 +311,35 @@ impl ComponentWidgetState {
             state_value: value.to_owned(),
         })
     }
+
+    fn multi_select(value: &Option<Vec<String>>) -> ComponentWidgetState {
+        ComponentWidgetState::MultiSelect(MultiSelectState {
+            state_value: value.to_owned().unwrap_or_default(),
+            search_value: "".to_string(),
+        })
+    }
+
+    fn file_picker(value: &Option<Vec<String>>) -> ComponentWidgetState {
+        ComponentWidgetState::FilePicker(FilePickerState {
+            state_value: value.to_owned().unwrap_or_default(),
+            show_browser: false,
+            directory: None,
+        })
+    }
+
+    fn search_bar_dropdown(widget: &SearchBarDropdownWidget) -> ComponentWidgetState {
+        // first item is selected if plugin didn't specify the value
+        let state_value = widget
+            .value
+            .clone()
+            .or_else(|| search_bar_dropdown_items(widget).first().map(|item| item.value.clone()));
+
+        ComponentWidgetState::SearchBarDropdown(SearchBarDropdownState { state_value })
+    }
+
+    fn table_column() -> ComponentWidgetState {
+        ComponentWidgetState::TableColumn(TableColumnState { sort: None })
+    }
 }
 
 fn parse_date(value: &str) -> Option<(i32, u32, u32)> {
@@ -204,3 +359,20 @@ fn parse_date(value: &str) -> Option<(i32, u32, u32)> {
         _ => None,
     }
 }
+
+fn parse_time(value: &str) -> Option<(u32, u32)> {
+    let hm: Vec<_> = value.split(":").collect();
+
+    match hm[..] {
+        [hour, minute] | [hour, minute, _] => {
+            let hour = hour.parse::<u32>();
+            let minute = minute.parse::<u32>();
+
+            match (hour, minute) {
+                (Ok(hour), Ok(minute)) if hour < 24 && minute < 60 => Some((hour, minute)),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,217 @@
+use std::collections::HashMap;
+
+use gauntlet_common::model::PhysicalShortcut;
+use gauntlet_common::model::TableColumnWidget;
+use gauntlet_common::model::TableRowWidget;
+use gauntlet_common::model::TableWidget;
+use gauntlet_common::model::TableWidgetOrderedMembers;
+use iced::advanced::text::Shaping;
+use iced::widget::button;
+use iced::widget::column;
+use iced::widget::container;
+use iced::widget::horizontal_rule;
+use iced::widget::horizontal_space;
+use iced::widget::row;
+use iced::widget::scrollable;
+use iced::widget::text;
+use iced::widget::value;
+use iced::Alignment;
+use iced::Length;
+use iced_fonts::Bootstrap;
+use iced_fonts::BOOTSTRAP_FONT;
+
+use crate::ui::state::PluginViewState;
+use crate::ui::theme::button::ButtonStyle;
+use crate::ui::theme::container::ContainerStyle;
+use crate::ui::theme::row::RowStyle;
+use crate::ui::theme::text::TextStyle;
+use crate::ui::theme::Element;
+use crate::ui::theme::ThemableWidget;
+use crate::ui::widget::data::table_cell_value;
+use crate::ui::widget::data::ComponentWidgets;
+use crate::ui::widget::events::ComponentWidgetEvent;
+use crate::ui::widget::state::RootState;
+use crate::ui::widget::state::TableColumnState;
+use crate::ui::widget::state::TableSortDirection;
+
+impl<'b> ComponentWidgets<'b> {
+    pub fn render_table_widget<'a>(
+        &self,
+        table_widget: &TableWidget,
+        plugin_view_state: &PluginViewState,
+        entrypoint_name: &str,
+        action_shortcuts: &HashMap<String, PhysicalShortcut>,
+    ) -> Element<'a, ComponentWidgetEvent> {
+        let widget_id = table_widget.__id__;
+        let RootState {
+            show_action_panel,
+            focused_item,
+            ..
+        } = self.root_state(widget_id);
+
+        let columns: Vec<&TableColumnWidget> = table_widget
+            .content
+            .ordered_members
+            .iter()
+            .filter_map(|members| {
+                match members {
+                    TableWidgetOrderedMembers::TableColumn(widget) => Some(widget),
+                    TableWidgetOrderedMembers::TableRow(_) => None,
+                }
+            })
+            .collect();
+
+        let sort = ComponentWidgets::table_sort(self.state, table_widget);
+
+        let rows = ComponentWidgets::table_rows(table_widget, &sort);
+
+        let content = if rows.is_empty() {
+            match &table_widget.content.empty_view {
+                Some(widget) => self.render_empty_view_widget(widget),
+                None => horizontal_space().into(),
+            }
+        } else {
+            let header = self.render_table_header(&columns);
+
+            let header: Element<_> = container(header).width(Length::Fill).themed(ContainerStyle::List);
+
+            let rows: Vec<_> = rows
+                .iter()
+                .enumerate()
+                .map(|(index, row)| {
+                    let focused = focused_item.index == Some(index);
+
+                    self.render_table_row_widget(row, table_widget, &columns, focused)
+                })
+                .collect();
+
+            let rows: Element<_> = column(rows).width(Length::Fill).into();
+
+            let rows: Element<_> = container(rows).width(Length::Fill).themed(ContainerStyle::ListInner);
+
+            let rows: Element<_> = scrollable(rows)
+                .id(focused_item.scrollable_id.clone())
+                .width(Length::Fill)
+                .into();
+
+            let rows: Element<_> = container(rows).width(Length::Fill).themed(ContainerStyle::List);
+
+            let separator: Element<_> = horizontal_rule(1).into();
+
+            column(vec![header, separator, rows]).height(Length::Fill).into()
+        };
+
+        let focused_row = ComponentWidgets::table_focused_row(focused_item, table_widget, &sort);
+
+        let focused_item_id = focused_row.map(|row| row.id.to_string());
+
+        self.render_plugin_root(
+            *show_action_panel,
+            widget_id,
+            focused_item_id,
+            &table_widget.content.search_bar,
+            ComponentWidgets::table_actions(focused_row, table_widget),
+            content,
+            table_widget.is_loading.unwrap_or(false),
+            plugin_view_state,
+            entrypoint_name,
+            action_shortcuts,
+        )
+    }
+
+    fn render_table_header<'a>(&self, columns: &[&TableColumnWidget]) -> Element<'a, ComponentWidgetEvent> {
+        let cells: Vec<Element<_>> = columns
+            .iter()
+            .map(|column| {
+                let title: Element<_> = text(column.title.to_string())
+                    .shaping(Shaping::Advanced)
+                    .themed(TextStyle::ListSectionTitle);
+
+                let cell: Element<_> = if column.sortable.unwrap_or(false) {
+                    let TableColumnState { sort } = self.table_column_state(column.__id__);
+
+                    let mut content = vec![title];
+
+                    if let Some(direction) = sort {
+                        let icon = match direction {
+                            TableSortDirection::Ascending => Bootstrap::ArrowUp,
+                            TableSortDirection::Descending => Bootstrap::ArrowDown,
+                        };
+
+                        let icon: Element<_> = value(icon)
+                            .font(BOOTSTRAP_FONT)
+                            .size(12)
+                            .themed(TextStyle::ListSectionTitle);
+
+                        content.push(icon);
+                    }
+
+                    let content: Element<_> = row(content).spacing(4).align_y(Alignment::Center).into();
+
+                    button(content)
+                        .on_press(ComponentWidgetEvent::ToggleTableSort {
+                            widget_id: column.__id__,
+                        })
+                        .themed(ButtonStyle::TableHeaderCell)
+                } else {
+                    title
+                };
+
+                container(cell).width(table_column_width(column)).into()
+            })
+            .collect();
+
+        row(cells).themed(RowStyle::TableHeader)
+    }
+
+    fn render_table_row_widget<'a>(
+        &self,
+        widget: &TableRowWidget,
+        table_widget: &TableWidget,
+        columns: &[&TableColumnWidget],
+        focused: bool,
+    ) -> Element<'a, ComponentWidgetEvent> {
+        let cells: Vec<Element<_>> = columns
+            .iter()
+            .enumerate()
+            .map(|(index, column)| {
+                let value = table_cell_value(widget, index).unwrap_or_default();
+
+                let cell: Element<_> = text(value).shaping(Shaping::Advanced).into();
+
+                container(cell).width(table_column_width(column)).into()
+            })
+            .collect();
+
+        let content: Element<_> = row(cells).align_y(Alignment::Center).themed(RowStyle::TableRow);
+
+        let style = if focused {
+            ButtonStyle::ListItemFocused
+        } else {
+            ButtonStyle::ListItem
+        };
+
+        let actions = ComponentWidgets::table_actions(Some(widget), table_widget);
+
+        let action_ids = ComponentWidgets::action_ids(actions);
+        let primary_action = action_ids.first();
+
+        let on_press_msg = match primary_action {
+            None => ComponentWidgetEvent::Noop,
+            Some(widget_id) => {
+                ComponentWidgetEvent::RunPrimaryAction {
+                    widget_id: *widget_id,
+                    id: Some(widget.id.clone()),
+                }
+            }
+        };
+
+        button(content).on_press(on_press_msg).width(Length::Fill).themed(style)
+    }
+}
+
+fn table_column_width(column: &TableColumnWidget) -> Length {
+    let portion = column.width.map(|width| width.max(1.0) as u16).unwrap_or(1);
+
+    Length::FillPortion(portion)
+}
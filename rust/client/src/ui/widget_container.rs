@@ -31,6 +31,8 @@ pub struct PluginWidgetContainer {
     plugin_name: Option<String>,
     entrypoint_id: Option<EntrypointId>,
     entrypoint_name: Option<String>,
+    // set when form is submitted so the first invalid field is focused when plugin renders validation results
+    form_submitted: bool,
 }
 
 impl PluginWidgetContainer {
@@ -43,6 +45,7 @@ impl PluginWidgetContainer {
             plugin_name: None,
             entrypoint_id: None,
             entrypoint_name: None,
+            form_submitted: false,
         }
     }
 
@@ -95,8 +98,13 @@ impl PluginWidgetContainer {
 
         self.root_widget = Some(container);
 
+        let form_submitted = mem::take(&mut self.form_submitted);
+
         if first_open {
             ComponentWidgets::new(&mut self.root_widget, &mut self.state, plugin_id.clone(), &self.images).first_open()
+        } else if form_submitted {
+            ComponentWidgets::new(&mut self.root_widget, &mut self.state, plugin_id.clone(), &self.images)
+                .first_invalid_form_field()
         } else {
             AppMsg::Noop
         }
@@ -105,6 +113,17 @@ impl PluginWidgetContainer {
     pub fn handle_event(&mut self, plugin_id: PluginId, event: ComponentWidgetEvent) -> Option<UiViewEvent> {
         let widget_id = event.widget_id();
 
+        if let ComponentWidgetEvent::SubmitForm { .. } = event {
+            self.form_submitted = true;
+        }
+
+        if let ComponentWidgetEvent::ToggleTableSort { .. } = event {
+            event.handle(plugin_id.clone(), self.state.get_mut(&widget_id));
+
+            return ComponentWidgets::new(&self.root_widget, &self.state, plugin_id, &self.images)
+                .table_sort_focus_event();
+        }
+
         event.handle(plugin_id, self.state.get_mut(&widget_id))
     }
 
@@ -140,6 +159,27 @@ impl PluginWidgetContainer {
         ComponentWidgets::new(&self.root_widget, &self.state, plugin_id, &self.images).focus_search_bar(widget_id)
     }
 
+    pub fn focus_form_field(&self, widget_id: UiWidgetId) -> Task<AppMsg> {
+        let plugin_id = self.get_plugin_id();
+        ComponentWidgets::new(&self.root_widget, &self.state, plugin_id, &self.images).focus_form_field(widget_id)
+    }
+
+    pub fn submit_form(&self) -> Task<AppMsg> {
+        let plugin_id = self.get_plugin_id();
+        ComponentWidgets::new(&self.root_widget, &self.state, plugin_id, &self.images).submit_form()
+    }
+
+    pub fn has_search_bar_dropdown(&self) -> bool {
+        let plugin_id = self.get_plugin_id();
+        ComponentWidgets::new(&self.root_widget, &self.state, plugin_id, &self.images).has_search_bar_dropdown()
+    }
+
+    pub fn select_next_search_bar_dropdown_item(&self, reverse: bool) -> Task<AppMsg> {
+        let plugin_id = self.get_plugin_id();
+        ComponentWidgets::new(&self.root_widget, &self.state, plugin_id, &self.images)
+            .select_next_search_bar_dropdown_item(reverse)
+    }
+
     pub fn toggle_action_panel(&mut self) {
         let plugin_id = self.get_plugin_id();
         ComponentWidgetsMut::new(&mut self.root_widget, &mut self.state, plugin_id, &self.images).toggle_action_panel()
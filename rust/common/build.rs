@@ -62,6 +62,9 @@ fn component_model_generator() -> Result<(), Box<dyn std::error::Error>> {
 
                 let has_text = matches!(children, Children::StringOrMembers { .. } | Children::String { .. });
 
+                // text parts are kept in ordered members to preserve their position relative to other members
+                let has_ordered_text = matches!(children, Children::StringOrMembers { .. });
+
                 let has_content = children_has_content || props_has_content || has_text;
 
                 let default = IndexMap::new();
@@ -97,6 +100,10 @@ fn component_model_generator() -> Result<(), Box<dyn std::error::Error>> {
                         ));
                     }
 
+                    if has_ordered_text {
+                        output.push_str("    Text(String),\n");
+                    }
+
                     output.push_str("}\n");
                 }
 
@@ -161,7 +168,7 @@ fn component_model_generator() -> Result<(), Box<dyn std::error::Error>> {
                             ));
                         }
 
-                        if has_text {
+                        if has_text && !has_ordered_text {
                             output.push_str("    pub text: Vec<String>,\n");
                         }
 
@@ -205,6 +212,7 @@ fn component_model_generator() -> Result<(), Box<dyn std::error::Error>> {
                                             items.iter().flat_map(|prop| all_component_refs(prop)).collect()
                                         }
                                         PropertyType::Array { item } => all_component_refs(item),
+                                        PropertyType::Record { value } => all_component_refs(value),
                                     }
                                 }
 
@@ -307,7 +315,7 @@ fn component_model_generator() -> Result<(), Box<dyn std::error::Error>> {
                                 output.push_str("        let mut ordered_members = vec![];\n");
                             }
 
-                            if has_text {
+                            if has_text && !has_ordered_text {
                                 output.push_str("        let mut text = vec![];\n");
                             }
 
@@ -381,7 +389,14 @@ fn component_model_generator() -> Result<(), Box<dyn std::error::Error>> {
                                         "                {}WidgetMembersOwned::Text {{ value }} => {{\n",
                                         name
                                     ));
-                                    output.push_str(&format!("                    text.insert(0, value);\n"));
+                                    if has_ordered_text {
+                                        output.push_str(&format!(
+                                            "                    ordered_members.insert(0, {}WidgetOrderedMembers::Text(value));\n",
+                                            name
+                                        ));
+                                    } else {
+                                        output.push_str(&format!("                    text.insert(0, value);\n"));
+                                    }
                                     output.push_str(&format!("                }}\n"));
                                 }
 
@@ -441,7 +456,7 @@ fn component_model_generator() -> Result<(), Box<dyn std::error::Error>> {
                                 output.push_str("            ordered_members\n");
                             }
 
-                            if has_text {
+                            if has_text && !has_ordered_text {
                                 output.push_str("            text\n");
                             }
 
@@ -596,11 +611,23 @@ fn component_model_generator() -> Result<(), Box<dyn std::error::Error>> {
                                     output.push_str(&format!("                }}\n"));
                                 }
 
+                                if has_ordered_text {
+                                    output.push_str(&format!(
+                                        "                {}WidgetOrderedMembers::Text(value) => {{\n",
+                                        name
+                                    ));
+                                    output.push_str(&format!(
+                                        "                    members.push({}WidgetMembersRef::Text {{ value }})\n",
+                                        name
+                                    ));
+                                    output.push_str(&format!("                }}\n"));
+                                }
+
                                 output.push_str(&format!("            }}\n"));
                                 output.push_str(&format!("        }}\n"));
                             }
 
-                            if has_text {
+                            if has_text && !has_ordered_text {
                                 output.push_str(&format!("        for value in &self.text {{\n"));
                                 output.push_str(&format!(
                                     "            members.push({}WidgetMembersRef::Text {{ value }});\n",
@@ -802,5 +829,6 @@ fn generate_required_type(property_type: &PropertyType, union_name: Option<Strin
             }
         }
         PropertyType::Array { item } => format!("Vec<{}>", generate_required_type(item, union_name)),
+        PropertyType::Record { .. } => panic!("client doesn't know about records in properties"),
     }
 }
@@ -100,6 +100,9 @@ pub struct PhysicalShortcut {
 pub struct LocalSaveData {
     pub stdout_file_path: String,
     pub stderr_file_path: String,
+    // host and port of v8 inspector server, devtools url is printed to stderr file when plugin starts
+    // none if plugin is disabled or inspector server failed to start
+    pub inspector_address: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -113,6 +116,8 @@ pub struct SearchResult {
     pub entrypoint_type: SearchResultEntrypointType,
     pub entrypoint_actions: Vec<SearchResultEntrypointAction>,
     pub entrypoint_accessories: Vec<SearchResultAccessory>,
+    pub entrypoint_arguments: Vec<SearchResultEntrypointArgument>,
+    pub entrypoint_accepts_input: Vec<SearchResultEntrypointInputKind>,
 }
 
 #[derive(Debug, Clone)]
@@ -135,6 +140,27 @@ pub struct SearchResultEntrypointAction {
     pub shortcut: Option<PhysicalShortcut>,
 }
 
+#[derive(Debug, Clone)]
+pub struct SearchResultEntrypointArgument {
+    pub id: String,
+    pub name: String,
+    pub required: bool,
+    pub kind: SearchResultEntrypointArgumentKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchResultEntrypointInputKind {
+    Text,
+    Image,
+}
+
+#[derive(Debug, Clone)]
+pub enum SearchResultEntrypointArgumentKind {
+    Text,
+    Password,
+    Dropdown { values: Vec<PreferenceEnumValue> },
+}
+
 #[derive(Debug, Clone)]
 pub enum SearchResultEntrypointActionType {
     Command,
@@ -229,6 +255,7 @@ pub struct UiSetupResponse {
 #[derive(Debug)]
 pub enum UiResponseData {
     Nothing,
+    PermissionPrompt { decision: PermissionPromptDecision },
     Err(anyhow::Error),
 }
 
@@ -261,6 +288,11 @@ pub enum UiRequestData {
         render_location: UiRenderLocation,
     },
     RequestSearchResultUpdate,
+    UpdateSearchResultRows {
+        plugin_id: PluginId,
+        updated: Vec<SearchResult>,
+        removed: Vec<EntrypointId>,
+    },
     ShowHud {
         display: String,
     },
@@ -283,6 +315,11 @@ pub enum UiRequestData {
         plugin_name: String,
         entrypoint_id: EntrypointId,
         entrypoint_name: String,
+        arguments: HashMap<String, String>,
+    },
+    ShowEntrypointArguments {
+        search_result: SearchResult,
+        arguments: HashMap<String, String>,
     },
     ShowGeneratedPluginView {
         plugin_id: PluginId,
@@ -294,6 +331,11 @@ pub enum UiRequestData {
     SynchronizeEvent {
         plugin_id: PluginId,
     },
+    ShowPermissionPrompt {
+        plugin_id: PluginId,
+        plugin_name: String,
+        permission: PluginPermission,
+    },
 }
 
 #[derive(Debug)]
@@ -311,6 +353,9 @@ pub enum BackendResponseData {
     InlineViewShortcuts {
         shortcuts: HashMap<PluginId, HashMap<String, PhysicalShortcut>>,
     },
+    PluginDirectory {
+        directory: FilePickerDirectory,
+    },
 }
 
 #[derive(Debug)]
@@ -323,6 +368,7 @@ pub enum BackendRequestData {
     RequestViewRender {
         plugin_id: PluginId,
         entrypoint_id: EntrypointId,
+        arguments: HashMap<String, String>,
     },
     RequestViewClose {
         plugin_id: PluginId,
@@ -330,6 +376,7 @@ pub enum BackendRequestData {
     RequestRunCommand {
         plugin_id: PluginId,
         entrypoint_id: EntrypointId,
+        arguments: HashMap<String, String>,
     },
     RequestRunGeneratedEntrypoint {
         plugin_id: PluginId,
@@ -362,11 +409,31 @@ pub enum BackendRequestData {
         entrypoint_id: Option<EntrypointId>,
     },
     InlineViewShortcuts,
+    ListPluginDirectory {
+        plugin_id: PluginId,
+        path: Option<String>,
+    },
+    CaptureEntrypointInput,
     SetupResponse {
         global_shortcut_error: Option<String>,
     },
 }
 
+// directory listing for file picker, path is none for the list of paths from "filesystem.read" permission
+#[derive(Debug, Clone)]
+pub struct FilePickerDirectory {
+    pub path: Option<String>,
+    pub parent: Option<String>,
+    pub entries: Vec<FilePickerEntry>,
+}
+
+#[derive(Debug, Clone)]
+pub struct FilePickerEntry {
+    pub name: String,
+    pub path: String,
+    pub is_directory: bool,
+}
+
 #[derive(Debug, Clone)]
 pub enum KeyboardEventOrigin {
     MainView,
@@ -470,6 +537,7 @@ pub trait WidgetVisitor {
     async fn h6_widget(&mut self, _widget: &H6Widget) {}
     async fn horizontal_break_widget(&mut self, _widget: &HorizontalBreakWidget) {}
     async fn code_block_widget(&mut self, _widget: &CodeBlockWidget) {}
+    async fn markdown_widget(&mut self, _widget: &MarkdownWidget) {}
     async fn paragraph_widget(&mut self, _widget: &ParagraphWidget) {}
     async fn content_widget(&mut self, widget: &ContentWidget) {
         for members in &widget.content.ordered_members {
@@ -484,6 +552,7 @@ pub trait WidgetVisitor {
                 ContentWidgetOrderedMembers::H6(widget) => self.h6_widget(widget).await,
                 ContentWidgetOrderedMembers::HorizontalBreak(widget) => self.horizontal_break_widget(widget).await,
                 ContentWidgetOrderedMembers::CodeBlock(widget) => self.code_block_widget(widget).await,
+                ContentWidgetOrderedMembers::Markdown(widget) => self.markdown_widget(widget).await,
             }
         }
     }
@@ -502,6 +571,8 @@ pub trait WidgetVisitor {
 
     async fn text_field_widget(&mut self, _widget: &TextFieldWidget) {}
     async fn password_field_widget(&mut self, _widget: &PasswordFieldWidget) {}
+    async fn number_field_widget(&mut self, _widget: &NumberFieldWidget) {}
+    async fn text_area_widget(&mut self, _widget: &TextAreaWidget) {}
     async fn checkbox_widget(&mut self, _widget: &CheckboxWidget) {}
     async fn date_picker_widget(&mut self, _widget: &DatePickerWidget) {}
     async fn select_item_widget(&mut self, _widget: &SelectItemWidget) {}
@@ -512,6 +583,14 @@ pub trait WidgetVisitor {
             }
         }
     }
+    async fn multi_select_widget(&mut self, widget: &MultiSelectWidget) {
+        for members in &widget.content.ordered_members {
+            match members {
+                MultiSelectWidgetOrderedMembers::SelectItem(widget) => self.select_item_widget(widget).await,
+            }
+        }
+    }
+    async fn file_picker_widget(&mut self, _widget: &FilePickerWidget) {}
     async fn separator_widget(&mut self, _widget: &SeparatorWidget) {}
     async fn form_widget(&mut self, widget: &FormWidget) {
         if let Some(widget) = &widget.content.actions {
@@ -521,9 +600,13 @@ pub trait WidgetVisitor {
             match members {
                 FormWidgetOrderedMembers::TextField(widget) => self.text_field_widget(widget).await,
                 FormWidgetOrderedMembers::PasswordField(widget) => self.password_field_widget(widget).await,
+                FormWidgetOrderedMembers::NumberField(widget) => self.number_field_widget(widget).await,
+                FormWidgetOrderedMembers::TextArea(widget) => self.text_area_widget(widget).await,
                 FormWidgetOrderedMembers::Checkbox(widget) => self.checkbox_widget(widget).await,
                 FormWidgetOrderedMembers::DatePicker(widget) => self.date_picker_widget(widget).await,
                 FormWidgetOrderedMembers::Select(widget) => self.select_widget(widget).await,
+                FormWidgetOrderedMembers::MultiSelect(widget) => self.multi_select_widget(widget).await,
+                FormWidgetOrderedMembers::FilePicker(widget) => self.file_picker_widget(widget).await,
                 FormWidgetOrderedMembers::Separator(widget) => self.separator_widget(widget).await,
             }
         }
@@ -558,7 +641,33 @@ pub trait WidgetVisitor {
         }
     }
 
-    async fn search_bar_widget(&mut self, _widget: &SearchBarWidget) {}
+    async fn search_bar_dropdown_item_widget(&mut self, _widget: &SearchBarDropdownItemWidget) {}
+    async fn search_bar_dropdown_section_widget(&mut self, widget: &SearchBarDropdownSectionWidget) {
+        for members in &widget.content.ordered_members {
+            match members {
+                SearchBarDropdownSectionWidgetOrderedMembers::SearchBarDropdownItem(widget) => {
+                    self.search_bar_dropdown_item_widget(widget).await
+                }
+            }
+        }
+    }
+    async fn search_bar_dropdown_widget(&mut self, widget: &SearchBarDropdownWidget) {
+        for members in &widget.content.ordered_members {
+            match members {
+                SearchBarDropdownWidgetOrderedMembers::SearchBarDropdownItem(widget) => {
+                    self.search_bar_dropdown_item_widget(widget).await
+                }
+                SearchBarDropdownWidgetOrderedMembers::SearchBarDropdownSection(widget) => {
+                    self.search_bar_dropdown_section_widget(widget).await
+                }
+            }
+        }
+    }
+    async fn search_bar_widget(&mut self, widget: &SearchBarWidget) {
+        if let Some(widget) = &widget.content.dropdown {
+            self.search_bar_dropdown_widget(widget).await
+        }
+    }
 
     async fn list_item_widget(&mut self, widget: &ListItemWidget) {
         if let Some(image) = &widget.icon {
@@ -616,6 +725,7 @@ pub trait WidgetVisitor {
                 ContentWidgetOrderedMembers::H6(widget) => self.h6_widget(widget).await,
                 ContentWidgetOrderedMembers::HorizontalBreak(widget) => self.horizontal_break_widget(widget).await,
                 ContentWidgetOrderedMembers::CodeBlock(widget) => self.code_block_widget(widget).await,
+                ContentWidgetOrderedMembers::Markdown(widget) => self.markdown_widget(widget).await,
             }
         }
     }
@@ -644,6 +754,36 @@ pub trait WidgetVisitor {
         }
     }
 
+    async fn table_column_widget(&mut self, _widget: &TableColumnWidget) {}
+    async fn table_cell_widget(&mut self, _widget: &TableCellWidget) {}
+    async fn table_row_widget(&mut self, widget: &TableRowWidget) {
+        if let Some(widget) = &widget.content.actions {
+            self.action_panel_widget(widget).await
+        }
+        for members in &widget.content.ordered_members {
+            match members {
+                TableRowWidgetOrderedMembers::TableCell(widget) => self.table_cell_widget(widget).await,
+            }
+        }
+    }
+    async fn table_widget(&mut self, widget: &TableWidget) {
+        if let Some(widget) = &widget.content.actions {
+            self.action_panel_widget(widget).await
+        }
+        if let Some(widget) = &widget.content.search_bar {
+            self.search_bar_widget(widget).await
+        }
+        if let Some(widget) = &widget.content.empty_view {
+            self.empty_view_widget(widget).await
+        }
+        for members in &widget.content.ordered_members {
+            match members {
+                TableWidgetOrderedMembers::TableColumn(widget) => self.table_column_widget(widget).await,
+                TableWidgetOrderedMembers::TableRow(widget) => self.table_row_widget(widget).await,
+            }
+        }
+    }
+
     async fn root_widget(&mut self, root_widget: &RootWidget) {
         if let Some(members) = &root_widget.content {
             match members {
@@ -652,6 +792,7 @@ pub trait WidgetVisitor {
                 RootWidgetMembers::Inline(widget) => self.inline_widget(widget).await,
                 RootWidgetMembers::List(widget) => self.list_widget(widget).await,
                 RootWidgetMembers::Grid(widget) => self.grid_widget(widget).await,
+                RootWidgetMembers::Table(widget) => self.table_widget(widget).await,
             }
         }
     }
@@ -679,6 +820,15 @@ pub struct SettingsEntrypoint {
     pub enabled: bool,
     pub preferences: HashMap<String, PluginPreference>,
     pub preferences_user_data: HashMap<String, PluginPreferenceUserData>,
+    pub interval_seconds: Option<u64>,
+    pub last_scheduled_run: Option<ScheduledRun>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ScheduledRun {
+    // unix timestamp in milliseconds
+    pub timestamp: i64,
+    pub error: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -690,6 +840,75 @@ pub struct SettingsPlugin {
     pub entrypoints: HashMap<EntrypointId, SettingsEntrypoint>,
     pub preferences: HashMap<String, PluginPreference>,
     pub preferences_user_data: HashMap<String, PluginPreferenceUserData>,
+    pub permissions: SettingsPluginPermissions,
+    // size of key-value storage in bytes
+    pub storage_usage: u64,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SettingsPluginPermissions {
+    // permissions declared in plugin manifest
+    pub declared: Vec<PluginPermission>,
+    // declared permissions which user decided to take away from plugin
+    pub revoked: Vec<PluginPermission>,
+    // ask user when plugin tries to access something not covered by permissions
+    pub ask: bool,
+    // permissions granted by user via "Always Allow" in permission prompt
+    pub granted: Vec<PluginPermission>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PluginAuditLogEntry {
+    // unix timestamp in milliseconds
+    pub timestamp: i64,
+    pub permission: PluginPermission,
+    pub allowed: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PluginPermission {
+    pub kind: PluginPermissionKind,
+    pub resource: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PluginPermissionKind {
+    Environment,
+    Network,
+    FilesystemRead,
+    FilesystemWrite,
+    ExecCommand,
+    ExecExecutable,
+    System,
+    Clipboard,
+    MainSearchBar,
+    Plugins,
+}
+
+impl Display for PluginPermissionKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let label = match self {
+            PluginPermissionKind::Environment => "Environment Variable",
+            PluginPermissionKind::Network => "Network",
+            PluginPermissionKind::FilesystemRead => "Filesystem Read",
+            PluginPermissionKind::FilesystemWrite => "Filesystem Write",
+            PluginPermissionKind::ExecCommand => "Run Command",
+            PluginPermissionKind::ExecExecutable => "Run Executable",
+            PluginPermissionKind::System => "System Information",
+            PluginPermissionKind::Clipboard => "Clipboard",
+            PluginPermissionKind::MainSearchBar => "Main Search Bar",
+            PluginPermissionKind::Plugins => "Plugin Functions",
+        };
+
+        write!(f, "{}", label)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PermissionPromptDecision {
+    AllowOnce,
+    AllowAlways,
+    Deny,
 }
 
 #[derive(Debug, Clone)]
@@ -11,22 +11,27 @@ use crate::model::BackendRequestData;
 use crate::model::BackendResponseData;
 use crate::model::DownloadStatus;
 use crate::model::EntrypointId;
+use crate::model::FilePickerDirectory;
 use crate::model::KeyboardEventOrigin;
 use crate::model::LocalSaveData;
 use crate::model::PhysicalKey;
 use crate::model::PhysicalShortcut;
+use crate::model::PluginAuditLogEntry;
 use crate::model::PluginId;
+use crate::model::PluginPermission;
 use crate::model::PluginPreferenceUserData;
 use crate::model::SearchResult;
 use crate::model::SettingsEntrypoint;
 use crate::model::SettingsEntrypointType;
 use crate::model::SettingsPlugin;
+use crate::model::SettingsPluginPermissions;
 use crate::model::SettingsTheme;
 use crate::model::UiPropertyValue;
 use crate::model::UiSetupData;
 use crate::model::UiWidgetId;
 use crate::model::WindowPositionMode;
 use crate::rpc::grpc::rpc_backend_client::RpcBackendClient;
+use crate::rpc::grpc::RpcClearPluginStorageRequest;
 use crate::rpc::grpc::RpcDownloadPluginRequest;
 use crate::rpc::grpc::RpcDownloadStatus;
 use crate::rpc::grpc::RpcDownloadStatusRequest;
@@ -34,13 +39,16 @@ use crate::rpc::grpc::RpcEntrypointTypeSettings;
 use crate::rpc::grpc::RpcGetGlobalShortcutRequest;
 use crate::rpc::grpc::RpcGetThemeRequest;
 use crate::rpc::grpc::RpcGetWindowPositionModeRequest;
+use crate::rpc::grpc::RpcOpenDeepLinkRequest;
 use crate::rpc::grpc::RpcPingRequest;
+use crate::rpc::grpc::RpcPluginAuditLogRequest;
 use crate::rpc::grpc::RpcPluginsRequest;
 use crate::rpc::grpc::RpcRemovePluginRequest;
 use crate::rpc::grpc::RpcRunActionRequest;
 use crate::rpc::grpc::RpcSaveLocalPluginRequest;
 use crate::rpc::grpc::RpcSetEntrypointStateRequest;
 use crate::rpc::grpc::RpcSetGlobalShortcutRequest;
+use crate::rpc::grpc::RpcSetPluginPermissionsRequest;
 use crate::rpc::grpc::RpcSetPluginStateRequest;
 use crate::rpc::grpc::RpcSetPreferenceValueRequest;
 use crate::rpc::grpc::RpcSetThemeRequest;
@@ -48,9 +56,13 @@ use crate::rpc::grpc::RpcSetWindowPositionModeRequest;
 use crate::rpc::grpc::RpcShortcut;
 use crate::rpc::grpc::RpcShowSettingsWindowRequest;
 use crate::rpc::grpc::RpcShowWindowRequest;
+use crate::rpc::grpc_convert::plugin_audit_log_entry_from_rpc;
+use crate::rpc::grpc_convert::plugin_permission_from_rpc;
+use crate::rpc::grpc_convert::plugin_permission_to_rpc;
 use crate::rpc::grpc_convert::plugin_preference_from_rpc;
 use crate::rpc::grpc_convert::plugin_preference_user_data_from_rpc;
 use crate::rpc::grpc_convert::plugin_preference_user_data_to_rpc;
+use crate::rpc::grpc_convert::scheduled_run_from_rpc;
 
 #[derive(Error, Debug, Clone)]
 pub enum BackendForFrontendApiError {
@@ -127,10 +139,12 @@ impl BackendForFrontendApi {
         &mut self,
         plugin_id: PluginId,
         entrypoint_id: EntrypointId,
+        arguments: HashMap<String, String>,
     ) -> Result<HashMap<String, PhysicalShortcut>, BackendForFrontendApiError> {
         let request = BackendRequestData::RequestViewRender {
             plugin_id,
             entrypoint_id,
+            arguments,
         };
 
         let BackendResponseData::RequestViewRender { shortcuts } = self.backend_sender.send_receive(request).await?
@@ -155,10 +169,12 @@ impl BackendForFrontendApi {
         &mut self,
         plugin_id: PluginId,
         entrypoint_id: EntrypointId,
+        arguments: HashMap<String, String>,
     ) -> Result<(), BackendForFrontendApiError> {
         let request = BackendRequestData::RequestRunCommand {
             plugin_id,
             entrypoint_id,
+            arguments,
         };
 
         let BackendResponseData::Nothing = self.backend_sender.send_receive(request).await? else {
@@ -278,6 +294,16 @@ impl BackendForFrontendApi {
         Ok(())
     }
 
+    pub async fn capture_entrypoint_input(&mut self) -> Result<(), BackendForFrontendApiError> {
+        let request = BackendRequestData::CaptureEntrypointInput;
+
+        let BackendResponseData::Nothing = self.backend_sender.send_receive(request).await? else {
+            unreachable!()
+        };
+
+        Ok(())
+    }
+
     pub async fn inline_view_shortcuts(
         &self,
     ) -> Result<HashMap<PluginId, HashMap<String, PhysicalShortcut>>, BackendForFrontendApiError> {
@@ -290,6 +316,21 @@ impl BackendForFrontendApi {
 
         Ok(shortcuts)
     }
+
+    pub async fn list_plugin_directory(
+        &self,
+        plugin_id: PluginId,
+        path: Option<String>,
+    ) -> Result<FilePickerDirectory, BackendForFrontendApiError> {
+        let request = BackendRequestData::ListPluginDirectory { plugin_id, path };
+
+        let BackendResponseData::PluginDirectory { directory } = self.backend_sender.send_receive(request).await?
+        else {
+            unreachable!()
+        };
+
+        Ok(directory)
+    }
 }
 
 #[derive(Error, Debug, Clone)]
@@ -340,10 +381,10 @@ impl BackendApi {
         Ok(())
     }
 
-    pub async fn show_window(&mut self) -> Result<(), BackendApiError> {
+    pub async fn show_window(&mut self, input: Option<String>) -> Result<(), BackendApiError> {
         let _ = self
             .client
-            .show_window(Request::new(RpcShowWindowRequest::default()))
+            .show_window(Request::new(RpcShowWindowRequest { input }))
             .await?;
 
         Ok(())
@@ -376,6 +417,15 @@ impl BackendApi {
         Ok(())
     }
 
+    pub async fn open_deep_link(&mut self, link: String) -> Result<(), BackendApiError> {
+        let _ = self
+            .client
+            .open_deep_link(Request::new(RpcOpenDeepLinkRequest { link }))
+            .await?;
+
+        Ok(())
+    }
+
     pub async fn plugins(&mut self) -> Result<HashMap<PluginId, SettingsPlugin>, BackendApiError> {
         let plugins = self
             .client
@@ -418,11 +468,37 @@ impl BackendApi {
                                 .into_iter()
                                 .map(|(key, value)| (key, plugin_preference_user_data_from_rpc(value)))
                                 .collect(),
+                            interval_seconds: entrypoint.interval_seconds,
+                            last_scheduled_run: entrypoint.last_scheduled_run.map(|run| scheduled_run_from_rpc(run)),
                         };
                         (id, entrypoint)
                     })
                     .collect();
 
+                let permissions = plugin
+                    .permissions
+                    .map(|permissions| {
+                        SettingsPluginPermissions {
+                            declared: permissions
+                                .declared
+                                .into_iter()
+                                .map(|permission| plugin_permission_from_rpc(permission))
+                                .collect(),
+                            revoked: permissions
+                                .revoked
+                                .into_iter()
+                                .map(|permission| plugin_permission_from_rpc(permission))
+                                .collect(),
+                            ask: permissions.ask,
+                            granted: permissions
+                                .granted
+                                .into_iter()
+                                .map(|permission| plugin_permission_from_rpc(permission))
+                                .collect(),
+                        }
+                    })
+                    .unwrap_or_default();
+
                 let id = PluginId::from_string(plugin.plugin_id);
                 let plugin = SettingsPlugin {
                     plugin_id: id.clone(),
@@ -440,6 +516,8 @@ impl BackendApi {
                         .into_iter()
                         .map(|(key, value)| (key, plugin_preference_user_data_from_rpc(value)))
                         .collect(),
+                    permissions,
+                    storage_usage: plugin.storage_usage,
                 };
 
                 (id, plugin)
@@ -614,6 +692,31 @@ impl BackendApi {
         Ok(())
     }
 
+    pub async fn set_plugin_permissions(
+        &mut self,
+        plugin_id: PluginId,
+        revoked: Vec<PluginPermission>,
+        ask: bool,
+        granted: Vec<PluginPermission>,
+    ) -> Result<(), BackendApiError> {
+        let request = RpcSetPluginPermissionsRequest {
+            plugin_id: plugin_id.to_string(),
+            revoked: revoked
+                .into_iter()
+                .map(|permission| plugin_permission_to_rpc(permission))
+                .collect(),
+            ask,
+            granted: granted
+                .into_iter()
+                .map(|permission| plugin_permission_to_rpc(permission))
+                .collect(),
+        };
+
+        self.client.set_plugin_permissions(Request::new(request)).await?;
+
+        Ok(())
+    }
+
     pub async fn download_plugin(&mut self, plugin_id: PluginId) -> Result<(), BackendApiError> {
         let request = RpcDownloadPluginRequest {
             plugin_id: plugin_id.to_string(),
@@ -662,14 +765,52 @@ impl BackendApi {
         Ok(())
     }
 
-    pub async fn save_local_plugin(&mut self, path: String) -> Result<LocalSaveData, BackendApiError> {
-        let request = RpcSaveLocalPluginRequest { path };
+    pub async fn clear_plugin_storage(&mut self, plugin_id: PluginId) -> Result<(), BackendApiError> {
+        let request = RpcClearPluginStorageRequest {
+            plugin_id: plugin_id.to_string(),
+        };
+
+        self.client.clear_plugin_storage(Request::new(request)).await?;
+
+        Ok(())
+    }
+
+    pub async fn plugin_audit_log(&mut self, plugin_id: PluginId) -> Result<Vec<PluginAuditLogEntry>, BackendApiError> {
+        let request = RpcPluginAuditLogRequest {
+            plugin_id: plugin_id.to_string(),
+        };
+
+        let audit_log = self
+            .client
+            .plugin_audit_log(Request::new(request))
+            .await?
+            .into_inner()
+            .audit_log
+            .into_iter()
+            .map(|entry| plugin_audit_log_entry_from_rpc(entry))
+            .collect();
+
+        Ok(audit_log)
+    }
+
+    pub async fn save_local_plugin(
+        &mut self,
+        path: String,
+        inspector_port: Option<u16>,
+        inspector_break_on_start: bool,
+    ) -> Result<LocalSaveData, BackendApiError> {
+        let request = RpcSaveLocalPluginRequest {
+            path,
+            inspector_port: inspector_port.map(|port| port as u32),
+            inspector_break_on_start,
+        };
 
         let response = self.client.save_local_plugin(Request::new(request)).await?.into_inner();
 
         Ok(LocalSaveData {
             stdout_file_path: response.stdout_file_path,
             stderr_file_path: response.stderr_file_path,
+            inspector_address: response.inspector_address,
         })
     }
 }
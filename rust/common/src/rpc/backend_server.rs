@@ -13,7 +13,9 @@ use crate::model::EntrypointId;
 use crate::model::LocalSaveData;
 use crate::model::PhysicalKey;
 use crate::model::PhysicalShortcut;
+use crate::model::PluginAuditLogEntry;
 use crate::model::PluginId;
+use crate::model::PluginPermission;
 use crate::model::PluginPreferenceUserData;
 use crate::model::SettingsEntrypointType;
 use crate::model::SettingsPlugin;
@@ -21,6 +23,8 @@ use crate::model::SettingsTheme;
 use crate::model::WindowPositionMode;
 use crate::rpc::grpc::rpc_backend_server::RpcBackend;
 use crate::rpc::grpc::rpc_backend_server::RpcBackendServer;
+use crate::rpc::grpc::RpcClearPluginStorageRequest;
+use crate::rpc::grpc::RpcClearPluginStorageResponse;
 use crate::rpc::grpc::RpcDownloadPluginRequest;
 use crate::rpc::grpc::RpcDownloadPluginResponse;
 use crate::rpc::grpc::RpcDownloadStatus;
@@ -35,9 +39,14 @@ use crate::rpc::grpc::RpcGetThemeRequest;
 use crate::rpc::grpc::RpcGetThemeResponse;
 use crate::rpc::grpc::RpcGetWindowPositionModeRequest;
 use crate::rpc::grpc::RpcGetWindowPositionModeResponse;
+use crate::rpc::grpc::RpcOpenDeepLinkRequest;
+use crate::rpc::grpc::RpcOpenDeepLinkResponse;
 use crate::rpc::grpc::RpcPingRequest;
 use crate::rpc::grpc::RpcPingResponse;
 use crate::rpc::grpc::RpcPlugin;
+use crate::rpc::grpc::RpcPluginAuditLogRequest;
+use crate::rpc::grpc::RpcPluginAuditLogResponse;
+use crate::rpc::grpc::RpcPluginPermissions;
 use crate::rpc::grpc::RpcPluginsRequest;
 use crate::rpc::grpc::RpcPluginsResponse;
 use crate::rpc::grpc::RpcRemovePluginRequest;
@@ -50,6 +59,8 @@ use crate::rpc::grpc::RpcSetEntrypointStateRequest;
 use crate::rpc::grpc::RpcSetEntrypointStateResponse;
 use crate::rpc::grpc::RpcSetGlobalShortcutRequest;
 use crate::rpc::grpc::RpcSetGlobalShortcutResponse;
+use crate::rpc::grpc::RpcSetPluginPermissionsRequest;
+use crate::rpc::grpc::RpcSetPluginPermissionsResponse;
 use crate::rpc::grpc::RpcSetPluginStateRequest;
 use crate::rpc::grpc::RpcSetPluginStateResponse;
 use crate::rpc::grpc::RpcSetPreferenceValueRequest;
@@ -63,9 +74,13 @@ use crate::rpc::grpc::RpcShowSettingsWindowRequest;
 use crate::rpc::grpc::RpcShowSettingsWindowResponse;
 use crate::rpc::grpc::RpcShowWindowRequest;
 use crate::rpc::grpc::RpcShowWindowResponse;
+use crate::rpc::grpc_convert::plugin_audit_log_entry_to_rpc;
+use crate::rpc::grpc_convert::plugin_permission_from_rpc;
+use crate::rpc::grpc_convert::plugin_permission_to_rpc;
 use crate::rpc::grpc_convert::plugin_preference_to_rpc;
 use crate::rpc::grpc_convert::plugin_preference_user_data_from_rpc;
 use crate::rpc::grpc_convert::plugin_preference_user_data_to_rpc;
+use crate::rpc::grpc_convert::scheduled_run_to_rpc;
 
 pub async fn wait_for_backend_server() {
     loop {
@@ -101,7 +116,7 @@ impl RpcBackendServerImpl {
 
 #[tonic::async_trait]
 pub trait BackendServer {
-    async fn show_window(&self) -> anyhow::Result<()>;
+    async fn show_window(&self, input: Option<String>) -> anyhow::Result<()>;
 
     async fn show_settings_window(&self) -> anyhow::Result<()>;
 
@@ -112,6 +127,8 @@ pub trait BackendServer {
         action_id: String,
     ) -> anyhow::Result<()>;
 
+    async fn open_deep_link(&self, link: String) -> anyhow::Result<()>;
+
     async fn plugins(&self) -> anyhow::Result<Vec<SettingsPlugin>>;
 
     async fn set_plugin_state(&self, plugin_id: PluginId, enabled: bool) -> anyhow::Result<()>;
@@ -143,13 +160,30 @@ pub trait BackendServer {
         preference_value: PluginPreferenceUserData,
     ) -> anyhow::Result<()>;
 
+    async fn set_plugin_permissions(
+        &self,
+        plugin_id: PluginId,
+        revoked: Vec<PluginPermission>,
+        ask: bool,
+        granted: Vec<PluginPermission>,
+    ) -> anyhow::Result<()>;
+
     async fn download_plugin(&self, plugin_id: PluginId) -> anyhow::Result<()>;
 
     async fn download_status(&self) -> anyhow::Result<HashMap<PluginId, DownloadStatus>>;
 
     async fn remove_plugin(&self, plugin_id: PluginId) -> anyhow::Result<()>;
 
-    async fn save_local_plugin(&self, path: String) -> anyhow::Result<LocalSaveData>;
+    async fn clear_plugin_storage(&self, plugin_id: PluginId) -> anyhow::Result<()>;
+
+    async fn plugin_audit_log(&self, plugin_id: PluginId) -> anyhow::Result<Vec<PluginAuditLogEntry>>;
+
+    async fn save_local_plugin(
+        &self,
+        path: String,
+        inspector_port: Option<u16>,
+        inspector_break_on_start: bool,
+    ) -> anyhow::Result<LocalSaveData>;
 }
 
 #[tonic::async_trait]
@@ -160,10 +194,12 @@ impl RpcBackend for RpcBackendServerImpl {
 
     async fn show_window(
         &self,
-        _request: Request<RpcShowWindowRequest>,
+        request: Request<RpcShowWindowRequest>,
     ) -> Result<Response<RpcShowWindowResponse>, Status> {
+        let input = request.into_inner().input;
+
         self.server
-            .show_window()
+            .show_window(input)
             .await
             .map_err(|err| Status::internal(format!("{:#}", err)))?;
 
@@ -202,6 +238,21 @@ impl RpcBackend for RpcBackendServerImpl {
         Ok(Response::new(RpcRunActionResponse::default()))
     }
 
+    async fn open_deep_link(
+        &self,
+        request: Request<RpcOpenDeepLinkRequest>,
+    ) -> Result<Response<RpcOpenDeepLinkResponse>, Status> {
+        let request = request.into_inner();
+        let link = request.link;
+
+        self.server
+            .open_deep_link(link)
+            .await
+            .map_err(|err| Status::internal(format!("{:#}", err)))?;
+
+        Ok(Response::new(RpcOpenDeepLinkResponse::default()))
+    }
+
     async fn plugins(&self, _: Request<RpcPluginsRequest>) -> Result<Response<RpcPluginsResponse>, Status> {
         let plugins = self
             .server
@@ -238,10 +289,34 @@ impl RpcBackend for RpcBackendServerImpl {
                                 .into_iter()
                                 .map(|(key, value)| (key, plugin_preference_user_data_to_rpc(value)))
                                 .collect(),
+                            interval_seconds: entrypoint.interval_seconds,
+                            last_scheduled_run: entrypoint.last_scheduled_run.map(|run| scheduled_run_to_rpc(run)),
                         }
                     })
                     .collect();
 
+                let permissions = RpcPluginPermissions {
+                    declared: plugin
+                        .permissions
+                        .declared
+                        .into_iter()
+                        .map(|permission| plugin_permission_to_rpc(permission))
+                        .collect(),
+                    revoked: plugin
+                        .permissions
+                        .revoked
+                        .into_iter()
+                        .map(|permission| plugin_permission_to_rpc(permission))
+                        .collect(),
+                    ask: plugin.permissions.ask,
+                    granted: plugin
+                        .permissions
+                        .granted
+                        .into_iter()
+                        .map(|permission| plugin_permission_to_rpc(permission))
+                        .collect(),
+                };
+
                 RpcPlugin {
                     plugin_id: plugin.plugin_id.to_string(),
                     plugin_name: plugin.plugin_name,
@@ -258,6 +333,8 @@ impl RpcBackend for RpcBackendServerImpl {
                         .into_iter()
                         .map(|(key, value)| (key, plugin_preference_user_data_to_rpc(value)))
                         .collect(),
+                    permissions: Some(permissions),
+                    storage_usage: plugin.storage_usage,
                 }
             })
             .collect();
@@ -333,6 +410,33 @@ impl RpcBackend for RpcBackendServerImpl {
         Ok(Response::new(RpcSetPreferenceValueResponse::default()))
     }
 
+    async fn set_plugin_permissions(
+        &self,
+        request: Request<RpcSetPluginPermissionsRequest>,
+    ) -> Result<Response<RpcSetPluginPermissionsResponse>, Status> {
+        let request = request.into_inner();
+        let plugin_id = PluginId::from_string(request.plugin_id);
+
+        let revoked = request
+            .revoked
+            .into_iter()
+            .map(|permission| plugin_permission_from_rpc(permission))
+            .collect();
+
+        let granted = request
+            .granted
+            .into_iter()
+            .map(|permission| plugin_permission_from_rpc(permission))
+            .collect();
+
+        self.server
+            .set_plugin_permissions(plugin_id, revoked, request.ask, granted)
+            .await
+            .map_err(|err| Status::internal(format!("{:#}", err)))?;
+
+        Ok(Response::new(RpcSetPluginPermissionsResponse::default()))
+    }
+
     async fn set_global_shortcut(
         &self,
         request: Request<RpcSetGlobalShortcutRequest>,
@@ -536,22 +640,68 @@ impl RpcBackend for RpcBackendServerImpl {
         Ok(Response::new(RpcRemovePluginResponse::default()))
     }
 
+    async fn clear_plugin_storage(
+        &self,
+        request: Request<RpcClearPluginStorageRequest>,
+    ) -> Result<Response<RpcClearPluginStorageResponse>, Status> {
+        let request = request.into_inner();
+        let plugin_id = request.plugin_id;
+
+        let plugin_id = PluginId::from_string(plugin_id);
+
+        self.server
+            .clear_plugin_storage(plugin_id)
+            .await
+            .map_err(|err| Status::internal(format!("{:#}", err)))?;
+
+        Ok(Response::new(RpcClearPluginStorageResponse::default()))
+    }
+
+    async fn plugin_audit_log(
+        &self,
+        request: Request<RpcPluginAuditLogRequest>,
+    ) -> Result<Response<RpcPluginAuditLogResponse>, Status> {
+        let request = request.into_inner();
+        let plugin_id = request.plugin_id;
+
+        let plugin_id = PluginId::from_string(plugin_id);
+
+        let audit_log = self
+            .server
+            .plugin_audit_log(plugin_id)
+            .await
+            .map_err(|err| Status::internal(format!("{:#}", err)))?;
+
+        let audit_log = audit_log
+            .into_iter()
+            .map(|entry| plugin_audit_log_entry_to_rpc(entry))
+            .collect();
+
+        Ok(Response::new(RpcPluginAuditLogResponse { audit_log }))
+    }
+
     async fn save_local_plugin(
         &self,
         request: Request<RpcSaveLocalPluginRequest>,
     ) -> Result<Response<RpcSaveLocalPluginResponse>, Status> {
         let request = request.into_inner();
         let path = request.path;
+        let inspector_port = request
+            .inspector_port
+            .map(|port| u16::try_from(port))
+            .transpose()
+            .map_err(|_| Status::invalid_argument("inspector_port"))?;
 
         let local_save_data = self
             .server
-            .save_local_plugin(path)
+            .save_local_plugin(path, inspector_port, request.inspector_break_on_start)
             .await
             .map_err(|err| Status::internal(format!("{:#}", err)))?;
 
         Ok(Response::new(RpcSaveLocalPluginResponse {
             stdout_file_path: local_save_data.stdout_file_path,
             stderr_file_path: local_save_data.stderr_file_path,
+            inspector_address: local_save_data.inspector_address,
         }))
     }
 }
@@ -6,9 +6,12 @@ use gauntlet_utils::channel::RequestSender;
 use thiserror::Error;
 
 use crate::model::EntrypointId;
+use crate::model::PermissionPromptDecision;
 use crate::model::PhysicalShortcut;
 use crate::model::PluginId;
+use crate::model::PluginPermission;
 use crate::model::RootWidget;
+use crate::model::SearchResult;
 use crate::model::UiRenderLocation;
 use crate::model::UiRequestData;
 use crate::model::UiResponseData;
@@ -52,6 +55,23 @@ impl FrontendApi {
         Ok(())
     }
 
+    pub async fn update_search_result_rows(
+        &self,
+        plugin_id: PluginId,
+        updated: Vec<SearchResult>,
+        removed: Vec<EntrypointId>,
+    ) -> Result<(), FrontendApiError> {
+        let request = UiRequestData::UpdateSearchResultRows {
+            plugin_id,
+            updated,
+            removed,
+        };
+
+        let _ = self.frontend_sender.send_receive(request).await;
+
+        Ok(())
+    }
+
     pub async fn replace_view(
         &self,
         plugin_id: PluginId,
@@ -188,6 +208,7 @@ impl FrontendApi {
         match data {
             UiResponseData::Nothing => Ok(()),
             UiResponseData::Err(err) => Err(err),
+            _ => unreachable!(),
         }
     }
 
@@ -203,6 +224,7 @@ impl FrontendApi {
         match data {
             UiResponseData::Nothing => Ok(()),
             UiResponseData::Err(err) => Err(err),
+            _ => unreachable!(),
         }
     }
 
@@ -218,6 +240,7 @@ impl FrontendApi {
         match data {
             UiResponseData::Nothing => Ok(()),
             UiResponseData::Err(err) => Err(err),
+            _ => unreachable!(),
         }
     }
 
@@ -249,12 +272,30 @@ impl FrontendApi {
         plugin_name: String,
         entrypoint_id: EntrypointId,
         entrypoint_name: String,
+        arguments: HashMap<String, String>,
     ) -> Result<(), FrontendApiError> {
         let data = UiRequestData::ShowPluginView {
             plugin_id,
             plugin_name,
             entrypoint_id,
             entrypoint_name,
+            arguments,
+        };
+        let UiResponseData::Nothing = self.frontend_sender.send_receive(data).await? else {
+            unreachable!()
+        };
+
+        Ok(())
+    }
+
+    pub async fn show_entrypoint_arguments(
+        &self,
+        search_result: SearchResult,
+        arguments: HashMap<String, String>,
+    ) -> Result<(), FrontendApiError> {
+        let data = UiRequestData::ShowEntrypointArguments {
+            search_result,
+            arguments,
         };
         let UiResponseData::Nothing = self.frontend_sender.send_receive(data).await? else {
             unreachable!()
@@ -272,4 +313,26 @@ impl FrontendApi {
 
         Ok(())
     }
+
+    pub async fn show_permission_prompt(
+        &self,
+        plugin_id: PluginId,
+        plugin_name: String,
+        permission: PluginPermission,
+    ) -> Result<PermissionPromptDecision, FrontendApiError> {
+        let data = UiRequestData::ShowPermissionPrompt {
+            plugin_id,
+            plugin_name,
+            permission,
+        };
+
+        // no timeout, user can take as much time as they need to make a decision
+        let mut receiver = self.frontend_sender.send(data)?;
+
+        match receiver.recv().await {
+            UiResponseData::PermissionPrompt { decision } => Ok(decision),
+            UiResponseData::Err(err) => Err(FrontendApiError::OtherError(err)),
+            UiResponseData::Nothing => unreachable!(),
+        }
+    }
 }
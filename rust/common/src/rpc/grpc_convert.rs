@@ -1,11 +1,19 @@
+use crate::model::PluginAuditLogEntry;
+use crate::model::PluginPermission;
+use crate::model::PluginPermissionKind;
 use crate::model::PluginPreference;
 use crate::model::PluginPreferenceUserData;
 use crate::model::PreferenceEnumValue;
+use crate::model::ScheduledRun;
 use crate::rpc::grpc::rpc_ui_property_value::Value;
 use crate::rpc::grpc::RpcEnumValue;
+use crate::rpc::grpc::RpcPluginAuditLogEntry;
+use crate::rpc::grpc::RpcPluginPermission;
+use crate::rpc::grpc::RpcPluginPermissionKind;
 use crate::rpc::grpc::RpcPluginPreference;
 use crate::rpc::grpc::RpcPluginPreferenceUserData;
 use crate::rpc::grpc::RpcPluginPreferenceValueType;
+use crate::rpc::grpc::RpcScheduledRun;
 use crate::rpc::grpc::RpcUiPropertyValue;
 
 pub fn plugin_preference_user_data_from_rpc(value: RpcPluginPreferenceUserData) -> PluginPreferenceUserData {
@@ -559,3 +567,75 @@ pub fn plugin_preference_from_rpc(value: RpcPluginPreference) -> PluginPreferenc
         }
     }
 }
+
+pub fn plugin_permission_to_rpc(value: PluginPermission) -> RpcPluginPermission {
+    let kind = match value.kind {
+        PluginPermissionKind::Environment => RpcPluginPermissionKind::PEnvironment,
+        PluginPermissionKind::Network => RpcPluginPermissionKind::PNetwork,
+        PluginPermissionKind::FilesystemRead => RpcPluginPermissionKind::PFilesystemRead,
+        PluginPermissionKind::FilesystemWrite => RpcPluginPermissionKind::PFilesystemWrite,
+        PluginPermissionKind::ExecCommand => RpcPluginPermissionKind::PExecCommand,
+        PluginPermissionKind::ExecExecutable => RpcPluginPermissionKind::PExecExecutable,
+        PluginPermissionKind::System => RpcPluginPermissionKind::PSystem,
+        PluginPermissionKind::Clipboard => RpcPluginPermissionKind::PClipboard,
+        PluginPermissionKind::MainSearchBar => RpcPluginPermissionKind::PMainSearchBar,
+        PluginPermissionKind::Plugins => RpcPluginPermissionKind::PPlugins,
+    };
+
+    RpcPluginPermission {
+        kind: kind.into(),
+        resource: value.resource,
+    }
+}
+
+pub fn plugin_permission_from_rpc(value: RpcPluginPermission) -> PluginPermission {
+    let kind: RpcPluginPermissionKind = value.kind.try_into().unwrap();
+
+    let kind = match kind {
+        RpcPluginPermissionKind::PEnvironment => PluginPermissionKind::Environment,
+        RpcPluginPermissionKind::PNetwork => PluginPermissionKind::Network,
+        RpcPluginPermissionKind::PFilesystemRead => PluginPermissionKind::FilesystemRead,
+        RpcPluginPermissionKind::PFilesystemWrite => PluginPermissionKind::FilesystemWrite,
+        RpcPluginPermissionKind::PExecCommand => PluginPermissionKind::ExecCommand,
+        RpcPluginPermissionKind::PExecExecutable => PluginPermissionKind::ExecExecutable,
+        RpcPluginPermissionKind::PSystem => PluginPermissionKind::System,
+        RpcPluginPermissionKind::PClipboard => PluginPermissionKind::Clipboard,
+        RpcPluginPermissionKind::PMainSearchBar => PluginPermissionKind::MainSearchBar,
+        RpcPluginPermissionKind::PPlugins => PluginPermissionKind::Plugins,
+    };
+
+    PluginPermission {
+        kind,
+        resource: value.resource,
+    }
+}
+
+pub fn plugin_audit_log_entry_to_rpc(value: PluginAuditLogEntry) -> RpcPluginAuditLogEntry {
+    RpcPluginAuditLogEntry {
+        timestamp: value.timestamp,
+        permission: Some(plugin_permission_to_rpc(value.permission)),
+        allowed: value.allowed,
+    }
+}
+
+pub fn plugin_audit_log_entry_from_rpc(value: RpcPluginAuditLogEntry) -> PluginAuditLogEntry {
+    PluginAuditLogEntry {
+        timestamp: value.timestamp,
+        permission: plugin_permission_from_rpc(value.permission.unwrap()),
+        allowed: value.allowed,
+    }
+}
+
+pub fn scheduled_run_to_rpc(value: ScheduledRun) -> RpcScheduledRun {
+    RpcScheduledRun {
+        timestamp: value.timestamp,
+        error: value.error,
+    }
+}
+
+pub fn scheduled_run_from_rpc(value: RpcScheduledRun) -> ScheduledRun {
+    ScheduledRun {
+        timestamp: value.timestamp,
+        error: value.error,
+    }
+}
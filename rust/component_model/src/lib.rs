@@ -85,6 +85,8 @@ pub enum PropertyType {
     Union { items: Vec<PropertyType> },
     #[serde(rename = "array")]
     Array { item: Box<PropertyType> },
+    #[serde(rename = "record")]
+    Record { value: Box<PropertyType> },
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -118,6 +120,7 @@ impl PropertyType {
                 first_variant.kind()
             }
             PropertyType::Array { item } => item.kind(),
+            PropertyType::Record { .. } => PropertyKind::Property,
         }
     }
 }
@@ -589,6 +592,15 @@ fn root(children: &[&Component]) -> Component {
                     ],
                 },
             ),
+            (
+                "DatePickerMode".to_owned(),
+                SharedType::Enum {
+                    items: ["Date", "Time", "DateTime"]
+                        .into_iter()
+                        .map(|s| s.to_string())
+                        .collect(),
+                },
+            ),
         ]),
     }
 }
@@ -777,14 +789,18 @@ pub fn create_component_model() -> Vec<Component> {
         ),
     );
 
-    // let link_component = component(
-    //     "link",
-    //     "Link",
-    //     [
-    //         property("href", false, PropertyType::String),
-    //     ],
-    //     children_string(),
-    // );
+    let link_component = component(
+        "link",
+        mark_doc!("/link/description.md"),
+        "Link",
+        [property(
+            "href",
+            mark_doc!("/link/props/href.md"),
+            false,
+            PropertyType::String,
+        )],
+        children_string(mark_doc!("/link/props/children.md")),
+    );
 
     let image_component = component(
         "image",
@@ -865,26 +881,37 @@ pub fn create_component_model() -> Vec<Component> {
         children_string(mark_doc!("/code_block/props/children.md")),
     );
 
-    // let code_component = component(
-    //     "code",
-    //     "Code",
-    //     [],
-    //     children_string()
-    // );
+    let markdown_component = component(
+        "markdown",
+        mark_doc!("/markdown/description.md"),
+        "Markdown",
+        [],
+        children_string(mark_doc!("/markdown/props/children.md")),
+    );
+
+    let code_component = component(
+        "code",
+        mark_doc!("/code/description.md"),
+        "Code",
+        [],
+        children_string(mark_doc!("/code/props/children.md")),
+    );
 
     let paragraph_component = component(
         "paragraph",
         mark_doc!("/paragraph/description.md"),
         "Paragraph",
         [],
-        children_string(mark_doc!("/paragraph/props/children.md")),
-        // children_string_or_members([
-        //     member("Link", &link_component),
-        //     member("Code", &code_component),
-        // ]),
+        children_string_or_members(
+            [
+                member("Link", &link_component, Arity::ZeroOrMore),
+                member("Code", &code_component, Arity::ZeroOrMore),
+            ],
+            [],
+        ),
     );
 
-    // content shouldn't have any interactable items
+    // content shouldn't have any interactable items, except links inside of paragraph
     let content_component = component(
         "content",
         mark_doc!("/content/description.md"),
@@ -903,6 +930,7 @@ pub fn create_component_model() -> Vec<Component> {
                 member("H6", &h6_component, Arity::ZeroOrMore),
                 member("HorizontalBreak", &horizontal_break_component, Arity::ZeroOrMore),
                 member("CodeBlock", &code_block_component, Arity::ZeroOrMore),
+                member("Markdown", &markdown_component, Arity::ZeroOrMore),
                 // member("Code", &code_component),
             ],
             [],
@@ -941,6 +969,7 @@ pub fn create_component_model() -> Vec<Component> {
         mark_doc!("/text_field/description.md"),
         "TextField",
         [
+            property("id", mark_doc!("/text_field/props/id.md"), true, PropertyType::String),
             property(
                 "label",
                 mark_doc!("/text_field/props/label.md"),
@@ -953,6 +982,18 @@ pub fn create_component_model() -> Vec<Component> {
                 true,
                 PropertyType::String,
             ),
+            property(
+                "error",
+                mark_doc!("/text_field/props/error.md"),
+                true,
+                PropertyType::String,
+            ),
+            property(
+                "info",
+                mark_doc!("/text_field/props/info.md"),
+                true,
+                PropertyType::String,
+            ),
             event(
                 "onChange",
                 mark_doc!("/text_field/props/onChange.md"),
@@ -968,6 +1009,12 @@ pub fn create_component_model() -> Vec<Component> {
         mark_doc!("/password_field/description.md"),
         "PasswordField",
         [
+            property(
+                "id",
+                mark_doc!("/password_field/props/id.md"),
+                true,
+                PropertyType::String,
+            ),
             property(
                 "label",
                 mark_doc!("/password_field/props/label.md"),
@@ -980,6 +1027,18 @@ pub fn create_component_model() -> Vec<Component> {
                 true,
                 PropertyType::String,
             ),
+            property(
+                "error",
+                mark_doc!("/password_field/props/error.md"),
+                true,
+                PropertyType::String,
+            ),
+            property(
+                "info",
+                mark_doc!("/password_field/props/info.md"),
+                true,
+                PropertyType::String,
+            ),
             event(
                 "onChange",
                 mark_doc!("/password_field/props/onChange.md"),
@@ -990,18 +1049,116 @@ pub fn create_component_model() -> Vec<Component> {
         children_none(),
     );
 
-    // let text_area_component = component(
-    //     "text_area",
-    //     "TextArea",
-    //     [],
-    //     children_none(),
-    // );
+    let number_field_component = component(
+        "number_field",
+        mark_doc!("/number_field/description.md"),
+        "NumberField",
+        [
+            property("id", mark_doc!("/number_field/props/id.md"), true, PropertyType::String),
+            property(
+                "label",
+                mark_doc!("/number_field/props/label.md"),
+                true,
+                PropertyType::String,
+            ),
+            property(
+                "value",
+                mark_doc!("/number_field/props/value.md"),
+                true,
+                PropertyType::Number,
+            ),
+            property(
+                "min",
+                mark_doc!("/number_field/props/min.md"),
+                true,
+                PropertyType::Number,
+            ),
+            property(
+                "max",
+                mark_doc!("/number_field/props/max.md"),
+                true,
+                PropertyType::Number,
+            ),
+            property(
+                "step",
+                mark_doc!("/number_field/props/step.md"),
+                true,
+                PropertyType::Number,
+            ),
+            property(
+                "precision",
+                mark_doc!("/number_field/props/precision.md"),
+                true,
+                PropertyType::Number,
+            ),
+            property(
+                "error",
+                mark_doc!("/number_field/props/error.md"),
+                true,
+                PropertyType::String,
+            ),
+            property(
+                "info",
+                mark_doc!("/number_field/props/info.md"),
+                true,
+                PropertyType::String,
+            ),
+            event(
+                "onChange",
+                mark_doc!("/number_field/props/onChange.md"),
+                true,
+                [property("value", "".to_string(), false, PropertyType::Number)],
+            ),
+        ],
+        children_none(),
+    );
+
+    let text_area_component = component(
+        "text_area",
+        mark_doc!("/text_area/description.md"),
+        "TextArea",
+        [
+            property("id", mark_doc!("/text_area/props/id.md"), true, PropertyType::String),
+            property(
+                "label",
+                mark_doc!("/text_area/props/label.md"),
+                true,
+                PropertyType::String,
+            ),
+            property(
+                "value",
+                mark_doc!("/text_area/props/value.md"),
+                true,
+                PropertyType::String,
+            ),
+            property(
+                "error",
+                mark_doc!("/text_area/props/error.md"),
+                true,
+                PropertyType::String,
+            ),
+            property(
+                "info",
+                mark_doc!("/text_area/props/info.md"),
+                true,
+                PropertyType::String,
+            ),
+            event(
+                "onChange",
+                mark_doc!("/text_area/props/onChange.md"),
+                true,
+                [property("value", "".to_string(), true, PropertyType::String)],
+            ),
+        ],
+        children_none(),
+    );
 
     let checkbox_component = component(
         "checkbox",
         mark_doc!("/checkbox/description.md"),
         "Checkbox",
         [
+            property("id", mark_doc!("/checkbox/props/id.md"), true, PropertyType::String),
             property(
                 "label",
                 mark_doc!("/checkbox/props/label.md"),
@@ -1020,6 +1177,13 @@ pub fn create_component_model() -> Vec<Component> {
                 true,
                 PropertyType::Boolean,
             ),
+            property(
+                "error",
+                mark_doc!("/checkbox/props/error.md"),
+                true,
+                PropertyType::String,
+            ),
+            property("info", mark_doc!("/checkbox/props/info.md"), true, PropertyType::String),
             event(
                 "onChange",
                 mark_doc!("/checkbox/props/onChange.md"),
@@ -1035,6 +1199,7 @@ pub fn create_component_model() -> Vec<Component> {
         mark_doc!("/date_picker/description.md"),
         "DatePicker",
         [
+            property("id", mark_doc!("/date_picker/props/id.md"), true, PropertyType::String),
             property(
                 "label",
                 mark_doc!("/date_picker/props/label.md"),
@@ -1047,6 +1212,26 @@ pub fn create_component_model() -> Vec<Component> {
                 true,
                 PropertyType::String,
             ),
+            property(
+                "mode",
+                mark_doc!("/date_picker/props/mode.md"),
+                true,
+                PropertyType::SharedTypeRef {
+                    name: "DatePickerMode".to_owned(),
+                },
+            ),
+            property(
+                "error",
+                mark_doc!("/date_picker/props/error.md"),
+                true,
+                PropertyType::String,
+            ),
+            property(
+                "info",
+                mark_doc!("/date_picker/props/info.md"),
+                true,
+                PropertyType::String,
+            ),
             event(
                 "onChange",
                 mark_doc!("/date_picker/props/onChange.md"),
@@ -1075,8 +1260,11 @@ pub fn create_component_model() -> Vec<Component> {
         mark_doc!("/select/description.md"),
         "Select",
         [
+            property("id", mark_doc!("/select/props/id.md"), true, PropertyType::String),
             property("label", mark_doc!("/select/props/label.md"), true, PropertyType::String),
             property("value", mark_doc!("/select/props/value.md"), true, PropertyType::String),
+            property("error", mark_doc!("/select/props/error.md"), true, PropertyType::String),
+            property("info", mark_doc!("/select/props/info.md"), true, PropertyType::String),
             event(
                 "onChange",
                 mark_doc!("/select/props/onChange.md"),
@@ -1087,12 +1275,123 @@ pub fn create_component_model() -> Vec<Component> {
         children_members([member("Item", &select_item_component, Arity::ZeroOrMore)], []),
     );
 
-    // let multi_select_component = component(
-    //     "multi_select",
-    //     "MultiSelect",
-    //     [],
-    //     children_none(),
-    // );
+    let multi_select_component = component(
+        "multi_select",
+        mark_doc!("/multi_select/description.md"),
+        "MultiSelect",
+        [
+            property("id", mark_doc!("/multi_select/props/id.md"), true, PropertyType::String),
+            property(
+                "label",
+                mark_doc!("/multi_select/props/label.md"),
+                true,
+                PropertyType::String,
+            ),
+            property(
+                "value",
+                mark_doc!("/multi_select/props/value.md"),
+                true,
+                PropertyType::Array {
+                    item: Box::new(PropertyType::String),
+                },
+            ),
+            property(
+                "error",
+                mark_doc!("/multi_select/props/error.md"),
+                true,
+                PropertyType::String,
+            ),
+            property(
+                "info",
+                mark_doc!("/multi_select/props/info.md"),
+                true,
+                PropertyType::String,
+            ),
+            event(
+                "onChange",
+                mark_doc!("/multi_select/props/onChange.md"),
+                true,
+                [property(
+                    "values",
+                    "".to_string(),
+                    false,
+                    PropertyType::Array {
+                        item: Box::new(PropertyType::String),
+                    },
+                )],
+            ),
+        ],
+        children_members([member("Item", &select_item_component, Arity::ZeroOrMore)], []),
+    );
+
+    let file_picker_component = component(
+        "file_picker",
+        mark_doc!("/file_picker/description.md"),
+        "FilePicker",
+        [
+            property("id", mark_doc!("/file_picker/props/id.md"), true, PropertyType::String),
+            property(
+                "label",
+                mark_doc!("/file_picker/props/label.md"),
+                true,
+                PropertyType::String,
+            ),
+            property(
+                "value",
+                mark_doc!("/file_picker/props/value.md"),
+                true,
+                PropertyType::Array {
+                    item: Box::new(PropertyType::String),
+                },
+            ),
+            property(
+                "allowMultiple",
+                mark_doc!("/file_picker/props/allowMultiple.md"),
+                true,
+                PropertyType::Boolean,
+            ),
+            property(
+                "canChooseDirectories",
+                mark_doc!("/file_picker/props/canChooseDirectories.md"),
+                true,
+                PropertyType::Boolean,
+            ),
+            property(
+                "extensions",
+                mark_doc!("/file_picker/props/extensions.md"),
+                true,
+                PropertyType::Array {
+                    item: Box::new(PropertyType::String),
+                },
+            ),
+            property(
+                "error",
+                mark_doc!("/file_picker/props/error.md"),
+                true,
+                PropertyType::String,
+            ),
+            property(
+                "info",
+                mark_doc!("/file_picker/props/info.md"),
+                true,
+                PropertyType::String,
+            ),
+            event(
+                "onChange",
+                mark_doc!("/file_picker/props/onChange.md"),
+                true,
+                [property(
+                    "values",
+                    "".to_string(),
+                    false,
+                    PropertyType::Array {
+                        item: Box::new(PropertyType::String),
+                    },
+                )],
+            ),
+        ],
+        children_none(),
+    );
 
     let separator_component = component(
         "separator",
@@ -1119,16 +1418,39 @@ pub fn create_component_model() -> Vec<Component> {
                 true,
                 component_ref(&action_panel_component, Arity::ZeroOrOne),
             ),
+            event(
+                "onSubmit",
+                mark_doc!("/form/props/onSubmit.md"),
+                true,
+                [property(
+                    "values",
+                    "".to_string(),
+                    false,
+                    PropertyType::Record {
+                        value: Box::new(PropertyType::Union {
+                            items: vec![
+                                PropertyType::String,
+                                PropertyType::Boolean,
+                                PropertyType::Array {
+                                    item: Box::new(PropertyType::String),
+                                },
+                            ],
+                        }),
+                    },
+                )],
+            ),
         ],
         children_members(
             [
                 member("TextField", &text_field_component, Arity::ZeroOrMore),
                 member("PasswordField", &password_field_component, Arity::ZeroOrMore),
-                // member("TextArea", &text_area_component),
+                member("NumberField", &number_field_component, Arity::ZeroOrMore),
+                member("TextArea", &text_area_component, Arity::ZeroOrMore),
                 member("Checkbox", &checkbox_component, Arity::ZeroOrMore),
                 member("DatePicker", &date_picker_component, Arity::ZeroOrMore),
                 member("Select", &select_component, Arity::ZeroOrMore),
-                // member("MultiSelect", &multi_select_component),
+                member("MultiSelect", &multi_select_component, Arity::ZeroOrMore),
+                member("FilePicker", &file_picker_component, Arity::ZeroOrMore),
                 member("Separator", &separator_component, Arity::ZeroOrMore),
             ],
             [],
@@ -1252,6 +1574,76 @@ pub fn create_component_model() -> Vec<Component> {
         children_none(),
     );
 
+    let search_bar_dropdown_item_component = component(
+        "search_bar_dropdown_item",
+        mark_doc!("/search_bar_dropdown_item/description.md"),
+        "SearchBarDropdownItem",
+        [
+            property(
+                "value",
+                mark_doc!("/search_bar_dropdown_item/props/value.md"),
+                false,
+                PropertyType::String,
+            ),
+            property(
+                "title",
+                mark_doc!("/search_bar_dropdown_item/props/title.md"),
+                false,
+                PropertyType::String,
+            ),
+        ],
+        children_none(),
+    );
+
+    let search_bar_dropdown_section_component = component(
+        "search_bar_dropdown_section",
+        mark_doc!("/search_bar_dropdown_section/description.md"),
+        "SearchBarDropdownSection",
+        [property(
+            "title",
+            mark_doc!("/search_bar_dropdown_section/props/title.md"),
+            false,
+            PropertyType::String,
+        )],
+        children_members(
+            [member("Item", &search_bar_dropdown_item_component, Arity::ZeroOrMore)],
+            [],
+        ),
+    );
+
+    let search_bar_dropdown_component = component(
+        "search_bar_dropdown",
+        mark_doc!("/search_bar_dropdown/description.md"),
+        "SearchBarDropdown",
+        [
+            property(
+                "value",
+                mark_doc!("/search_bar_dropdown/props/value.md"),
+                true,
+                PropertyType::String,
+            ),
+            property(
+                "placeholder",
+                mark_doc!("/search_bar_dropdown/props/placeholder.md"),
+                true,
+                PropertyType::String,
+            ),
+            event(
+                "onChange",
+                mark_doc!("/search_bar_dropdown/props/onChange.md"),
+                true,
+                [property("value", "".to_string(), false, PropertyType::String)],
+            ),
+        ],
+        children_members(
+            [
+                member("Item", &search_bar_dropdown_item_component, Arity::ZeroOrMore),
+                member("Section", &search_bar_dropdown_section_component, Arity::ZeroOrMore),
+            ],
+            [],
+        ),
+    );
+
     let search_bar_component = component(
         "search_bar",
         mark_doc!("/search_bar/description.md"),
@@ -1276,7 +1668,10 @@ pub fn create_component_model() -> Vec<Component> {
                 [property("value", "".to_string(), true, PropertyType::String)],
             ),
         ],
-        children_none(),
+        children_members(
+            [],
+            [member("Dropdown", &search_bar_dropdown_component, Arity::ZeroOrOne)],
+        ),
     );
 
     let list_item_component = component(
@@ -1366,6 +1761,13 @@ pub fn create_component_model() -> Vec<Component> {
                 true,
                 [property("itemId", "".to_string(), true, PropertyType::String)],
             ),
+            property(
+                "hasMore",
+                mark_doc!("/list/props/hasMore.md"),
+                true,
+                PropertyType::Boolean,
+            ),
+            event("onLoadMore", mark_doc!("/list/props/onLoadMore.md"), true, []),
         ],
         children_members(
             [
@@ -1469,6 +1871,13 @@ pub fn create_component_model() -> Vec<Component> {
                 true,
                 [property("itemId", "".to_string(), true, PropertyType::String)],
             ),
+            property(
+                "hasMore",
+                mark_doc!("/grid/props/hasMore.md"),
+                true,
+                PropertyType::Boolean,
+            ),
+            event("onLoadMore", mark_doc!("/grid/props/onLoadMore.md"), true, []),
         ],
         children_members(
             [
@@ -1482,6 +1891,99 @@ pub fn create_component_model() -> Vec<Component> {
         ),
     );
 
+    let table_column_component = component(
+        "table_column",
+        mark_doc!("/table_column/description.md"),
+        "TableColumn",
+        [
+            property(
+                "id",
+                mark_doc!("/table_column/props/id.md"),
+                false,
+                PropertyType::String,
+            ),
+            property(
+                "title",
+                mark_doc!("/table_column/props/title.md"),
+                false,
+                PropertyType::String,
+            ),
+            property(
+                "width",
+                mark_doc!("/table_column/props/width.md"),
+                true,
+                PropertyType::Number,
+            ),
+            property(
+                "sortable",
+                mark_doc!("/table_column/props/sortable.md"),
+                true,
+                PropertyType::Boolean,
+            ),
+        ],
+        children_none(),
+    );
+
+    let table_cell_component = component(
+        "table_cell",
+        mark_doc!("/table_cell/description.md"),
+        "TableCell",
+        [],
+        children_string(mark_doc!("/table_cell/props/children.md")),
+    );
+
+    let table_row_component = component(
+        "table_row",
+        mark_doc!("/table_row/description.md"),
+        "TableRow",
+        [
+            property("id", mark_doc!("/table_row/props/id.md"), false, PropertyType::String),
+            property(
+                "actions",
+                mark_doc!("/table_row/props/actions.md"),
+                true,
+                component_ref(&action_panel_component, Arity::ZeroOrOne),
+            ),
+        ],
+        children_members([member("Cell", &table_cell_component, Arity::ZeroOrMore)], []),
+    );
+
+    let table_component = component(
+        "table",
+        mark_doc!("/table/description.md"),
+        "Table",
+        [
+            property(
+                "isLoading",
+                mark_doc!("/list/props/isLoading.md"),
+                true,
+                PropertyType::Boolean,
+            ),
+            property(
+                "actions",
+                mark_doc!("/table/props/actions.md"),
+                true,
+                component_ref(&action_panel_component, Arity::ZeroOrOne),
+            ),
+            event(
+                "onRowFocusChange",
+                mark_doc!("/table/props/onRowFocusChange.md"),
+                true,
+                [property("rowId", "".to_string(), true, PropertyType::String)],
+            ),
+        ],
+        children_members(
+            [
+                member("Column", &table_column_component, Arity::ZeroOrMore),
+                member("Row", &table_row_component, Arity::ZeroOrMore),
+            ],
+            [
+                member("SearchBar", &search_bar_component, Arity::ZeroOrOne),
+                member("EmptyView", &empty_view_component, Arity::ZeroOrOne),
+            ],
+        ),
+    );
+
     let text_part = text_part();
 
     let root = root(&[
@@ -1490,11 +1992,14 @@ pub fn create_component_model() -> Vec<Component> {
         &inline_component,
         &list_component,
         &grid_component,
+        &table_component,
     ]);
 
     // Detail
     // Detail.Content
     // Detail.Content.Paragraph
+    // Detail.Content.Paragraph.Link
+    // Detail.Content.Paragraph.Code
     // Detail.Content.Image
     // Detail.Content.H1-6
     // Detail.Content.HorizontalBreak
@@ -1551,6 +2056,13 @@ pub fn create_component_model() -> Vec<Component> {
     // Grid.Item
     // Grid.Section
 
+    // Table
+    // Table.Column
+    // Table.Row
+    // Table.Row.Cell
+    // Table.SearchBar
+    // Table.EmptyView
+
     vec![
         text_part,
         action_component,
@@ -1563,7 +2075,7 @@ pub fn create_component_model() -> Vec<Component> {
         metadata_value_component,
         metadata_icon_component,
         metadata_component,
-        // link_component,
+        link_component,
         image_component,
         h1_component,
         h2_component,
@@ -1573,18 +2085,21 @@ pub fn create_component_model() -> Vec<Component> {
         h6_component,
         horizontal_break_component,
         code_block_component,
-        // code_component,
+        markdown_component,
+        code_component,
         paragraph_component,
         content_component,
         detail_component,
         text_field_component,
         password_field_component,
-        // text_area_component,
+        number_field_component,
+        text_area_component,
         checkbox_component,
         date_picker_component,
         select_item_component,
         select_component,
-        // multi_select_component,
+        multi_select_component,
+        file_picker_component,
         separator_component,
         form_component,
         inline_separator_component,
@@ -1592,6 +2107,9 @@ pub fn create_component_model() -> Vec<Component> {
         empty_view_component,
         accessory_icon_component,
         accessory_text_component,
+        search_bar_dropdown_item_component,
+        search_bar_dropdown_section_component,
+        search_bar_dropdown_component,
         search_bar_component,
         list_item_component,
         list_section_component,
@@ -1599,6 +2117,10 @@ pub fn create_component_model() -> Vec<Component> {
         grid_item_component,
         grid_section_component,
         grid_component,
+        table_column_component,
+        table_cell_component,
+        table_row_component,
+        table_component,
         root,
     ]
 }
@@ -194,6 +194,9 @@ fn update(state: &mut ManagementAppModel, message: ManagementAppMsg) -> Task<Man
                     ManagementAppPluginMsgOut::PluginsReloaded(plugins) => {
                         ManagementAppMsg::Plugin(ManagementAppPluginMsgIn::PluginsFetched(plugins))
                     }
+                    ManagementAppPluginMsgOut::AuditLogReloaded(plugin_id, audit_log) => {
+                        ManagementAppMsg::Plugin(ManagementAppPluginMsgIn::AuditLogFetched(plugin_id, audit_log))
+                    }
                     ManagementAppPluginMsgOut::Noop => ManagementAppMsg::Plugin(ManagementAppPluginMsgIn::Noop),
                     ManagementAppPluginMsgOut::DownloadPlugin { plugin_id } => {
                         ManagementAppMsg::DownloadPlugin { plugin_id }
@@ -3,6 +3,7 @@ use std::collections::HashMap;
 use std::rc::Rc;
 
 use gauntlet_common::model::EntrypointId;
+use gauntlet_common::model::PluginAuditLogEntry;
 use gauntlet_common::model::PluginId;
 use gauntlet_common::model::PluginPreferenceUserData;
 use gauntlet_common::model::SettingsPlugin;
@@ -32,24 +33,36 @@ use iced_fonts::BOOTSTRAP_FONT;
 use crate::theme::button::ButtonStyle;
 use crate::theme::text::TextStyle;
 use crate::theme::Element;
+use crate::views::plugins::audit_log::audit_log_ui;
+use crate::views::plugins::permissions::permissions_ui;
+use crate::views::plugins::permissions::PluginPermissionsMsg;
 use crate::views::plugins::preferences::preferences_ui;
 use crate::views::plugins::preferences::PluginPreferencesMsg;
 use crate::views::plugins::preferences::SelectItem;
+use crate::views::plugins::schedule::schedule_ui;
+use crate::views::plugins::storage::storage_ui;
 use crate::views::plugins::table::PluginTableMsgIn;
 use crate::views::plugins::table::PluginTableMsgOut;
 use crate::views::plugins::table::PluginTableState;
 use crate::views::plugins::table::PluginTableUpdateResult;
 
+mod audit_log;
+mod permissions;
 mod preferences;
+mod schedule;
+mod storage;
 mod table;
 
 #[derive(Debug, Clone)]
 pub enum ManagementAppPluginMsgIn {
     PluginTableMsg(PluginTableMsgIn),
     PluginPreferenceMsg(PluginPreferencesMsg),
+    PluginPermissionsMsg(PluginPermissionsMsg),
     FetchPlugins,
     PluginsFetched(HashMap<PluginId, SettingsPlugin>),
+    AuditLogFetched(PluginId, Vec<PluginAuditLogEntry>),
     RemovePlugin { plugin_id: PluginId },
+    ClearPluginStorage { plugin_id: PluginId },
     DownloadPlugin { plugin_id: PluginId },
     SelectItem(SelectedItem),
     Noop,
@@ -57,6 +70,7 @@ pub enum ManagementAppPluginMsgIn {
 
 pub enum ManagementAppPluginMsgOut {
     PluginsReloaded(HashMap<PluginId, SettingsPlugin>),
+    AuditLogReloaded(PluginId, Vec<PluginAuditLogEntry>),
     SelectedItem(SelectedItem),
     DownloadPlugin { plugin_id: PluginId },
     HandleBackendError(BackendApiError),
@@ -69,6 +83,8 @@ pub struct ManagementAppPluginsState {
     plugin_data: Rc<RefCell<PluginDataContainer>>,
     preference_user_data: HashMap<(PluginId, Option<EntrypointId>, String), PluginPreferenceUserDataState>,
     selected_item: SelectedItem,
+    // audit log is only fetched for currently selected plugin
+    audit_log: Option<(PluginId, Vec<PluginAuditLogEntry>)>,
 }
 
 impl ManagementAppPluginsState {
@@ -103,6 +119,7 @@ impl ManagementAppPluginsState {
             plugin_data: Rc::new(RefCell::new(PluginDataContainer::new())),
             preference_user_data: HashMap::new(),
             selected_item: select_item,
+            audit_log: None,
             table_state: PluginTableState::new(),
         }
     }
@@ -209,6 +226,35 @@ impl ManagementAppPluginsState {
                     }
                 }
             }
+            ManagementAppPluginMsgIn::PluginPermissionsMsg(msg) => {
+                match msg {
+                    PluginPermissionsMsg::UpdatePermissions {
+                        plugin_id,
+                        revoked,
+                        ask,
+                        granted,
+                    } => {
+                        let mut backend_client = backend_api.clone();
+
+                        Task::perform(
+                            async move {
+                                backend_client
+                                    .set_plugin_permissions(plugin_id, revoked, ask, granted)
+                                    .await?;
+
+                                let plugins = backend_client.plugins().await?;
+
+                                Ok(plugins)
+                            },
+                            |result| {
+                                handle_backend_error(result, |plugins| {
+                                    ManagementAppPluginMsgOut::PluginsReloaded(plugins)
+                                })
+                            },
+                        )
+                    }
+                }
+            }
             ManagementAppPluginMsgIn::FetchPlugins => {
                 let mut backend_api = backend_api.clone();
 
@@ -226,6 +272,11 @@ impl ManagementAppPluginsState {
             ManagementAppPluginMsgIn::PluginsFetched(plugins) => {
                 self.apply_plugin_fetch(plugins);
 
+                self.fetch_audit_log(backend_api)
+            }
+            ManagementAppPluginMsgIn::AuditLogFetched(plugin_id, audit_log) => {
+                self.audit_log = Some((plugin_id, audit_log));
+
                 Task::none()
             }
             ManagementAppPluginMsgIn::RemovePlugin { plugin_id } => {
@@ -246,18 +297,56 @@ impl ManagementAppPluginsState {
                     },
                 )
             }
+            ManagementAppPluginMsgIn::ClearPluginStorage { plugin_id } => {
+                let mut backend_client = backend_api.clone();
+
+                Task::perform(
+                    async move {
+                        backend_client.clear_plugin_storage(plugin_id).await?;
+
+                        let plugins = backend_client.plugins().await?;
+
+                        Ok(plugins)
+                    },
+                    |result| {
+                        handle_backend_error(result, |plugins| ManagementAppPluginMsgOut::PluginsReloaded(plugins))
+                    },
+                )
+            }
             ManagementAppPluginMsgIn::DownloadPlugin { plugin_id } => {
                 Task::done(ManagementAppPluginMsgOut::DownloadPlugin { plugin_id })
             }
             ManagementAppPluginMsgIn::SelectItem(selected_item) => {
                 self.selected_item = selected_item;
 
-                Task::none()
+                self.fetch_audit_log(backend_api)
             }
             ManagementAppPluginMsgIn::Noop => Task::none(),
         }
     }
 
+    fn fetch_audit_log(&self, backend_api: BackendApi) -> Task<ManagementAppPluginMsgOut> {
+        let plugin_id = match &self.selected_item {
+            SelectedItem::Plugin { plugin_id } => plugin_id.clone(),
+            _ => return Task::none(),
+        };
+
+        let mut backend_api = backend_api;
+
+        Task::perform(
+            async move {
+                let audit_log = backend_api.plugin_audit_log(plugin_id.clone()).await?;
+
+                Ok((plugin_id, audit_log))
+            },
+            |result| {
+                handle_backend_error(result, |(plugin_id, audit_log)| {
+                    ManagementAppPluginMsgOut::AuditLogReloaded(plugin_id, audit_log)
+                })
+            },
+        )
+    }
+
     fn apply_plugin_fetch(&mut self, plugins: HashMap<PluginId, SettingsPlugin>) {
         self.preference_user_data = plugins
             .iter()
@@ -387,6 +476,22 @@ impl ManagementAppPluginsState {
                                 .map(|msg| ManagementAppPluginMsgIn::PluginPreferenceMsg(msg)),
                         );
 
+                        column_content.push(
+                            permissions_ui(plugin_id.clone(), &plugin.permissions)
+                                .map(|msg| ManagementAppPluginMsgIn::PluginPermissionsMsg(msg)),
+                        );
+
+                        column_content.push(storage_ui(
+                            plugin.storage_usage,
+                            ManagementAppPluginMsgIn::ClearPluginStorage {
+                                plugin_id: plugin.plugin_id.clone(),
+                            },
+                        ));
+
+                        if let Some((_, audit_log)) = self.audit_log.as_ref().filter(|(id, _)| id == plugin_id) {
+                            column_content.push(audit_log_ui(audit_log));
+                        }
+
                         let content: Element<_> = column(column_content).spacing(12).into();
 
                         let content: Element<_> = scrollable(content).height(Length::Fill).width(Length::Fill).into();
@@ -492,6 +597,10 @@ impl ManagementAppPluginsState {
                             .map(|msg| ManagementAppPluginMsgIn::PluginPreferenceMsg(msg)),
                         );
 
+                        if let Some(interval_seconds) = entrypoint.interval_seconds {
+                            column_content.push(schedule_ui(interval_seconds, entrypoint.last_scheduled_run.as_ref()));
+                        }
+
                         let column: Element<_> = column(column_content).spacing(12).into();
 
                         let column: Element<_> = scrollable(column).width(Length::Fill).into();
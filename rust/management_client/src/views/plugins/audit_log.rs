@@ -0,0 +1,82 @@
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use gauntlet_common::model::PluginAuditLogEntry;
+use iced::padding;
+use iced::widget::column;
+use iced::widget::container;
+use iced::widget::row;
+use iced::widget::text;
+use iced::widget::text::Shaping;
+use iced::Length;
+use iced::Padding;
+
+use crate::theme::text::TextStyle;
+use crate::theme::Element;
+
+pub fn audit_log_ui<'a, T: 'a>(audit_log: &[PluginAuditLogEntry]) -> Element<'a, T> {
+    let mut column_content = vec![];
+
+    let label: Element<_> = text("Access Log").size(14).class(TextStyle::Subtitle).into();
+
+    let label = container(label).padding(padding::left(8.0)).into();
+
+    column_content.push(label);
+
+    if audit_log.is_empty() {
+        let empty: Element<_> = text("Plugin hasn't accessed anything sensitive yet")
+            .class(TextStyle::Subtitle)
+            .into();
+
+        let empty = container(empty).padding(Padding::from([4.0, 8.0])).into();
+
+        column_content.push(empty);
+    }
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_millis() as i64)
+        .unwrap_or_default();
+
+    for entry in audit_log {
+        let time: Element<_> = text(format_time_ago(now - entry.timestamp))
+            .class(TextStyle::Subtitle)
+            .width(Length::Fixed(120.0))
+            .into();
+
+        let permission: Element<_> = text(format!("{}: {}", entry.permission.kind, entry.permission.resource))
+            .shaping(Shaping::Advanced)
+            .width(Length::Fill)
+            .into();
+
+        let result: Element<_> = if entry.allowed {
+            text("Allowed").class(TextStyle::Positive).into()
+        } else {
+            text("Denied").class(TextStyle::Destructive).into()
+        };
+
+        let item: Element<_> = row([time, permission, result]).spacing(8.0).into();
+
+        let item = container(item).padding(Padding::from([4.0, 8.0])).into();
+
+        column_content.push(item);
+    }
+
+    let element: Element<_> = column(column_content).into();
+
+    element
+}
+
+pub(super) fn format_time_ago(elapsed_millis: i64) -> String {
+    let seconds = (elapsed_millis / 1000).max(0);
+
+    if seconds < 60 {
+        "Just now".to_string()
+    } else if seconds < 60 * 60 {
+        format!("{} min ago", seconds / 60)
+    } else if seconds < 24 * 60 * 60 {
+        format!("{} h ago", seconds / (60 * 60))
+    } else {
+        format!("{} days ago", seconds / (24 * 60 * 60))
+    }
+}
@@ -0,0 +1,145 @@
+use gauntlet_common::model::PluginId;
+use gauntlet_common::model::PluginPermission;
+use gauntlet_common::model::SettingsPluginPermissions;
+use iced::padding;
+use iced::widget;
+use iced::widget::button;
+use iced::widget::checkbox;
+use iced::widget::column;
+use iced::widget::container;
+use iced::widget::row;
+use iced::widget::text;
+use iced::widget::text::Shaping;
+use iced::Alignment;
+use iced::Length;
+use iced::Padding;
+use iced_fonts::Bootstrap;
+use iced_fonts::BOOTSTRAP_FONT;
+
+use crate::theme::button::ButtonStyle;
+use crate::theme::text::TextStyle;
+use crate::theme::Element;
+
+#[derive(Debug, Clone)]
+pub enum PluginPermissionsMsg {
+    UpdatePermissions {
+        plugin_id: PluginId,
+        revoked: Vec<PluginPermission>,
+        ask: bool,
+        granted: Vec<PluginPermission>,
+    },
+}
+
+pub fn permissions_ui<'a>(
+    plugin_id: PluginId,
+    permissions: &SettingsPluginPermissions,
+) -> Element<'a, PluginPermissionsMsg> {
+    let mut column_content = vec![];
+
+    let label: Element<_> = text("Permissions").size(14).class(TextStyle::Subtitle).into();
+
+    let label = container(label).padding(padding::left(8.0)).into();
+
+    column_content.push(label);
+
+    for permission in &permissions.declared {
+        let enabled = !permissions.revoked.contains(permission);
+
+        let plugin_id = plugin_id.clone();
+        let permission = permission.clone();
+        let revoked = permissions.revoked.clone();
+        let ask = permissions.ask;
+        let granted = permissions.granted.clone();
+
+        let label = format!("{}: {}", permission.kind, permission.resource);
+
+        let input_field: Element<_> = checkbox(label, enabled)
+            .text_shaping(Shaping::Advanced)
+            .on_toggle(move |value| {
+                let mut revoked = revoked.clone();
+
+                if value {
+                    revoked.retain(|item| item != &permission);
+                } else {
+                    revoked.push(permission.clone());
+                }
+
+                PluginPermissionsMsg::UpdatePermissions {
+                    plugin_id: plugin_id.clone(),
+                    revoked,
+                    ask,
+                    granted: granted.clone(),
+                }
+            })
+            .into();
+
+        let input_field = container(input_field).padding(Padding::from([4.0, 8.0])).into();
+
+        column_content.push(input_field);
+    }
+
+    let ask_field: Element<_> = {
+        let plugin_id = plugin_id.clone();
+        let revoked = permissions.revoked.clone();
+        let granted = permissions.granted.clone();
+
+        checkbox(
+            "Ask when plugin requests access to something not listed above",
+            permissions.ask,
+        )
+        .on_toggle(move |value| {
+            PluginPermissionsMsg::UpdatePermissions {
+                plugin_id: plugin_id.clone(),
+                revoked: revoked.clone(),
+                ask: value,
+                granted: granted.clone(),
+            }
+        })
+        .into()
+    };
+
+    let ask_field = container(ask_field).padding(Padding::new(8.0)).into();
+
+    column_content.push(ask_field);
+
+    if !permissions.granted.is_empty() {
+        let granted_label: Element<_> = text("Always allowed").size(14).class(TextStyle::Subtitle).into();
+
+        let granted_label = container(granted_label).padding(padding::left(8.0)).into();
+
+        column_content.push(granted_label);
+
+        for (index, permission) in permissions.granted.iter().enumerate() {
+            let mut granted = permissions.granted.clone();
+            granted.remove(index);
+
+            let item_text: Element<_> = text(format!("{}: {}", permission.kind, permission.resource))
+                .shaping(Shaping::Advanced)
+                .width(Length::Fill)
+                .into();
+
+            let remove_icon = widget::value(Bootstrap::Dash).font(BOOTSTRAP_FONT);
+
+            let remove_button: Element<_> = button(remove_icon)
+                .class(ButtonStyle::Primary)
+                .on_press(PluginPermissionsMsg::UpdatePermissions {
+                    plugin_id: plugin_id.clone(),
+                    revoked: permissions.revoked.clone(),
+                    ask: permissions.ask,
+                    granted,
+                })
+                .padding(Padding::from([5.0, 7.0]))
+                .into();
+
+            let item: Element<_> = row([item_text, remove_button]).align_y(Alignment::Center).into();
+
+            let item = container(item).padding(Padding::from([4.0, 8.0])).into();
+
+            column_content.push(item);
+        }
+    }
+
+    let element: Element<_> = column(column_content).into();
+
+    element
+}
@@ -0,0 +1,80 @@
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use gauntlet_common::model::ScheduledRun;
+use iced::padding;
+use iced::widget::column;
+use iced::widget::container;
+use iced::widget::text;
+use iced::widget::text::Shaping;
+use iced::Padding;
+
+use crate::theme::text::TextStyle;
+use crate::theme::Element;
+use crate::views::plugins::audit_log::format_time_ago;
+
+pub fn schedule_ui<'a, T: 'a>(interval_seconds: u64, last_run: Option<&ScheduledRun>) -> Element<'a, T> {
+    let mut column_content = vec![];
+
+    let label: Element<_> = text("Schedule").size(14).class(TextStyle::Subtitle).into();
+
+    let label = container(label).padding(padding::left(8.0)).into();
+
+    column_content.push(label);
+
+    let interval: Element<_> = text(format!(
+        "Runs in background every {}",
+        format_interval(interval_seconds)
+    ))
+    .into();
+
+    let interval = container(interval).padding(Padding::from([4.0, 8.0])).into();
+
+    column_content.push(interval);
+
+    let last_run: Element<_> = match last_run {
+        None => text("Hasn't run yet").class(TextStyle::Subtitle).into(),
+        Some(last_run) => {
+            let now = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|duration| duration.as_millis() as i64)
+                .unwrap_or_default();
+
+            let time_ago = format_time_ago(now - last_run.timestamp);
+
+            match &last_run.error {
+                None => {
+                    text(format!("Last run succeeded: {}", time_ago))
+                        .class(TextStyle::Positive)
+                        .into()
+                }
+                Some(error) => {
+                    text(format!("Last run failed: {}\n{}", time_ago, error))
+                        .shaping(Shaping::Advanced)
+                        .class(TextStyle::Destructive)
+                        .into()
+                }
+            }
+        }
+    };
+
+    let last_run = container(last_run).padding(Padding::from([4.0, 8.0])).into();
+
+    column_content.push(last_run);
+
+    let element: Element<_> = column(column_content).into();
+
+    element
+}
+
+fn format_interval(interval_seconds: u64) -> String {
+    if interval_seconds % (24 * 60 * 60) == 0 {
+        format!("{} days", interval_seconds / (24 * 60 * 60))
+    } else if interval_seconds % (60 * 60) == 0 {
+        format!("{} h", interval_seconds / (60 * 60))
+    } else if interval_seconds % 60 == 0 {
+        format!("{} min", interval_seconds / 60)
+    } else {
+        format!("{} s", interval_seconds)
+    }
+}
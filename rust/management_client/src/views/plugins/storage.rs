@@ -0,0 +1,61 @@
+use iced::padding;
+use iced::widget::button;
+use iced::widget::column;
+use iced::widget::container;
+use iced::widget::horizontal_space;
+use iced::widget::row;
+use iced::widget::text;
+use iced::Alignment;
+use iced::Padding;
+
+use crate::theme::button::ButtonStyle;
+use crate::theme::text::TextStyle;
+use crate::theme::Element;
+
+pub fn storage_ui<'a, T: Clone + 'a>(storage_usage: u64, on_clear: T) -> Element<'a, T> {
+    let mut column_content = vec![];
+
+    let label: Element<_> = text("Storage").size(14).class(TextStyle::Subtitle).into();
+
+    let label = container(label).padding(padding::left(8.0)).into();
+
+    column_content.push(label);
+
+    let usage: Element<_> = if storage_usage == 0 {
+        text("Plugin hasn't stored any data yet")
+            .class(TextStyle::Subtitle)
+            .into()
+    } else {
+        text(format!("Uses {}", format_size(storage_usage))).into()
+    };
+
+    let mut clear_button = button(text("Clear data")).class(ButtonStyle::Destructive);
+
+    if storage_usage != 0 {
+        clear_button = clear_button.on_press(on_clear);
+    }
+
+    let clear_button: Element<_> = clear_button.into();
+
+    let content: Element<_> = row([usage, horizontal_space().into(), clear_button])
+        .align_y(Alignment::Center)
+        .into();
+
+    let content = container(content).padding(Padding::from([4.0, 8.0])).into();
+
+    column_content.push(content);
+
+    let element: Element<_> = column(column_content).into();
+
+    element
+}
+
+fn format_size(bytes: u64) -> String {
+    if bytes >= 1024 * 1024 {
+        format!("{:.1} MB", bytes as f64 / (1024.0 * 1024.0))
+    } else if bytes >= 1024 {
+        format!("{:.1} KB", bytes as f64 / 1024.0)
+    } else {
+        format!("{} B", bytes)
+    }
+}
@@ -2,6 +2,7 @@ use std::collections::HashMap;
 
 use anyhow::anyhow;
 use gauntlet_common::model::EntrypointId;
+use gauntlet_common::model::PluginId;
 use gauntlet_common::model::RootWidget;
 use gauntlet_common::model::UiRenderLocation;
 use gauntlet_utils::channel::RequestError;
@@ -9,7 +10,11 @@ use gauntlet_utils::channel::RequestSender;
 
 use crate::model::JsClipboardData;
 use crate::model::JsGeneratedSearchItem;
+use crate::model::JsPermissionPromptDecision;
+use crate::model::JsPermissionPromptKind;
 use crate::model::JsPreferenceUserData;
+use crate::model::JsStorageEntry;
+use crate::model::JsStorageOperation;
 use crate::JsRequest;
 use crate::JsResponse;
 use crate::JsUiRenderLocation;
@@ -21,6 +26,11 @@ pub trait BackendForPluginRuntimeApi {
         generated_entrypoints: Vec<JsGeneratedSearchItem>,
         refresh_search_list: bool,
     ) -> anyhow::Result<()>;
+    async fn update_search_index(
+        &self,
+        upserted_entrypoints: Vec<JsGeneratedSearchItem>,
+        removed_entrypoint_ids: Vec<String>,
+    ) -> anyhow::Result<()>;
     async fn get_asset_data(&self, path: &str) -> anyhow::Result<Vec<u8>>;
     async fn get_entrypoint_generator_entrypoint_ids(&self) -> anyhow::Result<Vec<String>>;
     async fn get_plugin_preferences(&self) -> anyhow::Result<HashMap<String, JsPreferenceUserData>>;
@@ -68,6 +78,30 @@ pub trait BackendForPluginRuntimeApi {
     ) -> anyhow::Result<()>;
     async fn ui_clear_inline_view(&self) -> anyhow::Result<()>;
     async fn ui_synchronize_event(&self) -> anyhow::Result<()>;
+    async fn permission_prompt(
+        &self,
+        kind: JsPermissionPromptKind,
+        resource: String,
+    ) -> anyhow::Result<JsPermissionPromptDecision>;
+    async fn audit_log(&self, kind: JsPermissionPromptKind, resource: String, allowed: bool) -> anyhow::Result<()>;
+    async fn scheduled_run_finished(&self, entrypoint_id: EntrypointId, error: Option<String>) -> anyhow::Result<()>;
+    async fn inspector_started(&self, address: String) -> anyhow::Result<()>;
+    async fn call_plugin_function(
+        &self,
+        plugin_id: PluginId,
+        function_id: String,
+        args: String,
+    ) -> anyhow::Result<String>;
+    async fn plugin_function_call_finished(
+        &self,
+        call_id: String,
+        result: Option<String>,
+        error: Option<String>,
+    ) -> anyhow::Result<()>;
+    async fn storage_get(&self, namespace: String, key: String) -> anyhow::Result<Option<String>>;
+    async fn storage_list(&self, namespace: String, prefix: String) -> anyhow::Result<Vec<JsStorageEntry>>;
+    async fn storage_clear(&self, namespace: String) -> anyhow::Result<()>;
+    async fn storage_apply(&self, namespace: String, operations: Vec<JsStorageOperation>) -> anyhow::Result<()>;
 }
 
 #[derive(Clone)]
@@ -93,6 +127,16 @@ impl BackendForPluginRuntimeApiProxy {
             }
         }
     }
+
+    // for requests which wait for user input
+    async fn request_without_timeout(&self, request: JsRequest) -> anyhow::Result<JsResponse> {
+        let mut receiver = self
+            .request_sender
+            .send(request)
+            .map_err(|_| anyhow!("Plugin runtime is being stopped"))?;
+
+        Ok(receiver.recv().await.map_err(|e| anyhow!(e))?)
+    }
 }
 
 impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiProxy {
@@ -112,6 +156,22 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiProxy {
         }
     }
 
+    async fn update_search_index(
+        &self,
+        upserted_entrypoints: Vec<JsGeneratedSearchItem>,
+        removed_entrypoint_ids: Vec<String>,
+    ) -> anyhow::Result<()> {
+        let request = JsRequest::UpdateSearchIndex {
+            upserted_entrypoints,
+            removed_entrypoint_ids,
+        };
+
+        match self.request(request).await? {
+            JsResponse::Nothing => Ok(()),
+            value @ _ => panic!("Unexpected JsResponse type: {:?}", value),
+        }
+    }
+
     async fn get_asset_data(&self, path: &str) -> anyhow::Result<Vec<u8>> {
         let request = JsRequest::GetAssetData { path: path.to_string() };
 
@@ -344,4 +404,117 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiProxy {
             value @ _ => panic!("Unexpected JsResponse type: {:?}", value),
         }
     }
+
+    async fn permission_prompt(
+        &self,
+        kind: JsPermissionPromptKind,
+        resource: String,
+    ) -> anyhow::Result<JsPermissionPromptDecision> {
+        let request = JsRequest::PermissionPrompt { kind, resource };
+
+        match self.request_without_timeout(request).await? {
+            JsResponse::PermissionPromptDecision { data } => Ok(data),
+            value @ _ => panic!("Unexpected JsResponse type: {:?}", value),
+        }
+    }
+
+    async fn audit_log(&self, kind: JsPermissionPromptKind, resource: String, allowed: bool) -> anyhow::Result<()> {
+        let request = JsRequest::AuditLog {
+            kind,
+            resource,
+            allowed,
+        };
+
+        match self.request(request).await? {
+            JsResponse::Nothing => Ok(()),
+            value @ _ => panic!("Unexpected JsResponse type: {:?}", value),
+        }
+    }
+
+    async fn scheduled_run_finished(&self, entrypoint_id: EntrypointId, error: Option<String>) -> anyhow::Result<()> {
+        let request = JsRequest::ScheduledRunFinished { entrypoint_id, error };
+
+        match self.request(request).await? {
+            JsResponse::Nothing => Ok(()),
+            value @ _ => panic!("Unexpected JsResponse type: {:?}", value),
+        }
+    }
+
+    async fn inspector_started(&self, address: String) -> anyhow::Result<()> {
+        let request = JsRequest::InspectorStarted { address };
+
+        match self.request(request).await? {
+            JsResponse::Nothing => Ok(()),
+            value @ _ => panic!("Unexpected JsResponse type: {:?}", value),
+        }
+    }
+
+    async fn call_plugin_function(
+        &self,
+        plugin_id: PluginId,
+        function_id: String,
+        args: String,
+    ) -> anyhow::Result<String> {
+        let request = JsRequest::CallPluginFunction {
+            plugin_id,
+            function_id,
+            args,
+        };
+
+        // backend has its own timeout for plugin function calls
+        match self.request_without_timeout(request).await? {
+            JsResponse::PluginFunctionResult { data } => Ok(data),
+            value @ _ => panic!("Unexpected JsResponse type: {:?}", value),
+        }
+    }
+
+    async fn plugin_function_call_finished(
+        &self,
+        call_id: String,
+        result: Option<String>,
+        error: Option<String>,
+    ) -> anyhow::Result<()> {
+        let request = JsRequest::PluginFunctionCallFinished { call_id, result, error };
+
+        match self.request(request).await? {
+            JsResponse::Nothing => Ok(()),
+            value @ _ => panic!("Unexpected JsResponse type: {:?}", value),
+        }
+    }
+
+    async fn storage_get(&self, namespace: String, key: String) -> anyhow::Result<Option<String>> {
+        let request = JsRequest::StorageGet { namespace, key };
+
+        match self.request(request).await? {
+            JsResponse::StorageValue { data } => Ok(data),
+            value @ _ => panic!("Unexpected JsResponse type: {:?}", value),
+        }
+    }
+
+    async fn storage_list(&self, namespace: String, prefix: String) -> anyhow::Result<Vec<JsStorageEntry>> {
+        let request = JsRequest::StorageList { namespace, prefix };
+
+        match self.request(request).await? {
+            JsResponse::StorageEntries { data } => Ok(data),
+            value @ _ => panic!("Unexpected JsResponse type: {:?}", value),
+        }
+    }
+
+    async fn storage_clear(&self, namespace: String) -> anyhow::Result<()> {
+        let request = JsRequest::StorageClear { namespace };
+
+        match self.request(request).await? {
+            JsResponse::Nothing => Ok(()),
+            value @ _ => panic!("Unexpected JsResponse type: {:?}", value),
+        }
+    }
+
+    async fn storage_apply(&self, namespace: String, operations: Vec<JsStorageOperation>) -> anyhow::Result<()> {
+        let request = JsRequest::StorageApply { namespace, operations };
+
+        match self.request(request).await? {
+            JsResponse::Nothing => Ok(()),
+            value @ _ => panic!("Unexpected JsResponse type: {:?}", value),
+        }
+    }
 }
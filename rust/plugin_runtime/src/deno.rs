@@ -1,14 +1,20 @@
 use std::collections::HashMap;
 use std::fs::File;
+use std::io::Read;
+use std::io::Write;
+use std::net::SocketAddr;
+use std::net::TcpStream;
 use std::path::Path;
 use std::path::PathBuf;
 use std::pin::Pin;
 use std::rc::Rc;
 use std::sync::Arc;
+use std::time::Duration;
 
 use anyhow::anyhow;
 use anyhow::Context;
 use deno_core::futures::Stream;
+use deno_core::serde_json;
 use deno_core::url::Url;
 use deno_core::FastString;
 use deno_core::ModuleLoadResponse;
@@ -24,6 +30,8 @@ use deno_runtime::deno_fs::FileSystem;
 use deno_runtime::deno_fs::RealFs;
 use deno_runtime::deno_io::Stdio;
 use deno_runtime::deno_io::StdioPipe;
+use deno_runtime::deno_permissions::prompter::set_prompter;
+use deno_runtime::inspector_server::InspectorServer;
 use deno_runtime::worker::MainWorker;
 use deno_runtime::worker::WorkerOptions;
 use deno_runtime::worker::WorkerServiceOptions;
@@ -33,7 +41,9 @@ use once_cell::sync::Lazy;
 use regex::Regex;
 use tokio::runtime::Handle;
 use tokio::sync::mpsc::Receiver;
+use tokio::sync::mpsc::Sender;
 
+use crate::api::BackendForPluginRuntimeApi;
 use crate::api::BackendForPluginRuntimeApiProxy;
 use crate::assets::asset_data;
 use crate::assets::asset_data_blocking;
@@ -44,22 +54,31 @@ use crate::clipboard::clipboard_write;
 use crate::clipboard::clipboard_write_text;
 use crate::component_model::ComponentModel;
 use crate::entrypoint_generators::get_entrypoint_generator_entrypoint_ids;
+use crate::entrypoint_generators::scheduled_run_finished;
 use crate::environment::environment_gauntlet_version;
 use crate::environment::environment_is_development;
 use crate::environment::environment_plugin_cache_dir;
 use crate::environment::environment_plugin_data_dir;
+use crate::environment::environment_plugin_id;
 use crate::events::op_plugin_get_pending_event;
 use crate::events::synchronize_event;
 use crate::events::EventReceiver;
 use crate::events::JsEvent;
+use crate::fs_watch::fs_unwatch;
+use crate::fs_watch::fs_watch;
+use crate::fs_watch::FileSystemWatchers;
 use crate::logs::op_log_debug;
 use crate::logs::op_log_error;
 use crate::logs::op_log_info;
 use crate::logs::op_log_trace;
 use crate::logs::op_log_warn;
 use crate::model::JsInit;
+use crate::permissions::exec_permission_paths;
 use crate::permissions::permissions_to_deno;
+use crate::permissions::PluginPermissionPrompter;
 use crate::plugin_data::PluginData;
+use crate::plugin_functions::call_plugin_function;
+use crate::plugin_functions::plugin_function_call_finished;
 use crate::plugins::applications::current_os;
 use crate::plugins::applications::wayland;
 use crate::plugins::applications::ApplicationContext;
@@ -70,7 +89,16 @@ use crate::preferences::entrypoint_preferences_required;
 use crate::preferences::get_entrypoint_preferences;
 use crate::preferences::get_plugin_preferences;
 use crate::preferences::plugin_preferences_required;
+use crate::process::process_kill;
+use crate::process::process_next_event;
+use crate::process::process_spawn;
+use crate::process::RunningProcesses;
 use crate::search::reload_search_index;
+use crate::search::update_search_index;
+use crate::storage::storage_apply;
+use crate::storage::storage_clear;
+use crate::storage::storage_get;
+use crate::storage::storage_list;
 use crate::ui::clear_inline_view;
 use crate::ui::fetch_action_id_for_shortcut;
 use crate::ui::hide_window;
@@ -280,6 +308,18 @@ impl ModuleLoader for CustomModuleLoader {
             }
         }
     }
+
+    // used by deno when formatting js errors, so stack traces point to original plugin sources
+    fn get_source_map(&self, file_name: &str) -> Option<Vec<u8>> {
+        let module_id = file_name
+            .strip_prefix("gauntlet:entrypoint?")
+            .or_else(|| file_name.strip_prefix("gauntlet:module?"))?;
+
+        self.code
+            .source_maps
+            .get(module_id)
+            .map(|source_map| source_map.as_bytes().to_vec())
+    }
 }
 
 deno_core::extension!(
@@ -298,6 +338,7 @@ deno_core::extension!(
 
         // entrypoint generators
         get_entrypoint_generator_entrypoint_ids,
+        scheduled_run_finished,
 
         // assets
         asset_data,
@@ -324,6 +365,7 @@ deno_core::extension!(
 
         // search
         reload_search_index,
+        update_search_index,
 
         // clipboard
         clipboard_read_text,
@@ -335,13 +377,34 @@ deno_core::extension!(
         // plugin environment
         environment_gauntlet_version,
         environment_is_development,
+        environment_plugin_id,
         environment_plugin_data_dir,
         environment_plugin_cache_dir,
+
+        // plugin functions
+        call_plugin_function,
+        plugin_function_call_finished,
+
+        // processes
+        process_spawn,
+        process_next_event,
+        process_kill,
+
+        // storage
+        storage_get,
+        storage_list,
+        storage_clear,
+        storage_apply,
+
+        // file system
+        fs_watch,
+        fs_unwatch,
     ],
     options = {
         event_receiver: EventReceiver,
         plugin_data: PluginData,
         component_model: ComponentModel,
+        file_system_watchers: FileSystemWatchers,
         backend_api: BackendForPluginRuntimeApiProxy,
         outer_handle: Handle
     },
@@ -349,8 +412,10 @@ deno_core::extension!(
         state.put(options.event_receiver);
         state.put(options.plugin_data);
         state.put(options.component_model);
+        state.put(options.file_system_watchers);
         state.put(options.backend_api);
         state.put(options.outer_handle);
+        state.put(RunningProcesses::new());
     },
 );
 
@@ -445,10 +510,14 @@ pub async fn start_js_runtime(
     outer_handle: Handle,
     init: JsInit,
     event_stream: Receiver<JsEvent>,
+    event_sender: Sender<JsEvent>,
     api: BackendForPluginRuntimeApiProxy,
 ) -> anyhow::Result<()> {
     let bundled = init.plugin_id.to_string().starts_with("bundled://");
 
+    let inspector = init.inspector;
+    let stderr_file_path = init.stderr_file.clone();
+
     let stdout = if let Some(stdout_file) = init.stdout_file {
         let stdout_file = PathBuf::from(stdout_file);
 
@@ -509,6 +578,21 @@ pub async fn start_js_runtime(
         Path::new(&init.plugin_cache_dir),
     )?;
 
+    let exec_permission_paths = exec_permission_paths(
+        &init.permissions.exec,
+        &home_dir,
+        Path::new(&init.plugin_data_dir),
+        Path::new(&init.plugin_cache_dir),
+    )?;
+
+    // plugin runtime runs in separate process, so global prompter only affects current plugin
+    set_prompter(Box::new(PluginPermissionPrompter::new(
+        outer_handle.clone(),
+        api.clone(),
+        &init.permissions,
+        exec_permission_paths,
+    )?));
+
     let gauntlet_esm = if cfg!(feature = "release") && !init.dev_plugin {
         prod::gauntlet_esm::init_ops_and_esm()
     } else {
@@ -528,7 +612,8 @@ pub async fn start_js_runtime(
                 home_dir,
             ),
             ComponentModel::new(),
-            api,
+            FileSystemWatchers::new(event_sender),
+            api.clone(),
             outer_handle,
         ),
         gauntlet_esm,
@@ -550,6 +635,28 @@ pub async fn start_js_runtime(
         extensions.push(crate::plugins::applications::gauntlet_internal_windows::init_ops_and_esm());
     }
 
+    let inspector_server = match &inspector {
+        Some(inspector) => {
+            match start_inspector_server(inspector.port) {
+                Ok((server, address)) => {
+                    api.inspector_started(address.to_string()).await?;
+
+                    Some((Arc::new(server), address))
+                }
+                Err(err) => {
+                    tracing::error!("Unable to start inspector server: {:?}", err);
+                    None
+                }
+            }
+        }
+        None => None,
+    };
+
+    let break_on_start = match (&inspector, &inspector_server) {
+        (Some(inspector), Some(_)) => inspector.break_on_start,
+        _ => false,
+    };
+
     let mut worker = MainWorker::bootstrap_from_options(
         init_url.clone(),
         WorkerServiceOptions {
@@ -574,17 +681,107 @@ pub async fn start_js_runtime(
                 ..Default::default()
             },
             extensions,
-            maybe_inspector_server: None,
-            should_wait_for_inspector_session: false,
-            should_break_on_first_statement: false,
+            maybe_inspector_server: inspector_server.as_ref().map(|(server, _)| server.clone()),
+            should_wait_for_inspector_session: break_on_start,
+            should_break_on_first_statement: break_on_start,
             origin_storage_dir: Some(PathBuf::from(init.local_storage_dir)),
             stdio: Stdio { stdin, stdout, stderr },
             ..Default::default()
         },
     );
 
+    if let Some((_, address)) = &inspector_server {
+        let address = *address;
+
+        match tokio::task::spawn_blocking(move || inspector_devtools_url(address)).await? {
+            Ok(url) => {
+                tracing::info!("Plugin inspector is available at: {}", url);
+
+                if let Some(stderr_file_path) = stderr_file_path {
+                    let mut stderr_file = File::options().append(true).open(stderr_file_path)?;
+
+                    writeln!(stderr_file, "Debugger is available at: {}", url)?;
+
+                    if break_on_start {
+                        writeln!(stderr_file, "Plugin is waiting for debugger to connect")?;
+                    }
+                }
+            }
+            Err(err) => tracing::error!("Unable to get devtools url from inspector server: {:?}", err),
+        }
+    }
+
     worker.execute_main_module(&init_url).await?;
     worker.run_event_loop(false).await?;
 
     Ok(())
 }
+
+// inspector server can't be given already bound listener and doesn't expose the address it actually bound to,
+// so free port is picked here and another one is tried if something else takes it before inspector server binds it
+fn start_inspector_server(port: Option<u16>) -> anyhow::Result<(InspectorServer, SocketAddr)> {
+    if let Some(port) = port {
+        let address = SocketAddr::from(([127, 0, 0, 1], port));
+
+        let server = InspectorServer::new(address, "gauntlet")
+            .with_context(|| format!("Unable to start inspector server on {}", address))?;
+
+        return Ok((server, address));
+    }
+
+    let mut last_error = anyhow!("Unable to find free port for inspector server");
+
+    for _ in 0..10 {
+        let address = std::net::TcpListener::bind(("127.0.0.1", 0))?.local_addr()?;
+
+        match InspectorServer::new(address, "gauntlet") {
+            Ok(server) => return Ok((server, address)),
+            Err(err) => last_error = anyhow!(err).context(format!("Unable to start inspector server on {}", address)),
+        }
+    }
+
+    Err(last_error)
+}
+
+// inspector server doesn't expose session id it generates, so ask for it the same way chrome://inspect does
+fn inspector_devtools_url(address: SocketAddr) -> anyhow::Result<String> {
+    let mut last_error = anyhow!("Plugin runtime was not registered in inspector server");
+
+    // runtime is registered in inspector server asynchronously
+    for _ in 0..10 {
+        match request_devtools_url(address) {
+            Ok(Some(url)) => return Ok(url),
+            Ok(None) => {}
+            Err(err) => last_error = err,
+        }
+
+        std::thread::sleep(Duration::from_millis(100));
+    }
+
+    Err(last_error)
+}
+
+fn request_devtools_url(address: SocketAddr) -> anyhow::Result<Option<String>> {
+    let mut stream = TcpStream::connect(address)?;
+
+    let request = format!("GET /json/list HTTP/1.1\r\nHost: {address}\r\nConnection: close\r\n\r\n");
+
+    stream.write_all(request.as_bytes())?;
+
+    let mut response = String::new();
+    stream.read_to_string(&mut response)?;
+
+    let (_, body) = response
+        .split_once("\r\n\r\n")
+        .ok_or(anyhow!("Invalid response from inspector server"))?;
+
+    let targets: Vec<serde_json::Value> = serde_json::from_str(body)?;
+
+    let url = targets
+        .first()
+        .and_then(|target| target.get("devtoolsFrontendUrl"))
+        .and_then(|url| url.as_str())
+        .map(|url| url.to_string());
+
+    Ok(url)
+}
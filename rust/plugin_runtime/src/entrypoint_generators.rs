@@ -3,6 +3,7 @@ use std::rc::Rc;
 
 use deno_core::op2;
 use deno_core::OpState;
+use gauntlet_common::model::EntrypointId;
 
 use crate::api::BackendForPluginRuntimeApi;
 use crate::api::BackendForPluginRuntimeApiProxy;
@@ -20,3 +21,21 @@ pub async fn get_entrypoint_generator_entrypoint_ids(state: Rc<RefCell<OpState>>
 
     api.get_entrypoint_generator_entrypoint_ids().await
 }
+
+#[op2(async)]
+pub async fn scheduled_run_finished(
+    state: Rc<RefCell<OpState>>,
+    #[string] entrypoint_id: String,
+    #[serde] error: Option<String>,
+) -> anyhow::Result<()> {
+    let api = {
+        let state = state.borrow();
+
+        let api = state.borrow::<BackendForPluginRuntimeApiProxy>().clone();
+
+        api
+    };
+
+    api.scheduled_run_finished(EntrypointId::from_string(entrypoint_id), error)
+        .await
+}
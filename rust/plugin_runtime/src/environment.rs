@@ -17,6 +17,12 @@ pub fn environment_is_development(state: &mut OpState) -> bool {
     plugin_id.to_string().starts_with("file://")
 }
 
+#[op2]
+#[string]
+pub fn environment_plugin_id(state: &mut OpState) -> String {
+    state.borrow::<PluginData>().plugin_id().to_string()
+}
+
 #[op2]
 #[string]
 pub fn environment_plugin_data_dir(state: &mut OpState) -> String {
@@ -1,4 +1,5 @@
 use std::cell::RefCell;
+use std::collections::HashMap;
 use std::pin::Pin;
 use std::rc::Rc;
 
@@ -15,6 +16,8 @@ use serde::Serialize;
 use tokio::sync::mpsc::Receiver;
 
 use crate::api::BackendForPluginRuntimeApiProxy;
+use crate::process::view_closed;
+use crate::process::view_opened;
 use crate::BackendForPluginRuntimeApi;
 
 #[derive(Debug, Deserialize, Serialize, Encode, Decode)]
@@ -23,11 +26,15 @@ pub enum JsEvent {
     OpenView {
         #[serde(rename = "entrypointId")]
         entrypoint_id: String,
+        arguments: HashMap<String, String>,
+        input: Option<JsEntrypointInput>,
     },
     CloseView,
     RunCommand {
         #[serde(rename = "entrypointId")]
         entrypoint_id: String,
+        arguments: HashMap<String, String>,
+        input: Option<JsEntrypointInput>,
     },
     RunGeneratedEntrypoint {
         #[serde(rename = "entrypointId")]
@@ -62,6 +69,30 @@ pub enum JsEvent {
         text: String,
     },
     RefreshSearchIndex,
+    PreferencesChanged {
+        #[serde(rename = "entrypointId")]
+        entrypoint_id: Option<String>,
+    },
+    RunScheduledEntrypoint {
+        #[serde(rename = "entrypointId")]
+        entrypoint_id: String,
+    },
+    CallPluginFunction {
+        #[serde(rename = "callId")]
+        call_id: String,
+        #[serde(rename = "callerPluginId")]
+        caller_plugin_id: String,
+        #[serde(rename = "entrypointId")]
+        entrypoint_id: String,
+        #[serde(rename = "functionId")]
+        function_id: String,
+        args: String,
+    },
+    FileSystemWatch {
+        #[serde(rename = "watchId")]
+        watch_id: u32,
+        changes: Vec<JsFileSystemChange>,
+    },
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, Encode, Decode)]
@@ -77,9 +108,30 @@ pub enum JsUiPropertyValue {
     String { value: String },
     Number { value: f64 },
     Bool { value: bool },
+    Array { values: Vec<JsUiPropertyValue> },
+    Object { values: HashMap<String, JsUiPropertyValue> },
     Undefined,
 }
 
+#[derive(Debug, Deserialize, Serialize, Encode, Decode)]
+pub struct JsEntrypointInput {
+    pub text: Option<String>,
+    pub png_data: Option<Vec<u8>>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Encode, Decode)]
+pub struct JsFileSystemChange {
+    pub path: String,
+    pub kind: JsFileSystemChangeKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize, Encode, Decode)]
+pub enum JsFileSystemChangeKind {
+    Create,
+    Modify,
+    Delete,
+}
+
 pub struct EventReceiver {
     event_stream: Rc<RefCell<Receiver<JsEvent>>>,
 }
@@ -105,6 +157,16 @@ pub async fn op_plugin_get_pending_event(state: Rc<RefCell<OpState>>) -> anyhow:
 
     tracing::trace!("Received plugin event {:?}", event);
 
+    match &event {
+        JsEvent::OpenView { entrypoint_id, .. } => view_opened(&state, entrypoint_id),
+        JsEvent::CloseView => {
+            if let Err(err) = view_closed(&state).await {
+                tracing::error!("Unable to stop processes of closed view: {:?}", err);
+            }
+        }
+        _ => {}
+    }
+
     Ok(event)
 }
 
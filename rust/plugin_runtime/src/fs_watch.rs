@@ -0,0 +1,202 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use anyhow::anyhow;
+use deno_core::op2;
+use deno_core::OpState;
+use deno_runtime::deno_permissions::PermissionsContainer;
+use indexmap::IndexMap;
+use notify::event::ModifyKind;
+use notify::event::RenameMode;
+use notify::EventKind;
+use notify::RecommendedWatcher;
+use notify::RecursiveMode;
+use notify::Watcher;
+use tokio::sync::mpsc::unbounded_channel;
+use tokio::sync::mpsc::Sender;
+use tokio::sync::mpsc::UnboundedReceiver;
+use tokio::time::Instant;
+
+use crate::events::JsEvent;
+use crate::events::JsFileSystemChange;
+use crate::events::JsFileSystemChangeKind;
+
+const DEBOUNCE_DURATION: Duration = Duration::from_millis(200);
+// continuously changing files would otherwise delay the event indefinitely
+const DEBOUNCE_MAX_DURATION: Duration = Duration::from_secs(1);
+
+pub struct FileSystemWatchers {
+    event_sender: Sender<JsEvent>,
+    next_id: u32,
+    // dropping watcher stops it
+    watchers: HashMap<u32, RecommendedWatcher>,
+}
+
+impl FileSystemWatchers {
+    pub fn new(event_sender: Sender<JsEvent>) -> Self {
+        Self {
+            event_sender,
+            next_id: 0,
+            watchers: HashMap::new(),
+        }
+    }
+}
+
+#[op2]
+pub fn fs_watch(state: &mut OpState, #[string] path: String, recursive: bool) -> anyhow::Result<u32> {
+    if !PathBuf::from(&path).is_absolute() {
+        return Err(anyhow!("Watched path should be absolute: {:?}", path));
+    }
+
+    // same check that deno does for Deno.readFile, including permission prompt
+    let path = state
+        .borrow_mut::<PermissionsContainer>()
+        .check_read(&path, "Gauntlet.watchPath()")?;
+
+    let path = path
+        .canonicalize()
+        .map_err(|err| anyhow!("Unable to watch path {:?}: {}", path, err))?;
+
+    let watchers = state.borrow_mut::<FileSystemWatchers>();
+
+    let id = watchers.next_id;
+    watchers.next_id += 1;
+
+    let (sender, receiver) = unbounded_channel();
+
+    // callback is called on watcher's own thread
+    let mut watcher = notify::recommended_watcher(move |result: notify::Result<notify::Event>| {
+        match result {
+            Ok(event) => {
+                let _ = sender.send(event);
+            }
+            Err(err) => {
+                tracing::warn!("Error occurred when watching path: {:?}", err);
+            }
+        }
+    })?;
+
+    let recursive_mode = if recursive {
+        RecursiveMode::Recursive
+    } else {
+        RecursiveMode::NonRecursive
+    };
+
+    watcher.watch(&path, recursive_mode)?;
+
+    watchers.watchers.insert(id, watcher);
+
+    tokio::spawn(debounce_changes(id, receiver, watchers.event_sender.clone()));
+
+    Ok(id)
+}
+
+#[op2(fast)]
+pub fn fs_unwatch(state: &mut OpState, id: u32) {
+    let watchers = state.borrow_mut::<FileSystemWatchers>();
+
+    watchers.watchers.remove(&id);
+}
+
+// collects changes until there are no new ones for a while or max duration has passed, and sends them as a single event
+async fn debounce_changes(
+    watch_id: u32,
+    mut receiver: UnboundedReceiver<notify::Event>,
+    event_sender: Sender<JsEvent>,
+) {
+    // receiver is closed when watcher is dropped
+    while let Some(event) = receiver.recv().await {
+        let mut changes = IndexMap::new();
+
+        add_changes(&mut changes, event);
+
+        let deadline = Instant::now() + DEBOUNCE_MAX_DURATION;
+
+        loop {
+            let now = Instant::now();
+
+            if now >= deadline {
+                break;
+            }
+
+            let timeout = DEBOUNCE_DURATION.min(deadline - now);
+
+            match tokio::time::timeout(timeout, receiver.recv()).await {
+                Ok(Some(event)) => add_changes(&mut changes, event),
+                Ok(None) | Err(_) => break,
+            }
+        }
+
+        if changes.is_empty() {
+            continue;
+        }
+
+        let changes = changes
+            .into_iter()
+            .map(|(path, kind)| {
+                JsFileSystemChange {
+                    path: path.to_string_lossy().to_string(),
+                    kind,
+                }
+            })
+            .collect();
+
+        let event = JsEvent::FileSystemWatch { watch_id, changes };
+
+        if event_sender.send(event).await.is_err() {
+            break;
+        }
+    }
+}
+
+fn add_changes(changes: &mut IndexMap<PathBuf, JsFileSystemChangeKind>, event: notify::Event) {
+    let kinds = match event.kind {
+        EventKind::Create(_) => vec![JsFileSystemChangeKind::Create; event.paths.len()],
+        EventKind::Remove(_) => vec![JsFileSystemChangeKind::Delete; event.paths.len()],
+        EventKind::Modify(ModifyKind::Name(RenameMode::From)) => vec![JsFileSystemChangeKind::Delete],
+        EventKind::Modify(ModifyKind::Name(RenameMode::To)) => vec![JsFileSystemChangeKind::Create],
+        EventKind::Modify(ModifyKind::Name(RenameMode::Both)) => {
+            vec![JsFileSystemChangeKind::Delete, JsFileSystemChangeKind::Create]
+        }
+        EventKind::Modify(ModifyKind::Name(RenameMode::Any | RenameMode::Other)) => {
+            // platform doesn't tell which side of rename this is
+            event
+                .paths
+                .iter()
+                .map(|path| {
+                    if path.exists() {
+                        JsFileSystemChangeKind::Create
+                    } else {
+                        JsFileSystemChangeKind::Delete
+                    }
+                })
+                .collect()
+        }
+        EventKind::Modify(_) => vec![JsFileSystemChangeKind::Modify; event.paths.len()],
+        EventKind::Access(_) | EventKind::Any | EventKind::Other => vec![],
+    };
+
+    for (path, kind) in event.paths.into_iter().zip(kinds) {
+        let merged = match (changes.get(&path), kind) {
+            (None, kind) => Some(kind),
+            (Some(JsFileSystemChangeKind::Create), JsFileSystemChangeKind::Modify) => {
+                Some(JsFileSystemChangeKind::Create)
+            }
+            (Some(JsFileSystemChangeKind::Create), JsFileSystemChangeKind::Delete) => None,
+            (Some(JsFileSystemChangeKind::Delete), JsFileSystemChangeKind::Create) => {
+                Some(JsFileSystemChangeKind::Modify)
+            }
+            (Some(_), kind) => Some(kind),
+        };
+
+        match merged {
+            Some(kind) => {
+                changes.insert(path, kind);
+            }
+            None => {
+                changes.shift_remove(&path);
+            }
+        }
+    }
+}
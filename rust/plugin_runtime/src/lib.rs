@@ -6,22 +6,27 @@ mod deno;
 mod entrypoint_generators;
 mod environment;
 mod events;
+mod fs_watch;
 mod logs;
 mod model;
 mod permissions;
 mod plugin_data;
+mod plugin_functions;
 mod plugins;
 mod preferences;
+mod process;
 mod search;
+mod storage;
 mod ui;
 
 use std::cell::RefCell;
 use std::cell::RefMut;
+use std::collections::HashMap;
 use std::convert;
 use std::fmt::Debug;
-use std::ops::Deref;
 use std::ops::DerefMut;
 use std::sync::atomic::AtomicU32;
+use std::sync::atomic::AtomicU64;
 use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
@@ -32,11 +37,15 @@ pub use api::BackendForPluginRuntimeApi;
 use bincode::Decode;
 use bincode::Encode;
 use deno_core::futures::SinkExt;
+pub use events::JsEntrypointInput;
 pub use events::JsEvent;
+pub use events::JsFileSystemChange;
+pub use events::JsFileSystemChangeKind;
 pub use events::JsKeyboardEventOrigin;
 pub use events::JsUiPropertyValue;
 use gauntlet_utils::channel::Payload;
 use gauntlet_utils::channel::RequestReceiver;
+use gauntlet_utils::channel::Responder;
 use interprocess::local_socket::tokio::prelude::*;
 use interprocess::local_socket::tokio::RecvHalf;
 use interprocess::local_socket::tokio::SendHalf;
@@ -46,6 +55,8 @@ use interprocess::local_socket::NameType;
 use interprocess::local_socket::ToNsName;
 pub use model::*;
 use once_cell::sync::Lazy;
+pub use permissions::read_permission_paths;
+pub use permissions::NetworkPermission;
 pub use permissions::PERMISSIONS_VARIABLE_PATTERN;
 use regex::Regex;
 use serde::de::DeserializeOwned;
@@ -58,7 +69,6 @@ use tokio::runtime::Handle;
 use tokio::sync::mpsc::channel;
 use tokio::sync::mpsc::Receiver;
 use tokio::sync::mpsc::Sender;
-use tokio::sync::oneshot;
 use tokio::sync::Mutex;
 use tokio::sync::MutexGuard;
 use tokio_util::sync::CancellationToken;
@@ -93,7 +103,7 @@ async fn run_outer(socket_name: String) -> anyhow::Result<()> {
     let (request_sender, mut request_receiver) =
         gauntlet_utils::channel::channel::<JsRequest, Result<JsResponse, String>>();
     let (event_sender, event_receiver) = channel::<JsEvent>(10);
-    let response_oneshot = Mutex::new(None);
+    let pending_requests = Mutex::new(HashMap::new());
 
     let init = recv_message::<JsInit>(JsMessageSide::PluginRuntime, &mut recver).await?;
 
@@ -110,7 +120,7 @@ async fn run_outer(socket_name: String) -> anyhow::Result<()> {
         result @ _ = {
              tokio::task::unconstrained(async {
                 loop {
-                    if let Err(err) = message_loop(&mut recver, &event_sender, &response_oneshot, stop_token.clone()).await {
+                    if let Err(err) = message_loop(&mut recver, &event_sender, &pending_requests, stop_token.clone()).await {
                         tracing::error!("Message loop has returned an error: {:?}", err);
                         break;
                     }
@@ -122,7 +132,7 @@ async fn run_outer(socket_name: String) -> anyhow::Result<()> {
         result @ _ = {
              tokio::task::unconstrained(async {
                 loop {
-                    if let Err(err) = request_loop(&mut sender, &mut request_receiver, &response_oneshot).await {
+                    if let Err(err) = request_loop(&mut sender, &mut request_receiver, &pending_requests).await {
                         tracing::error!("Request loop has returned an error: {:?}", err);
                         break;
                     }
@@ -132,7 +142,7 @@ async fn run_outer(socket_name: String) -> anyhow::Result<()> {
             tracing::error!("Request loop has unexpectedly stopped {:?}", plugin_id)
         }
         result @ _ = {
-            run_new_tokio(handle, stop_token.clone(), init, event_receiver, api)
+            run_new_tokio(handle, stop_token.clone(), init, event_receiver, event_sender.clone(), api)
         } => {
             tracing::error!("Request loop has unexpectedly stopped {:?}", plugin_id)
         }
@@ -157,6 +167,7 @@ async fn run_new_tokio(
     stop_token: CancellationToken,
     init: JsInit,
     event_receiver: Receiver<JsEvent>,
+    event_sender: Sender<JsEvent>,
     api: BackendForPluginRuntimeApiProxy,
 ) -> anyhow::Result<()> {
     tokio::task::spawn_blocking(|| {
@@ -164,7 +175,7 @@ async fn run_new_tokio(
             .enable_all()
             .build()
             .expect("unable to start tokio runtime for plugin")
-            .block_on(run(outer_handle, stop_token, init, event_receiver, api))
+            .block_on(run(outer_handle, stop_token, init, event_receiver, event_sender, api))
     })
     .await??;
 
@@ -176,6 +187,7 @@ async fn run(
     stop_token: CancellationToken,
     init: JsInit,
     event_receiver: Receiver<JsEvent>,
+    event_sender: Sender<JsEvent>,
     api: BackendForPluginRuntimeApiProxy,
 ) -> anyhow::Result<()> {
     let plugin_id = init.plugin_id.clone();
@@ -186,7 +198,7 @@ async fn run(
         }
         result @ _ = {
             tokio::task::unconstrained(async {
-                 start_js_runtime(outer_handle, init, event_receiver, api).await
+                 start_js_runtime(outer_handle, init, event_receiver, event_sender, api).await
             })
         } => {
             if let Err(err) = result {
@@ -200,53 +212,37 @@ async fn run(
     Ok(())
 }
 
+static REQUEST_ID: AtomicU64 = AtomicU64::new(0);
+
+// backend can respond out of order, e.g. plugin function call waits for another plugin
+// which in turn can send requests that call functions of this plugin
 async fn request_loop(
     send: &mut SendHalf,
     request_receiver: &mut RequestReceiver<JsRequest, Result<JsResponse, String>>,
-    response_oneshot: &Mutex<Option<oneshot::Sender<Result<JsResponse, String>>>>,
+    pending_requests: &Mutex<HashMap<u64, Responder<Result<JsResponse, String>>>>,
 ) -> anyhow::Result<()> {
     let (request, responder) = request_receiver.recv().await;
 
     tracing::trace!("Received request {:?}", &request);
 
-    let rx = {
-        let mut response_oneshot = response_oneshot.lock().await;
-
-        let None = response_oneshot.deref() else {
-            return Err(anyhow!(
-                "Trying to set response one shot while previous is not fulfilled"
-            ));
-        };
-
-        let (tx, rx) = oneshot::channel::<Result<JsResponse, String>>();
+    let id = REQUEST_ID.fetch_add(1, Ordering::SeqCst);
 
-        *response_oneshot = Some(tx);
-
-        rx
-    };
+    pending_requests.lock().await.insert(id, responder);
 
     send_message(
         JsMessageSide::PluginRuntime,
         send,
-        JsPluginRuntimeMessage::Request(request),
+        JsPluginRuntimeMessage::Request { id, request },
     )
     .await?;
 
-    tracing::trace!("Waiting for oneshot response...");
-
-    let response = rx.await?;
-
-    tracing::trace!("Sending response request {:?}", &response);
-
-    responder.respond(response);
-
     Ok(())
 }
 
 async fn message_loop(
     recv: &mut RecvHalf,
     event_sender: &Sender<JsEvent>,
-    response_oneshot: &Mutex<Option<oneshot::Sender<Result<JsResponse, String>>>>,
+    pending_requests: &Mutex<HashMap<u64, Responder<Result<JsResponse, String>>>>,
     stop_token: CancellationToken,
 ) -> anyhow::Result<()> {
     match recv_message::<JsMessage>(JsMessageSide::PluginRuntime, recv).await {
@@ -267,19 +263,15 @@ async fn message_loop(
 
                     Ok(())
                 }
-                JsMessage::Response(response) => {
-                    let mut response_oneshot = response_oneshot.lock().await;
-
-                    match response_oneshot.take() {
-                        Some(mut oneshot) => {
-                            match oneshot.send(response) {
-                                Err(_) => {
-                                    tracing::error!("Dropped oneshot receiving side");
-                                }
-                                Ok(_) => {
-                                    tracing::trace!("Oneshot response sent");
-                                }
-                            }
+                JsMessage::Response { id, response } => {
+                    let mut pending_requests = pending_requests.lock().await;
+
+                    match pending_requests.remove(&id) {
+                        Some(responder) => {
+                            tracing::trace!("Sending response for request {}: {:?}", id, &response);
+
+                            // requester could have given up waiting because of timeout
+                            responder.try_respond(response);
                         }
                         None => {
                             tracing::error!("Received response without corresponding request: {:?}", response);
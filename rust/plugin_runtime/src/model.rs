@@ -15,7 +15,10 @@ use crate::JsEvent;
 #[derive(Debug, Encode, Decode)]
 pub enum JsMessage {
     Event(JsEvent),
-    Response(Result<JsResponse, String>),
+    Response {
+        id: u64,
+        response: Result<JsResponse, String>,
+    },
     Stop,
 }
 
@@ -28,6 +31,7 @@ pub enum JsUiRenderLocation {
 #[derive(Debug, Encode, Decode)]
 pub struct JsPluginCode {
     pub js: HashMap<String, String>,
+    pub source_maps: HashMap<String, String>,
 }
 
 #[derive(Debug, Encode, Decode)]
@@ -39,6 +43,7 @@ pub struct JsInit {
     pub inline_view_entrypoint_id: Option<String>,
     pub entrypoint_names: HashMap<EntrypointId, String>,
     pub dev_plugin: bool,
+    pub inspector: Option<JsPluginInspector>,
     pub home_dir: String,
     pub local_storage_dir: String,
     pub plugin_cache_dir: String,
@@ -47,6 +52,13 @@ pub struct JsInit {
     pub stderr_file: Option<String>,
 }
 
+#[derive(Debug, Encode, Decode)]
+pub struct JsPluginInspector {
+    // any free port if not specified
+    pub port: Option<u16>,
+    pub break_on_start: bool,
+}
+
 #[derive(Debug, Encode, Decode)]
 pub struct JsPluginPermissions {
     pub environment: Vec<String>,
@@ -55,6 +67,18 @@ pub struct JsPluginPermissions {
     pub exec: JsPluginPermissionsExec,
     pub system: Vec<String>,
     pub main_search_bar: Vec<JsPluginPermissionsMainSearchBar>,
+    pub denied: JsPluginPermissionsDenied,
+    pub ask: bool,
+}
+
+// permissions revoked by user, denied without prompting even in "ask" mode
+#[derive(Debug, Encode, Decode)]
+pub struct JsPluginPermissionsDenied {
+    pub environment: Vec<String>,
+    pub network: Vec<String>,
+    pub filesystem: JsPluginPermissionsFileSystem,
+    pub exec: JsPluginPermissionsExec,
+    pub system: Vec<String>,
 }
 
 #[derive(Debug, Encode, Decode)]
@@ -74,10 +98,28 @@ pub enum JsPluginPermissionsMainSearchBar {
     Read,
 }
 
+#[derive(Clone, Copy, Debug, Encode, Decode)]
+pub enum JsPermissionPromptKind {
+    Environment,
+    Network,
+    FilesystemRead,
+    FilesystemWrite,
+    ExecCommand,
+    ExecExecutable,
+    System,
+}
+
+#[derive(Clone, Copy, Debug, Encode, Decode)]
+pub enum JsPermissionPromptDecision {
+    AllowOnce,
+    AllowAlways,
+    Deny,
+}
+
 #[derive(Debug, Encode, Decode)]
 pub enum JsPluginRuntimeMessage {
     Stopped,
-    Request(JsRequest),
+    Request { id: u64, request: JsRequest },
 }
 
 #[derive(Debug, Encode, Decode)]
@@ -110,6 +152,18 @@ pub enum JsResponse {
     ActionIdForShortcut {
         data: Option<String>,
     },
+    PermissionPromptDecision {
+        data: JsPermissionPromptDecision,
+    },
+    PluginFunctionResult {
+        data: String,
+    },
+    StorageValue {
+        data: Option<String>,
+    },
+    StorageEntries {
+        data: Vec<JsStorageEntry>,
+    },
 }
 
 #[derive(Debug, Encode, Decode)]
@@ -144,6 +198,10 @@ pub enum JsRequest {
         generated_entrypoints: Vec<JsGeneratedSearchItem>,
         refresh_search_list: bool,
     },
+    UpdateSearchIndex {
+        upserted_entrypoints: Vec<JsGeneratedSearchItem>,
+        removed_entrypoint_ids: Vec<String>,
+    },
     GetAssetData {
         path: String,
     },
@@ -173,6 +231,67 @@ pub enum JsRequest {
         modifier_alt: bool,
         modifier_meta: bool,
     },
+    PermissionPrompt {
+        kind: JsPermissionPromptKind,
+        resource: String,
+    },
+    AuditLog {
+        kind: JsPermissionPromptKind,
+        resource: String,
+        allowed: bool,
+    },
+    ScheduledRunFinished {
+        entrypoint_id: EntrypointId,
+        error: Option<String>,
+    },
+    InspectorStarted {
+        address: String,
+    },
+    CallPluginFunction {
+        plugin_id: PluginId,
+        function_id: String,
+        args: String,
+    },
+    PluginFunctionCallFinished {
+        call_id: String,
+        result: Option<String>,
+        error: Option<String>,
+    },
+    StorageGet {
+        namespace: String,
+        key: String,
+    },
+    StorageList {
+        namespace: String,
+        prefix: String,
+    },
+    StorageClear {
+        namespace: String,
+    },
+    StorageApply {
+        namespace: String,
+        operations: Vec<JsStorageOperation>,
+    },
+}
+
+#[derive(Debug, Deserialize, Serialize, Encode, Decode)]
+pub struct JsStorageEntry {
+    pub key: String,
+    pub value: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Encode, Decode)]
+#[serde(tag = "type")]
+pub enum JsStorageOperation {
+    Set {
+        key: String,
+        value: String,
+        #[serde(rename = "ttlSeconds")]
+        ttl_seconds: Option<u64>,
+    },
+    Delete {
+        key: String,
+    },
 }
 
 #[derive(Deserialize, Serialize, Encode, Decode)]
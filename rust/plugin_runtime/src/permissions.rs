@@ -1,14 +1,19 @@
 use std::collections::HashSet;
 use std::hash::Hash;
+use std::net::IpAddr;
 use std::path::Path;
 use std::path::PathBuf;
 use std::str::FromStr;
 use std::sync::Arc;
 
 use anyhow::anyhow;
+use deno_core::futures::executor::block_on;
+use deno_core::url::Host;
 use deno_runtime::deno_fs::FileSystemRc;
 use deno_runtime::deno_fs::RealFs;
-use deno_runtime::deno_permissions::AllowRunDescriptor;
+use deno_runtime::deno_permissions::prompter::PermissionPrompter;
+use deno_runtime::deno_permissions::prompter::PromptResponse;
+use deno_runtime::deno_permissions::DenyRunDescriptor;
 use deno_runtime::deno_permissions::EnvDescriptor;
 use deno_runtime::deno_permissions::EnvQueryDescriptor;
 use deno_runtime::deno_permissions::NetDescriptor;
@@ -25,8 +30,13 @@ use deno_runtime::permissions::RuntimePermissionDescriptorParser;
 use gauntlet_common::dirs::Dirs;
 use once_cell::sync::Lazy;
 use regex::Regex;
+use tokio::runtime::Handle;
 use typed_path::Utf8TypedPath;
 
+use crate::api::BackendForPluginRuntimeApi;
+use crate::api::BackendForPluginRuntimeApiProxy;
+use crate::model::JsPermissionPromptDecision;
+use crate::model::JsPermissionPromptKind;
 use crate::JsPluginPermissions;
 use crate::JsPluginPermissionsExec;
 
@@ -40,27 +50,36 @@ pub fn permissions_to_deno(
     plugin_data_dir: &Path,
     plugin_cache_dir: &Path,
 ) -> anyhow::Result<PermissionsContainer> {
+    let prompt = permissions.ask;
+
+    // wildcard and ip range network permissions are enforced by prompter, so it has to be called for them
+    let net_prompt = prompt || !network_patterns(&permissions.network)?.is_empty();
+
     Ok(PermissionsContainer::new(
         Arc::new(RuntimePermissionDescriptorParser::new(fs)),
         Permissions {
             read: path_permission(
                 &permissions.filesystem.read,
+                &permissions.denied.filesystem.read,
                 ReadDescriptor,
                 home_dir,
                 plugin_data_dir,
                 plugin_cache_dir,
+                prompt,
             )?,
             write: path_permission(
                 &permissions.filesystem.write,
+                &permissions.denied.filesystem.write,
                 WriteDescriptor,
                 home_dir,
                 plugin_data_dir,
                 plugin_cache_dir,
+                prompt,
             )?,
-            net: net_permission(&permissions.network),
-            env: env_permission(&permissions.environment),
-            sys: sys_permission(&permissions.system)?,
-            run: run_permission(&permissions.exec, home_dir, plugin_data_dir, plugin_cache_dir)?,
+            net: net_permission(&permissions.network, &permissions.denied.network, net_prompt),
+            env: env_permission(&permissions.environment, &permissions.denied.environment, prompt),
+            sys: sys_permission(&permissions.system, &permissions.denied.system, prompt)?,
+            run: run_permission(&permissions.denied.exec, home_dir, plugin_data_dir, plugin_cache_dir)?,
             ffi: Permissions::new_unary(None, None, false),
             import: UnaryPermission::default(),
             all: Permissions::new_all(false),
@@ -68,104 +87,410 @@ pub fn permissions_to_deno(
     ))
 }
 
+// paths from "filesystem.read" permission with variables substituted, paths not applicable to current os are skipped
+pub fn read_permission_paths(
+    read: &[String],
+    home_dir: &Path,
+    plugin_data_dir: &Path,
+    plugin_cache_dir: &Path,
+) -> anyhow::Result<Vec<PathBuf>> {
+    let paths = read
+        .iter()
+        .map(|path| augment_path(path, home_dir, plugin_data_dir, plugin_cache_dir))
+        .collect::<anyhow::Result<Vec<_>>>()?
+        .into_iter()
+        .filter_map(std::convert::identity)
+        .collect();
+
+    Ok(paths)
+}
+
+// empty list means nothing is allowed or denied, not everything
+fn to_list<D: Eq + Hash>(list: HashSet<D>) -> Option<HashSet<D>> {
+    if list.is_empty() {
+        None
+    } else {
+        Some(list)
+    }
+}
+
 fn path_permission<P: Eq + Hash, T: QueryDescriptor<AllowDesc = P, DenyDesc = P> + Hash>(
     paths: &[String],
+    denied_paths: &[String],
     to_permission: fn(PathBuf) -> P,
     home_dir: &Path,
     plugin_data_dir: &Path,
     plugin_cache_dir: &Path,
+    prompt: bool,
 ) -> anyhow::Result<UnaryPermission<T>> {
-    let allow_list = paths
+    let allow_list = read_permission_paths(paths, home_dir, plugin_data_dir, plugin_cache_dir)?
         .into_iter()
-        .map(|path| {
-            augment_path(path, home_dir, plugin_data_dir, plugin_cache_dir)
-                .map(|path| path.map(|path| to_permission(path)))
-        })
-        .collect::<anyhow::Result<Vec<_>>>()?
+        .map(to_permission)
+        .collect();
+
+    let deny_list = read_permission_paths(denied_paths, home_dir, plugin_data_dir, plugin_cache_dir)?
         .into_iter()
-        .filter_map(std::convert::identity)
-        .collect::<HashSet<_>>();
+        .map(to_permission)
+        .collect();
 
-    let allow_list = if allow_list.is_empty() { None } else { Some(allow_list) };
+    Ok(Permissions::new_unary(to_list(allow_list), to_list(deny_list), prompt))
+}
 
-    Ok(Permissions::new_unary(allow_list, None, false))
+// wildcard and ip range permissions are not supported by deno, they are checked in prompter instead
+fn exact_net_descriptors(domain_and_ports: &[String]) -> HashSet<NetDescriptor> {
+    domain_and_ports
+        .into_iter()
+        .filter(|domain_and_port| matches!(NetworkPermission::parse(domain_and_port), Ok(NetworkPermission::Exact)))
+        .map(|domain_and_port| NetDescriptor::parse(&domain_and_port).expect("should be validated when loading"))
+        .collect()
 }
 
-fn net_permission(domain_and_ports: &[String]) -> UnaryPermission<NetDescriptor> {
-    let allow_list = if domain_and_ports.is_empty() {
-        None
-    } else {
-        let allow_list = domain_and_ports
-            .into_iter()
-            .map(|domain_and_port| NetDescriptor::parse(&domain_and_port).expect("should be validated when loading"))
-            .collect();
+fn net_permission(domain_and_ports: &[String], denied: &[String], prompt: bool) -> UnaryPermission<NetDescriptor> {
+    let allow_list = exact_net_descriptors(domain_and_ports);
+    let deny_list = exact_net_descriptors(denied);
 
-        Some(allow_list)
-    };
+    Permissions::new_unary(to_list(allow_list), to_list(deny_list), prompt)
+}
 
-    Permissions::new_unary(allow_list, None, false)
+#[derive(Debug, Clone)]
+pub enum NetworkPermission {
+    // host with optional port, checked by deno itself
+    Exact,
+    // "*.example.com" or "*.example.com:443", matches subdomains of any depth but not the domain itself
+    Subdomains { domain: String, port: Option<u16> },
+    // "192.168.1.0/24" or "fd00::/8"
+    IpRange { address: IpAddr, prefix_len: u8 },
 }
 
-fn env_permission(envs: &[String]) -> UnaryPermission<EnvQueryDescriptor> {
-    let allow_list = if envs.is_empty() {
-        None
-    } else {
-        let allow_list = envs.into_iter().map(|env| EnvDescriptor::new(env)).collect();
+impl NetworkPermission {
+    pub fn parse(value: &str) -> anyhow::Result<NetworkPermission> {
+        if let Some(domain_and_port) = value.strip_prefix("*.") {
+            let (domain, port) = match domain_and_port.rsplit_once(':') {
+                Some((domain, port)) => {
+                    let port = port
+                        .parse::<u16>()
+                        .map_err(|_| anyhow!("Invalid port in network permission: {}", value))?;
+
+                    (domain, Some(port))
+                }
+                None => (domain_and_port, None),
+            };
+
+            match Host::parse(domain) {
+                Ok(Host::Domain(domain)) if !domain.contains('*') => Ok(NetworkPermission::Subdomains { domain, port }),
+                _ => Err(anyhow!("Invalid domain in wildcard network permission: {}", value)),
+            }
+        } else if let Some((address, prefix_len)) = value.split_once('/') {
+            let address = IpAddr::from_str(address)
+                .map_err(|_| anyhow!("Invalid ip address in network permission: {}", value))?;
+
+            let prefix_len = prefix_len
+                .parse::<u8>()
+                .map_err(|_| anyhow!("Invalid prefix length in network permission: {}", value))?;
+
+            let max_prefix_len = match address {
+                IpAddr::V4(_) => 32,
+                IpAddr::V6(_) => 128,
+            };
+
+            if prefix_len > max_prefix_len {
+                Err(anyhow!("Invalid prefix length in network permission: {}", value))?
+            }
+
+            Ok(NetworkPermission::IpRange { address, prefix_len })
+        } else if value.contains('*') {
+            Err(anyhow!(
+                "Wildcard in network permission is only allowed as a first subdomain: {}",
+                value
+            ))
+        } else {
+            Ok(NetworkPermission::Exact)
+        }
+    }
 
-        Some(allow_list)
-    };
+    // resource as formatted by deno, e.g. "api.example.com:443" or "[::1]:8080"
+    fn matches(&self, resource: &str) -> bool {
+        let (host, resource_port) = split_host_port(resource);
 
-    Permissions::new_unary(allow_list, None, false)
+        match self {
+            NetworkPermission::Exact => false,
+            NetworkPermission::Subdomains { domain, port } => {
+                let port_matches = match port {
+                    None => true,
+                    Some(port) => resource_port == Some(*port),
+                };
+
+                port_matches && host.to_lowercase().ends_with(&format!(".{}", domain))
+            }
+            NetworkPermission::IpRange { address, prefix_len } => {
+                match (address, IpAddr::from_str(host)) {
+                    (IpAddr::V4(address), Ok(IpAddr::V4(host))) => {
+                        let mask = u32::MAX.checked_shl(32 - *prefix_len as u32).unwrap_or(0);
+
+                        u32::from(*address) & mask == u32::from(host) & mask
+                    }
+                    (IpAddr::V6(address), Ok(IpAddr::V6(host))) => {
+                        let mask = u128::MAX.checked_shl(128 - *prefix_len as u32).unwrap_or(0);
+
+                        u128::from(*address) & mask == u128::from(host) & mask
+                    }
+                    _ => false,
+                }
+            }
+        }
+    }
 }
 
-fn sys_permission(system: &[String]) -> anyhow::Result<UnaryPermission<SysDescriptor>> {
-    let allow_list = if system.is_empty() {
-        None
-    } else {
-        let allow_list = system
-            .into_iter()
-            .map(|system| SysDescriptor::parse(system.to_owned()))
-            .collect::<Result<Vec<_>, _>>()?
-            .into_iter()
-            .collect();
+fn split_host_port(resource: &str) -> (&str, Option<u16>) {
+    if let Some(rest) = resource.strip_prefix('[') {
+        if let Some((host, rest)) = rest.split_once(']') {
+            let port = rest.strip_prefix(':').and_then(|port| port.parse::<u16>().ok());
 
-        Some(allow_list)
-    };
+            return (host, port);
+        }
+    }
 
-    Ok(Permissions::new_unary(allow_list, None, false))
+    match resource.rsplit_once(':') {
+        // more than one colon means ipv6 address without port
+        Some((host, port)) if !host.contains(':') => (host, port.parse::<u16>().ok()),
+        _ => (resource, None),
+    }
 }
 
-fn run_permission(
+fn env_permission(envs: &[String], denied: &[String], prompt: bool) -> UnaryPermission<EnvQueryDescriptor> {
+    let allow_list = envs.into_iter().map(|env| EnvDescriptor::new(env)).collect();
+    let deny_list = denied.into_iter().map(|env| EnvDescriptor::new(env)).collect();
+
+    Permissions::new_unary(to_list(allow_list), to_list(deny_list), prompt)
+}
+
+fn sys_permission(
+    system: &[String],
+    denied: &[String],
+    prompt: bool,
+) -> anyhow::Result<UnaryPermission<SysDescriptor>> {
+    let allow_list = system
+        .into_iter()
+        .map(|system| SysDescriptor::parse(system.to_owned()))
+        .collect::<Result<HashSet<_>, _>>()?;
+
+    let deny_list = denied
+        .into_iter()
+        .map(|system| SysDescriptor::parse(system.to_owned()))
+        .collect::<Result<HashSet<_>, _>>()?;
+
+    Ok(Permissions::new_unary(to_list(allow_list), to_list(deny_list), prompt))
+}
+
+// paths of executables and commands from "exec" permission, commands not found in PATH are skipped
+pub fn exec_permission_paths(
     permissions: &JsPluginPermissionsExec,
     home_dir: &Path,
     plugin_data_dir: &Path,
     plugin_cache_dir: &Path,
+) -> anyhow::Result<Vec<PathBuf>> {
+    let granted_executable =
+        read_permission_paths(&permissions.executable, home_dir, plugin_data_dir, plugin_cache_dir)?;
+
+    let granted_command = permissions.command.iter().flat_map(|cmd| which::which_global(cmd).ok());
+
+    Ok(granted_executable.into_iter().chain(granted_command).collect())
+}
+
+// allowed commands are not passed to deno, so every command goes through prompter
+// where it is checked against "exec" permission and recorded in audit log, for both Deno.Command and Gauntlet.spawn()
+// deno remembers the decision, so each command is recorded once per runtime start
+fn run_permission(
+    denied: &JsPluginPermissionsExec,
+    home_dir: &Path,
+    plugin_data_dir: &Path,
+    plugin_cache_dir: &Path,
 ) -> anyhow::Result<UnaryPermission<RunQueryDescriptor>> {
-    let granted_executable = permissions
-        .executable
-        .iter()
-        .map(|path| {
-            augment_path(path, home_dir, plugin_data_dir, plugin_cache_dir)
-                .map(|path| path.map(|path| AllowRunDescriptor(path)))
+    let denied_executable = read_permission_paths(&denied.executable, home_dir, plugin_data_dir, plugin_cache_dir)?
+        .into_iter()
+        .map(|path| DenyRunDescriptor::Path(path));
+
+    let denied_command = denied.command.iter().map(|cmd| DenyRunDescriptor::Name(cmd.to_owned()));
+
+    let deny_list = denied_executable.chain(denied_command).collect();
+
+    Ok(Permissions::new_unary(None, to_list(deny_list), true))
+}
+
+pub struct PluginPermissionPrompter {
+    outer_handle: Handle,
+    api: BackendForPluginRuntimeApiProxy,
+    ask: bool,
+    network: Vec<NetworkPermission>,
+    denied_network: Vec<NetworkPermission>,
+    exec: Vec<PathBuf>,
+}
+
+impl PluginPermissionPrompter {
+    pub fn new(
+        outer_handle: Handle,
+        api: BackendForPluginRuntimeApiProxy,
+        permissions: &JsPluginPermissions,
+        exec: Vec<PathBuf>,
+    ) -> anyhow::Result<Self> {
+        Ok(Self {
+            outer_handle,
+            api,
+            ask: permissions.ask,
+            network: network_patterns(&permissions.network)?,
+            denied_network: network_patterns(&permissions.denied.network)?,
+            exec,
         })
+    }
+}
+
+fn network_patterns(network: &[String]) -> anyhow::Result<Vec<NetworkPermission>> {
+    let patterns = network
+        .iter()
+        .map(|value| NetworkPermission::parse(value))
         .collect::<anyhow::Result<Vec<_>>>()?
         .into_iter()
-        .filter_map(std::convert::identity)
-        .collect::<Vec<_>>();
+        .filter(|permission| !matches!(permission, NetworkPermission::Exact))
+        .collect();
 
-    let granted_command = permissions
-        .command
-        .iter()
-        .flat_map(|cmd| anyhow::Ok(AllowRunDescriptor(which::which_global(cmd)?)))
-        .collect::<Vec<_>>();
+    Ok(patterns)
+}
+
+// deno formats prompt message as `<name> access to "<resource>"`,
+// prompts without specific resource and with special resources like "<CWD>" are not supported
+fn prompt_resource(message: &str, name: &str) -> Option<String> {
+    let resource = message
+        .strip_prefix(name)?
+        .strip_prefix(" access to ")?
+        .strip_prefix('"')?
+        .strip_suffix('"')?;
+
+    Some(resource.to_string())
+}
+
+impl PermissionPrompter for PluginPermissionPrompter {
+    fn prompt(&mut self, message: &str, name: &str, api_name: Option<&str>, _is_unary: bool) -> PromptResponse {
+        let kind = match name {
+            "env" => JsPermissionPromptKind::Environment,
+            "net" => JsPermissionPromptKind::Network,
+            "read" => JsPermissionPromptKind::FilesystemRead,
+            "write" => JsPermissionPromptKind::FilesystemWrite,
+            "run" => JsPermissionPromptKind::ExecCommand,
+            "sys" => JsPermissionPromptKind::System,
+            _ => {
+                tracing::warn!("Denying unsupported permission '{}' requested by {:?}", name, api_name);
+
+                return PromptResponse::Deny;
+            }
+        };
+
+        let Some(resource) = prompt_resource(message, name) else {
+            tracing::warn!(
+                "Denying '{}' permission requested by {:?}, unable to get resource from: {}",
+                name,
+                api_name,
+                message
+            );
+
+            return PromptResponse::Deny;
+        };
+
+        let kind = match kind {
+            JsPermissionPromptKind::ExecCommand => exec_permission_kind(&resource),
+            kind => kind,
+        };
 
-    let mut granted = HashSet::new();
-    granted.extend(granted_executable);
-    granted.extend(granted_command);
+        let api = self.api.clone();
+        let outer_handle = self.outer_handle.clone();
 
-    let allow_list = if granted.is_empty() { None } else { Some(granted) };
+        if name == "net" {
+            if self
+                .denied_network
+                .iter()
+                .any(|permission| permission.matches(&resource))
+            {
+                record_audit_log(outer_handle, api, kind, resource, false);
 
-    Ok(Permissions::new_unary(allow_list, None, false))
+                return PromptResponse::Deny;
+            }
+
+            if self.network.iter().any(|permission| permission.matches(&resource)) {
+                return PromptResponse::Allow;
+            }
+        }
+
+        if name == "run" {
+            // deno passes command as it was requested, resolve it the same way "exec.command" permission is resolved
+            let path = if Path::new(&resource).is_absolute() {
+                Some(PathBuf::from(&resource))
+            } else {
+                which::which_global(&resource).ok()
+            };
+
+            if let Some(path) = path {
+                if self.exec.contains(&path) {
+                    record_audit_log(outer_handle, api, kind, resource, true);
+
+                    return PromptResponse::Allow;
+                }
+            }
+        }
+
+        if !self.ask {
+            record_audit_log(outer_handle, api, kind, resource, false);
+
+            return PromptResponse::Deny;
+        }
+
+        let decision = block_on(async move {
+            outer_handle
+                .spawn(async move { api.permission_prompt(kind, resource).await })
+                .await
+        });
+
+        match decision {
+            Ok(Ok(JsPermissionPromptDecision::AllowOnce)) => PromptResponse::Allow,
+            Ok(Ok(JsPermissionPromptDecision::AllowAlways)) => PromptResponse::Allow,
+            Ok(Ok(JsPermissionPromptDecision::Deny)) => PromptResponse::Deny,
+            Ok(Err(err)) => {
+                tracing::error!("Unable to prompt user for permission: {:?}", err);
+                PromptResponse::Deny
+            }
+            Err(err) => {
+                tracing::error!("Unable to prompt user for permission: {:?}", err);
+                PromptResponse::Deny
+            }
+        }
+    }
+}
+
+// decision made by user is recorded in audit log by backend, here only decisions made without asking are recorded
+fn record_audit_log(
+    outer_handle: Handle,
+    api: BackendForPluginRuntimeApiProxy,
+    kind: JsPermissionPromptKind,
+    resource: String,
+    allowed: bool,
+) {
+    let result = block_on(async move {
+        outer_handle
+            .spawn(async move { api.audit_log(kind, resource, allowed).await })
+            .await
+    });
+
+    match result {
+        Ok(Ok(())) => {}
+        Ok(Err(err)) => tracing::error!("Unable to record permission check in audit log: {:?}", err),
+        Err(err) => tracing::error!("Unable to record permission check in audit log: {:?}", err),
+    }
+}
+
+fn exec_permission_kind(command: &str) -> JsPermissionPromptKind {
+    if Path::new(command).is_absolute() {
+        JsPermissionPromptKind::ExecExecutable
+    } else {
+        JsPermissionPromptKind::ExecCommand
+    }
 }
 
 fn augment_path(
@@ -239,3 +564,106 @@ fn augment_path(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(value: &str) -> NetworkPermission {
+        NetworkPermission::parse(value).unwrap()
+    }
+
+    #[test]
+    fn exact_host_is_left_to_deno() {
+        assert!(matches!(parse("example.com"), NetworkPermission::Exact));
+        assert!(matches!(parse("example.com:443"), NetworkPermission::Exact));
+        assert!(!parse("example.com").matches("example.com"));
+    }
+
+    #[test]
+    fn wildcard_matches_subdomains_only() {
+        let permission = parse("*.example.com");
+
+        assert!(permission.matches("api.example.com:443"));
+        assert!(permission.matches("a.b.example.com"));
+        assert!(permission.matches("API.Example.com:80"));
+        assert!(!permission.matches("example.com"));
+        assert!(!permission.matches("example.com:443"));
+        assert!(!permission.matches("badexample.com"));
+        assert!(!permission.matches("api.badexample.com"));
+        assert!(!permission.matches("example.com.evil.org"));
+    }
+
+    #[test]
+    fn wildcard_with_port() {
+        let permission = parse("*.example.com:443");
+
+        assert!(permission.matches("api.example.com:443"));
+        assert!(!permission.matches("api.example.com:8443"));
+        assert!(!permission.matches("api.example.com"));
+    }
+
+    #[test]
+    fn invalid_wildcards() {
+        assert!(NetworkPermission::parse("*.example.com:http").is_err());
+        assert!(NetworkPermission::parse("*.*.example.com").is_err());
+        assert!(NetworkPermission::parse("api.*.example.com").is_err());
+        assert!(NetworkPermission::parse("*example.com").is_err());
+    }
+
+    #[test]
+    fn ipv4_range_boundaries() {
+        let permission = parse("192.168.1.0/24");
+
+        assert!(permission.matches("192.168.1.0"));
+        assert!(permission.matches("192.168.1.255:8080"));
+        assert!(!permission.matches("192.168.0.255"));
+        assert!(!permission.matches("192.168.2.0:80"));
+        assert!(!permission.matches("example.com"));
+
+        let permission = parse("10.0.0.1/32");
+
+        assert!(permission.matches("10.0.0.1"));
+        assert!(!permission.matches("10.0.0.2"));
+
+        let permission = parse("0.0.0.0/0");
+
+        assert!(permission.matches("8.8.8.8:53"));
+        assert!(!permission.matches("[::1]:53"));
+    }
+
+    #[test]
+    fn ipv6_range_boundaries() {
+        let permission = parse("fd00::/8");
+
+        assert!(permission.matches("[fd00::1]:8080"));
+        assert!(permission.matches("[fdff:ffff::1]"));
+        assert!(permission.matches("fd12::1"));
+        assert!(!permission.matches("[fe00::1]:8080"));
+        assert!(!permission.matches("[fcff::1]"));
+        assert!(!permission.matches("10.0.0.1"));
+
+        let permission = parse("::1/128");
+
+        assert!(permission.matches("[::1]:8080"));
+        assert!(!permission.matches("[::2]:8080"));
+    }
+
+    #[test]
+    fn invalid_ranges() {
+        assert!(NetworkPermission::parse("192.168.1.0/33").is_err());
+        assert!(NetworkPermission::parse("fd00::/129").is_err());
+        assert!(NetworkPermission::parse("192.168.1.0/abc").is_err());
+        assert!(NetworkPermission::parse("example.com/24").is_err());
+    }
+
+    #[test]
+    fn host_and_port_split() {
+        assert_eq!(split_host_port("example.com"), ("example.com", None));
+        assert_eq!(split_host_port("example.com:443"), ("example.com", Some(443)));
+        assert_eq!(split_host_port("[::1]:8080"), ("::1", Some(8080)));
+        assert_eq!(split_host_port("[::1]"), ("::1", None));
+        assert_eq!(split_host_port("fd00::1"), ("fd00::1", None));
+        assert_eq!(split_host_port("127.0.0.1:80"), ("127.0.0.1", Some(80)));
+    }
+}
@@ -0,0 +1,48 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use deno_core::op2;
+use deno_core::OpState;
+use gauntlet_common::model::PluginId;
+
+use crate::api::BackendForPluginRuntimeApi;
+use crate::api::BackendForPluginRuntimeApiProxy;
+
+// arguments and result are passed as json strings, they are validated by backend against schemas from manifest
+#[op2(async)]
+#[string]
+pub async fn call_plugin_function(
+    state: Rc<RefCell<OpState>>,
+    #[string] plugin_id: String,
+    #[string] function_id: String,
+    #[string] args: String,
+) -> anyhow::Result<String> {
+    let api = {
+        let state = state.borrow();
+
+        let api = state.borrow::<BackendForPluginRuntimeApiProxy>().clone();
+
+        api
+    };
+
+    api.call_plugin_function(PluginId::from_string(plugin_id), function_id, args)
+        .await
+}
+
+#[op2(async)]
+pub async fn plugin_function_call_finished(
+    state: Rc<RefCell<OpState>>,
+    #[string] call_id: String,
+    #[serde] result: Option<String>,
+    #[serde] error: Option<String>,
+) -> anyhow::Result<()> {
+    let api = {
+        let state = state.borrow();
+
+        let api = state.borrow::<BackendForPluginRuntimeApiProxy>().clone();
+
+        api
+    };
+
+    api.plugin_function_call_finished(call_id, result, error).await
+}
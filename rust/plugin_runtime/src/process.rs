@@ -0,0 +1,313 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::process::Stdio;
+use std::rc::Rc;
+
+use anyhow::anyhow;
+use deno_core::op2;
+use deno_core::OpState;
+use deno_runtime::deno_permissions::PermissionsContainer;
+use deno_runtime::deno_permissions::RunQueryDescriptor;
+use gauntlet_common::model::EntrypointId;
+use serde::Serialize;
+use tokio::io::AsyncRead;
+use tokio::io::AsyncReadExt;
+use tokio::sync::mpsc::unbounded_channel;
+use tokio::sync::mpsc::UnboundedReceiver;
+use tokio::sync::mpsc::UnboundedSender;
+use tokio_util::sync::CancellationToken;
+
+use crate::api::BackendForPluginRuntimeApi;
+use crate::api::BackendForPluginRuntimeApiProxy;
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "type")]
+pub enum JsProcessEvent {
+    Stdout { data: String },
+    Stderr { data: String },
+    Exit { code: Option<i32> },
+}
+
+// processes spawned while view is open belong to that view,
+// they are killed when view is closed and loading bar is shown while any of them is running
+pub struct RunningProcesses {
+    next_id: u32,
+    view_entrypoint_id: Option<EntrypointId>,
+    processes: HashMap<u32, RunningProcess>,
+}
+
+struct RunningProcess {
+    view_entrypoint_id: Option<EntrypointId>,
+    events: Rc<RefCell<UnboundedReceiver<JsProcessEvent>>>,
+    kill_token: CancellationToken,
+}
+
+impl RunningProcesses {
+    pub fn new() -> Self {
+        Self {
+            next_id: 0,
+            view_entrypoint_id: None,
+            processes: HashMap::new(),
+        }
+    }
+
+    fn has_view_processes(&self, entrypoint_id: &EntrypointId) -> bool {
+        self.processes
+            .values()
+            .any(|process| process.view_entrypoint_id.as_ref() == Some(entrypoint_id))
+    }
+}
+
+pub fn view_opened(state: &Rc<RefCell<OpState>>, entrypoint_id: &str) {
+    let mut state = state.borrow_mut();
+
+    let running_processes = state.borrow_mut::<RunningProcesses>();
+
+    running_processes.view_entrypoint_id = Some(EntrypointId::from_string(entrypoint_id));
+}
+
+pub async fn view_closed(state: &Rc<RefCell<OpState>>) -> anyhow::Result<()> {
+    let (api, entrypoint_id) = {
+        let mut state = state.borrow_mut();
+
+        let api = state.borrow::<BackendForPluginRuntimeApiProxy>().clone();
+
+        let running_processes = state.borrow_mut::<RunningProcesses>();
+
+        let Some(entrypoint_id) = running_processes.view_entrypoint_id.take() else {
+            return Ok(());
+        };
+
+        if !running_processes.has_view_processes(&entrypoint_id) {
+            return Ok(());
+        }
+
+        running_processes.processes.retain(|_, process| {
+            if process.view_entrypoint_id.as_ref() == Some(&entrypoint_id) {
+                process.kill_token.cancel();
+                false
+            } else {
+                true
+            }
+        });
+
+        (api, entrypoint_id)
+    };
+
+    api.ui_update_loading_bar(entrypoint_id, false).await
+}
+
+#[op2(async)]
+pub async fn process_spawn(
+    state: Rc<RefCell<OpState>>,
+    #[string] command: String,
+    #[serde] args: Vec<String>,
+    #[serde] cwd: Option<String>,
+) -> anyhow::Result<u32> {
+    {
+        let mut state = state.borrow_mut();
+
+        // same check that deno does for Deno.Command, including permission prompt and audit log
+        let query = RunQueryDescriptor::parse(&command)
+            .map_err(|err| anyhow!("Unable to resolve command '{}': {}", command, err))?;
+
+        state
+            .borrow_mut::<PermissionsContainer>()
+            .check_run(&query, "Gauntlet.spawn()")?;
+    }
+
+    let mut process_command = tokio::process::Command::new(&command);
+
+    process_command
+        .args(args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true);
+
+    if let Some(cwd) = cwd {
+        process_command.current_dir(cwd);
+    }
+
+    let mut child = process_command
+        .spawn()
+        .map_err(|err| anyhow!("Unable to spawn command '{}': {}", command, err))?;
+
+    let stdout = child.stdout.take().expect("stdout is piped");
+    let stderr = child.stderr.take().expect("stderr is piped");
+
+    let (sender, receiver) = unbounded_channel();
+
+    let stdout_task = tokio::spawn(read_output(stdout, sender.clone(), |data| {
+        JsProcessEvent::Stdout { data }
+    }));
+    let stderr_task = tokio::spawn(read_output(stderr, sender.clone(), |data| {
+        JsProcessEvent::Stderr { data }
+    }));
+
+    let kill_token = CancellationToken::new();
+
+    {
+        let kill_token = kill_token.clone();
+
+        tokio::spawn(async move {
+            let code = tokio::select! {
+                status = child.wait() => {
+                    match status {
+                        Ok(status) => status.code(),
+                        Err(err) => {
+                            tracing::warn!("Unable to wait for spawned process: {:?}", err);
+                            None
+                        }
+                    }
+                }
+                _ = kill_token.cancelled() => {
+                    if let Err(err) = child.kill().await {
+                        tracing::warn!("Unable to kill spawned process: {:?}", err);
+                    }
+                    None
+                }
+            };
+
+            // all output is delivered before exit
+            let _ = stdout_task.await;
+            let _ = stderr_task.await;
+
+            let _ = sender.send(JsProcessEvent::Exit { code });
+        });
+    }
+
+    let (id, show_loading_bar, api) = {
+        let mut state = state.borrow_mut();
+
+        let api = state.borrow::<BackendForPluginRuntimeApiProxy>().clone();
+
+        let running_processes = state.borrow_mut::<RunningProcesses>();
+
+        let id = running_processes.next_id;
+        running_processes.next_id += 1;
+
+        let view_entrypoint_id = running_processes.view_entrypoint_id.clone();
+
+        let show_loading_bar = match &view_entrypoint_id {
+            Some(entrypoint_id) if !running_processes.has_view_processes(entrypoint_id) => Some(entrypoint_id.clone()),
+            Some(_) | None => None,
+        };
+
+        running_processes.processes.insert(
+            id,
+            RunningProcess {
+                view_entrypoint_id,
+                events: Rc::new(RefCell::new(receiver)),
+                kill_token,
+            },
+        );
+
+        (id, show_loading_bar, api)
+    };
+
+    if let Some(entrypoint_id) = show_loading_bar {
+        api.ui_update_loading_bar(entrypoint_id, true).await?;
+    }
+
+    Ok(id)
+}
+
+// returns None when process is no longer tracked, e.g. it was killed because view was closed
+#[op2(async)]
+#[serde]
+pub async fn process_next_event(state: Rc<RefCell<OpState>>, id: u32) -> anyhow::Result<Option<JsProcessEvent>> {
+    let events = {
+        let state = state.borrow();
+
+        let running_processes = state.borrow::<RunningProcesses>();
+
+        match running_processes.processes.get(&id) {
+            Some(process) => process.events.clone(),
+            None => return Ok(None),
+        }
+    };
+
+    let event = events.borrow_mut().recv().await;
+
+    let Some(event) = event else {
+        return Ok(None);
+    };
+
+    if let JsProcessEvent::Exit { .. } = event {
+        let (api, hide_loading_bar) = {
+            let mut state = state.borrow_mut();
+
+            let api = state.borrow::<BackendForPluginRuntimeApiProxy>().clone();
+
+            let running_processes = state.borrow_mut::<RunningProcesses>();
+
+            let hide_loading_bar = match running_processes.processes.remove(&id) {
+                Some(RunningProcess {
+                    view_entrypoint_id: Some(entrypoint_id),
+                    ..
+                }) if !running_processes.has_view_processes(&entrypoint_id) => Some(entrypoint_id),
+                Some(_) | None => None,
+            };
+
+            (api, hide_loading_bar)
+        };
+
+        if let Some(entrypoint_id) = hide_loading_bar {
+            api.ui_update_loading_bar(entrypoint_id, false).await?;
+        }
+    }
+
+    Ok(Some(event))
+}
+
+#[op2(fast)]
+pub fn process_kill(state: &mut OpState, id: u32) {
+    let running_processes = state.borrow::<RunningProcesses>();
+
+    // process stays tracked until its exit event is read, so loading bar is hidden there
+    if let Some(process) = running_processes.processes.get(&id) {
+        process.kill_token.cancel();
+    }
+}
+
+async fn read_output(
+    mut output: impl AsyncRead + Unpin,
+    sender: UnboundedSender<JsProcessEvent>,
+    to_event: fn(String) -> JsProcessEvent,
+) {
+    let mut buffer = [0; 8192];
+    let mut pending = vec![];
+
+    loop {
+        let count = match output.read(&mut buffer).await {
+            Ok(0) => break,
+            Ok(count) => count,
+            Err(err) => {
+                tracing::warn!("Unable to read output of spawned process: {:?}", err);
+                break;
+            }
+        };
+
+        pending.extend_from_slice(&buffer[..count]);
+
+        // keep utf-8 character split between reads until the rest of it arrives
+        let complete = match std::str::from_utf8(&pending) {
+            Ok(_) => pending.len(),
+            Err(err) if err.error_len().is_none() => err.valid_up_to(),
+            Err(_) => pending.len(),
+        };
+
+        if complete == 0 {
+            continue;
+        }
+
+        let chunk = pending.drain(..complete).collect::<Vec<_>>();
+
+        let _ = sender.send(to_event(String::from_utf8_lossy(&chunk).into_owned()));
+    }
+
+    if !pending.is_empty() {
+        let _ = sender.send(to_event(String::from_utf8_lossy(&pending).into_owned()));
+    }
+}
@@ -27,3 +27,24 @@ pub async fn reload_search_index(
 
     Ok(())
 }
+
+// only changed generated entrypoints are sent, entrypoints which are not mentioned stay as they are
+#[op2(async)]
+pub async fn update_search_index(
+    state: Rc<RefCell<OpState>>,
+    #[serde] upserted_entrypoints: Vec<JsGeneratedSearchItem>,
+    #[serde] removed_entrypoint_ids: Vec<String>,
+) -> anyhow::Result<()> {
+    let api = {
+        let state = state.borrow();
+
+        let api = state.borrow::<BackendForPluginRuntimeApiProxy>().clone();
+
+        api
+    };
+
+    api.update_search_index(upserted_entrypoints, removed_entrypoint_ids)
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,78 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use deno_core::op2;
+use deno_core::OpState;
+
+use crate::api::BackendForPluginRuntimeApi;
+use crate::api::BackendForPluginRuntimeApiProxy;
+use crate::model::JsStorageEntry;
+use crate::model::JsStorageOperation;
+
+// values are passed as json strings, they are stored as is
+#[op2(async)]
+#[serde]
+pub async fn storage_get(
+    state: Rc<RefCell<OpState>>,
+    #[string] namespace: String,
+    #[string] key: String,
+) -> anyhow::Result<Option<String>> {
+    let api = {
+        let state = state.borrow();
+
+        let api = state.borrow::<BackendForPluginRuntimeApiProxy>().clone();
+
+        api
+    };
+
+    api.storage_get(namespace, key).await
+}
+
+#[op2(async)]
+#[serde]
+pub async fn storage_list(
+    state: Rc<RefCell<OpState>>,
+    #[string] namespace: String,
+    #[string] prefix: String,
+) -> anyhow::Result<Vec<JsStorageEntry>> {
+    let api = {
+        let state = state.borrow();
+
+        let api = state.borrow::<BackendForPluginRuntimeApiProxy>().clone();
+
+        api
+    };
+
+    api.storage_list(namespace, prefix).await
+}
+
+#[op2(async)]
+pub async fn storage_clear(state: Rc<RefCell<OpState>>, #[string] namespace: String) -> anyhow::Result<()> {
+    let api = {
+        let state = state.borrow();
+
+        let api = state.borrow::<BackendForPluginRuntimeApiProxy>().clone();
+
+        api
+    };
+
+    api.storage_clear(namespace).await
+}
+
+// operations are applied in a single transaction
+#[op2(async)]
+pub async fn storage_apply(
+    state: Rc<RefCell<OpState>>,
+    #[string] namespace: String,
+    #[serde] operations: Vec<JsStorageOperation>,
+) -> anyhow::Result<()> {
+    let api = {
+        let state = state.borrow();
+
+        let api = state.borrow::<BackendForPluginRuntimeApiProxy>().clone();
+
+        api
+    };
+
+    api.storage_apply(namespace, operations).await
+}
@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
 
@@ -61,7 +62,9 @@ pub async fn start_scenario_runner_frontend(
 
     println!("saving local plugin");
 
-    backend_client.save_local_plugin(scenario_plugin_dir.clone()).await?;
+    backend_client
+        .save_local_plugin(scenario_plugin_dir.clone(), None, false)
+        .await?;
 
     println!("local plugin saved");
 
@@ -105,7 +108,7 @@ pub async fn start_scenario_runner_frontend(
                     let entrypoint_id = EntrypointId::from_string(&entrypoint_name);
 
                     backend_for_frontend_client
-                        .request_view_render(plugin_id, entrypoint_id)
+                        .request_view_render(plugin_id, entrypoint_id, HashMap::new())
                         .await?;
                 }
             }
@@ -160,7 +163,9 @@ async fn request_loop(
             | UiRequestData::SetTheme { .. } => {
                 unreachable!()
             }
-            UiRequestData::SetGlobalShortcut { .. } | UiRequestData::RequestSearchResultUpdate => {
+            UiRequestData::SetGlobalShortcut { .. }
+            | UiRequestData::RequestSearchResultUpdate
+            | UiRequestData::UpdateSearchResultRows { .. } => {
                 // noop
             }
             UiRequestData::ReplaceView {
@@ -0,0 +1 @@
+ALTER TABLE plugin ADD COLUMN permissions_user_data JSON NOT NULL DEFAULT ('{}');
@@ -0,0 +1,10 @@
+CREATE TABLE plugin_audit_log
+(
+    id         INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+    plugin_id  TEXT    NOT NULL REFERENCES plugin (id) ON DELETE CASCADE,
+    timestamp  INTEGER NOT NULL,
+    permission JSON    NOT NULL,
+    allowed    BOOL    NOT NULL
+);
+
+CREATE INDEX plugin_audit_log_plugin_id ON plugin_audit_log (plugin_id);
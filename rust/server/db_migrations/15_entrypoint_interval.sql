@@ -0,0 +1,11 @@
+ALTER TABLE plugin_entrypoint ADD COLUMN interval_seconds INTEGER DEFAULT NULL;
+
+CREATE TABLE plugin_entrypoint_scheduled_run
+(
+    plugin_id     TEXT    NOT NULL REFERENCES plugin (id) ON DELETE CASCADE,
+    entrypoint_id TEXT    NOT NULL,
+    timestamp     INTEGER NOT NULL,
+    error         TEXT,
+
+    PRIMARY KEY (plugin_id, entrypoint_id)
+);
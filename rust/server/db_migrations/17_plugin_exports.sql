@@ -0,0 +1 @@
+ALTER TABLE plugin ADD COLUMN exports JSON NOT NULL DEFAULT ('[]');
@@ -0,0 +1 @@
+ALTER TABLE plugin_entrypoint ADD COLUMN accepts_input JSON NOT NULL DEFAULT ('[]');
@@ -63,7 +63,7 @@ pub fn start(minimized: bool) {
     #[cfg(not(feature = "scenario_runner"))]
     {
         if is_server_running() {
-            open_window()
+            open_window(None)
         } else {
             let (frontend_sender, frontend_receiver) = channel::<UiRequestData, UiResponseData>();
             let (backend_sender, backend_receiver) = channel::<BackendRequestData, BackendResponseData>();
@@ -105,6 +105,34 @@ pub fn run_action(plugin_id: String, entrypoint_id: String, action_id: String) {
         })
 }
 
+pub fn open_deep_link(link: String) {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .expect("unable to start server tokio runtime")
+        .block_on(async {
+            let result = BackendApi::new().await;
+
+            match result {
+                Ok(mut backend_api) => {
+                    if let Err(err) = backend_api.open_deep_link(link).await {
+                        match err {
+                            BackendApiError::Timeout => {
+                                tracing::error!("Timeout occurred when handling deep link");
+                            }
+                            BackendApiError::Internal { display: value } => {
+                                tracing::error!("Error occurred when handling deep link: {}", value);
+                            }
+                        }
+                    }
+                }
+                Err(_) => {
+                    tracing::error!("Unable to connect to server. Please check if you have Gauntlet running on your PC")
+                }
+            }
+        })
+}
+
 #[cfg(feature = "scenario_runner")]
 fn run_scenario_runner() {
     let runner_type =
@@ -200,7 +228,7 @@ async fn run_server(
         let plugin_path = std::fs::canonicalize(plugin_path).expect("valid path");
         let plugin_path = plugin_path.to_str().expect("valid utf8");
 
-        if let Err(err) = application_manager.save_local_plugin(plugin_path).await {
+        if let Err(err) = application_manager.save_local_plugin(plugin_path, None, false).await {
             tracing::error!("error loading dev plugin: {:?}", err);
         }
     }
@@ -213,6 +241,12 @@ async fn run_server(
         async move { start_backend_server(Box::new(BackendServerImpl::new(application_manager.clone()))).await }
     });
 
+    tokio::spawn({
+        let application_manager = application_manager.clone();
+
+        async move { application_manager.run_scheduled_entrypoints().await }
+    });
+
     loop {
         let (request_data, responder) = backend_receiver.recv().await;
 
@@ -248,9 +282,14 @@ async fn handle_request(
         BackendRequestData::RequestViewRender {
             plugin_id,
             entrypoint_id,
+            arguments,
         } => {
+            let input = application_manager
+                .entrypoint_input_for(&plugin_id, &entrypoint_id)
+                .await;
+
             let shortcuts = application_manager
-                .handle_render_view(plugin_id.clone(), entrypoint_id.clone())
+                .handle_render_view(plugin_id.clone(), entrypoint_id.clone(), arguments, input)
                 .await?;
 
             BackendResponseData::RequestViewRender { shortcuts }
@@ -263,8 +302,15 @@ async fn handle_request(
         BackendRequestData::RequestRunCommand {
             plugin_id,
             entrypoint_id,
+            arguments,
         } => {
-            application_manager.handle_run_command(plugin_id, entrypoint_id).await;
+            let input = application_manager
+                .entrypoint_input_for(&plugin_id, &entrypoint_id)
+                .await;
+
+            application_manager
+                .handle_run_command(plugin_id, entrypoint_id, arguments, input)
+                .await;
 
             BackendResponseData::Nothing
         }
@@ -335,6 +381,16 @@ async fn handle_request(
 
             BackendResponseData::InlineViewShortcuts { shortcuts }
         }
+        BackendRequestData::ListPluginDirectory { plugin_id, path } => {
+            let directory = application_manager.list_plugin_directory(plugin_id, path).await?;
+
+            BackendResponseData::PluginDirectory { directory }
+        }
+        BackendRequestData::CaptureEntrypointInput => {
+            application_manager.capture_entrypoint_input(None);
+
+            BackendResponseData::Nothing
+        }
     };
 
     Ok(response_data)
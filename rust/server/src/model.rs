@@ -1,6 +1,10 @@
+use std::collections::HashMap;
+
 use gauntlet_common::model::EntrypointId;
 use gauntlet_common::model::KeyboardEventOrigin;
 use gauntlet_common::model::PhysicalKey;
+use gauntlet_common::model::PluginId;
+use gauntlet_common::model::SearchResultEntrypointInputKind;
 use gauntlet_common::model::UiPropertyValue;
 use gauntlet_common::model::UiWidgetId;
 
@@ -8,10 +12,14 @@ use gauntlet_common::model::UiWidgetId;
 pub enum IntermediateUiEvent {
     OpenView {
         entrypoint_id: EntrypointId,
+        arguments: HashMap<String, String>,
+        input: Option<EntrypointInput>,
     },
     CloseView,
     RunCommand {
         entrypoint_id: String,
+        arguments: HashMap<String, String>,
+        input: Option<EntrypointInput>,
     },
     RunGeneratedEntrypoint {
         entrypoint_id: String,
@@ -35,6 +43,52 @@ pub enum IntermediateUiEvent {
         text: String,
     },
     RefreshSearchIndex,
+    PreferencesChanged {
+        entrypoint_id: Option<EntrypointId>,
+    },
+    RunScheduledEntrypoint {
+        entrypoint_id: EntrypointId,
+    },
+    CallPluginFunction {
+        call_id: String,
+        caller_plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        function_id: String,
+        args: String,
+    },
+}
+
+// selection, clipboard content or text passed via cli, captured when window is opened
+#[derive(Debug, Clone)]
+pub struct EntrypointInput {
+    pub text: Option<String>,
+    pub png_data: Option<Vec<u8>>,
+}
+
+impl EntrypointInput {
+    pub fn is_accepted_by(&self, accepts_input: &[SearchResultEntrypointInputKind]) -> bool {
+        (self.text.is_some() && accepts_input.contains(&SearchResultEntrypointInputKind::Text))
+            || (self.png_data.is_some() && accepts_input.contains(&SearchResultEntrypointInputKind::Image))
+    }
+
+    // entrypoint only receives kinds of input it accepts
+    pub fn for_entrypoint(&self, accepts_input: &[SearchResultEntrypointInputKind]) -> Option<EntrypointInput> {
+        if !self.is_accepted_by(accepts_input) {
+            return None;
+        }
+
+        let text = match accepts_input.contains(&SearchResultEntrypointInputKind::Text) {
+            true => self.text.clone(),
+            false => None,
+        };
+
+        let png_data = match accepts_input.contains(&SearchResultEntrypointInputKind::Image) {
+            true => self.png_data.clone(),
+            false => None,
+        };
+
+        Some(EntrypointInput { text, png_data })
+    }
 }
 
 pub enum ActionShortcutKey {
@@ -55,6 +55,10 @@ pub struct DbReadPlugin {
     pub preferences: HashMap<String, DbPluginPreference>,
     #[sqlx(json)]
     pub preferences_user_data: HashMap<String, DbPluginPreferenceUserData>,
+    #[sqlx(json)]
+    pub permissions_user_data: DbPluginPermissionsUserData,
+    #[sqlx(json)]
+    pub exports: Vec<DbPluginExport>,
 }
 
 #[derive(sqlx::FromRow)]
@@ -76,11 +80,18 @@ pub struct DbReadPluginEntrypoint {
     pub actions: Vec<DbPluginAction>,
     #[sqlx(json)]
     pub actions_user_data: Vec<DbPluginActionUserData>,
+    pub interval_seconds: Option<i64>,
+    #[sqlx(json)]
+    pub arguments: Vec<DbPluginArgument>,
+    #[sqlx(json)]
+    pub accepts_input: Vec<DbPluginEntrypointInputKind>,
 }
 
 #[derive(Deserialize, Serialize)]
 pub struct DbCode {
     pub js: HashMap<String, String>,
+    #[serde(default)]
+    pub source_maps: HashMap<String, String>,
 }
 
 pub struct DbWritePlugin {
@@ -94,6 +105,7 @@ pub struct DbWritePlugin {
     pub permissions: DbPluginPermissions,
     pub plugin_type: String,
     pub preferences: HashMap<String, DbPluginPreference>,
+    pub exports: Vec<DbPluginExport>,
 }
 
 pub struct DbWritePluginEntrypoint {
@@ -102,8 +114,11 @@ pub struct DbWritePluginEntrypoint {
     pub description: String,
     pub icon_path: Option<String>,
     pub entrypoint_type: String,
+    pub interval_seconds: Option<i64>,
     pub preferences: HashMap<String, DbPluginPreference>,
     pub actions: Vec<DbPluginAction>,
+    pub arguments: Vec<DbPluginArgument>,
+    pub accepts_input: Vec<DbPluginEntrypointInputKind>,
 }
 
 pub struct DbWritePluginAssetData {
@@ -142,6 +157,8 @@ pub struct DbPluginPermissions {
     pub clipboard: Vec<DbPluginClipboardPermissions>,
     #[serde(default)]
     pub main_search_bar: Vec<DbPluginMainSearchBarPermissions>,
+    #[serde(default)]
+    pub plugins: Vec<String>,
 }
 
 #[derive(Debug, Deserialize, Serialize, Default)]
@@ -176,6 +193,46 @@ pub enum DbPluginMainSearchBarPermissions {
     Read,
 }
 
+#[derive(Debug, Deserialize, Serialize, Default)]
+pub struct DbPluginPermissionsUserData {
+    #[serde(default)]
+    pub ask: bool,
+    #[serde(default)]
+    pub revoked: Vec<DbPluginPermission>,
+    #[serde(default)]
+    pub granted: Vec<DbPluginPermission>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct DbPluginPermission {
+    pub kind: DbPluginPermissionKind,
+    pub resource: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum DbPluginPermissionKind {
+    #[serde(rename = "environment")]
+    Environment,
+    #[serde(rename = "network")]
+    Network,
+    #[serde(rename = "filesystem_read")]
+    FilesystemRead,
+    #[serde(rename = "filesystem_write")]
+    FilesystemWrite,
+    #[serde(rename = "exec_command")]
+    ExecCommand,
+    #[serde(rename = "exec_executable")]
+    ExecExecutable,
+    #[serde(rename = "system")]
+    System,
+    #[serde(rename = "clipboard")]
+    Clipboard,
+    #[serde(rename = "main_search_bar")]
+    MainSearchBar,
+    #[serde(rename = "plugins")]
+    Plugins,
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(tag = "type")]
 pub enum DbPluginPreferenceUserData {
@@ -203,6 +260,39 @@ pub struct DbPluginAction {
     pub kind: DbPluginActionShortcutKind,
 }
 
+#[derive(Debug, Deserialize, Serialize)]
+pub enum DbPluginEntrypointInputKind {
+    #[serde(rename = "text")]
+    Text,
+    #[serde(rename = "image")]
+    Image,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(tag = "type")]
+pub enum DbPluginArgument {
+    #[serde(rename = "text")]
+    Text { id: String, name: String, required: bool },
+    #[serde(rename = "password")]
+    Password { id: String, name: String, required: bool },
+    #[serde(rename = "dropdown")]
+    Dropdown {
+        id: String,
+        name: String,
+        required: bool,
+        values: Vec<DbPreferenceEnumValue>,
+    },
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct DbPluginExport {
+    pub id: String,
+    pub entrypoint_id: String,
+    pub description: String,
+    pub input_schema: Option<serde_json::Value>,
+    pub output_schema: Option<serde_json::Value>,
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct DbPluginActionUserData {
     pub id: String,
@@ -342,8 +432,33 @@ pub struct DbPluginEntrypointFrecencyStats {
     pub num_accesses: i32,
 }
 
+#[derive(sqlx::FromRow)]
+pub struct DbReadPluginAuditLogEntry {
+    pub timestamp: i64,
+    #[sqlx(json)]
+    pub permission: DbPluginPermission,
+    pub allowed: bool,
+}
+
+#[derive(sqlx::FromRow)]
+pub struct DbReadScheduledEntrypoint {
+    pub plugin_id: String,
+    pub entrypoint_id: String,
+    pub interval_seconds: i64,
+}
+
+#[derive(sqlx::FromRow)]
+pub struct DbReadScheduledRun {
+    pub plugin_id: String,
+    pub entrypoint_id: String,
+    pub timestamp: i64,
+    pub error: Option<String>,
+}
+
 const SETTINGS_DATA_ID: &str = "settings_data"; // only one row in the table
 
+const AUDIT_LOG_MAX_ENTRIES_PER_PLUGIN: i64 = 500;
+
 impl DataDbRepository {
     pub async fn new(dirs: Dirs) -> anyhow::Result<Self> {
         let data_db_file = dirs.data_db_file()?;
@@ -1102,6 +1217,141 @@ impl DataDbRepository {
         Ok(())
     }
 
+    pub async fn set_permissions_user_data(
+        &self,
+        plugin_id: &str,
+        user_data: DbPluginPermissionsUserData,
+    ) -> anyhow::Result<()> {
+        // language=SQLite
+        sqlx::query("UPDATE plugin SET permissions_user_data = ?1 WHERE id = ?2")
+            .bind(Json(user_data))
+            .bind(plugin_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn add_granted_permission(&self, plugin_id: &str, permission: DbPluginPermission) -> anyhow::Result<()> {
+        let mut tx = self.pool.begin().await?;
+
+        let mut user_data = self
+            .get_plugin_by_id_with_executor(plugin_id, &mut *tx)
+            .await?
+            .permissions_user_data;
+
+        if !user_data.granted.contains(&permission) {
+            user_data.granted.push(permission);
+        }
+
+        // language=SQLite
+        sqlx::query("UPDATE plugin SET permissions_user_data = ?1 WHERE id = ?2")
+            .bind(Json(user_data))
+            .bind(plugin_id)
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    pub async fn add_audit_log_entry(
+        &self,
+        plugin_id: &str,
+        timestamp: i64,
+        permission: DbPluginPermission,
+        allowed: bool,
+    ) -> anyhow::Result<()> {
+        let mut tx = self.pool.begin().await?;
+
+        // language=SQLite
+        sqlx::query("INSERT INTO plugin_audit_log (plugin_id, timestamp, permission, allowed) VALUES(?1, ?2, ?3, ?4)")
+            .bind(plugin_id)
+            .bind(timestamp)
+            .bind(Json(permission))
+            .bind(allowed)
+            .execute(&mut *tx)
+            .await?;
+
+        // keep only the latest entries, so the log works as a ring buffer
+        // language=SQLite
+        let sql = r#"
+            DELETE FROM plugin_audit_log
+                WHERE plugin_id = ?1 AND id NOT IN (
+                    SELECT id FROM plugin_audit_log WHERE plugin_id = ?1 ORDER BY id DESC LIMIT ?2
+                )
+        "#;
+
+        sqlx::query(sql)
+            .bind(plugin_id)
+            .bind(AUDIT_LOG_MAX_ENTRIES_PER_PLUGIN)
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    pub async fn get_audit_log(&self, plugin_id: &str) -> anyhow::Result<Vec<DbReadPluginAuditLogEntry>> {
+        // language=SQLite
+        let result = sqlx::query_as::<_, DbReadPluginAuditLogEntry>(
+            "SELECT timestamp, permission, allowed FROM plugin_audit_log WHERE plugin_id = ?1 ORDER BY id DESC",
+        )
+        .bind(plugin_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(result)
+    }
+
+    pub async fn get_scheduled_entrypoints(&self) -> anyhow::Result<Vec<DbReadScheduledEntrypoint>> {
+        // language=SQLite
+        let sql = r#"
+            SELECT e.plugin_id, e.id AS entrypoint_id, e.interval_seconds
+                FROM plugin_entrypoint e
+                    JOIN plugin p ON p.id = e.plugin_id
+                WHERE e.interval_seconds IS NOT NULL AND e.enabled AND p.enabled
+        "#;
+
+        let result = sqlx::query_as::<_, DbReadScheduledEntrypoint>(sql)
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(result)
+    }
+
+    pub async fn set_scheduled_run_result(
+        &self,
+        plugin_id: &str,
+        entrypoint_id: &str,
+        timestamp: i64,
+        error: Option<String>,
+    ) -> anyhow::Result<()> {
+        // language=SQLite
+        sqlx::query("INSERT OR REPLACE INTO plugin_entrypoint_scheduled_run (plugin_id, entrypoint_id, timestamp, error) VALUES(?1, ?2, ?3, ?4)")
+            .bind(plugin_id)
+            .bind(entrypoint_id)
+            .bind(timestamp)
+            .bind(error)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn list_scheduled_runs(&self) -> anyhow::Result<Vec<DbReadScheduledRun>> {
+        // language=SQLite
+        let result = sqlx::query_as::<_, DbReadScheduledRun>(
+            "SELECT plugin_id, entrypoint_id, timestamp, error FROM plugin_entrypoint_scheduled_run",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(result)
+    }
+
     pub async fn save_pending_plugin(&self, plugin: DbWritePendingPlugin) -> anyhow::Result<()> {
         // language=SQLite
         sqlx::query("INSERT INTO pending_plugin VALUES(?1)")
@@ -1132,10 +1382,10 @@ impl DataDbRepository {
 
         // language=SQLite
         let sql = r#"
-            INSERT INTO plugin (id, name, enabled, code, permissions, preferences, preferences_user_data, description, type, uuid)
-                VALUES(?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)
+            INSERT INTO plugin (id, name, enabled, code, permissions, preferences, preferences_user_data, description, type, uuid, exports)
+                VALUES(?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)
                     ON CONFLICT (id)
-                        DO UPDATE SET name = ?2, enabled = ?3, code = ?4, permissions = ?5, preferences = ?6, preferences_user_data = ?7, description = ?8, type = ?9, uuid = ?10
+                        DO UPDATE SET name = ?2, enabled = ?3, code = ?4, permissions = ?5, preferences = ?6, preferences_user_data = ?7, description = ?8, type = ?9, uuid = ?10, exports = ?11
         "#;
 
         sqlx::query(sql)
@@ -1149,6 +1399,7 @@ impl DataDbRepository {
             .bind(new_plugin.description)
             .bind(new_plugin.plugin_type)
             .bind(uuid)
+            .bind(Json(new_plugin.exports))
             .execute(&mut *tx)
             .await?;
 
@@ -1176,7 +1427,7 @@ impl DataDbRepository {
                 .unwrap_or((Uuid::new_v4().to_string(), HashMap::new(), vec![], true));
 
             // language=SQLite
-            sqlx::query("INSERT OR REPLACE INTO plugin_entrypoint (id, plugin_id, name, enabled, type, preferences, preferences_user_data, description, actions, actions_user_data, icon_path, uuid) VALUES(?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)")
+            sqlx::query("INSERT OR REPLACE INTO plugin_entrypoint (id, plugin_id, name, enabled, type, preferences, preferences_user_data, description, actions, actions_user_data, icon_path, uuid, interval_seconds, arguments, accepts_input) VALUES(?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15)")
                 .bind(&new_entrypoint.id)
                 .bind(&new_plugin.id)
                 .bind(new_entrypoint.name)
@@ -1189,6 +1440,9 @@ impl DataDbRepository {
                 .bind(Json(actions_user_data))
                 .bind(new_entrypoint.icon_path)
                 .bind(uuid)
+                .bind(new_entrypoint.interval_seconds)
+                .bind(Json(new_entrypoint.arguments))
+                .bind(Json(new_entrypoint.accepts_input))
                 .execute(&mut *tx)
                 .await?;
         }
@@ -0,0 +1,196 @@
+use std::collections::HashMap;
+
+use anyhow::anyhow;
+use anyhow::Context;
+use gauntlet_common::model::EntrypointId;
+use gauntlet_common::model::PluginId;
+use gauntlet_common::model::SearchResultEntrypointArgument;
+use gauntlet_common::model::SearchResultEntrypointArgumentKind;
+use percent_encoding::percent_decode_str;
+use url::Url;
+
+pub const DEEP_LINK_SCHEME: &str = "gauntlet";
+
+pub struct DeepLink {
+    pub plugin_id: PluginId,
+    pub entrypoint_id: EntrypointId,
+    pub params: HashMap<String, String>,
+}
+
+// links have following format: gauntlet://<plugin-id>/<entrypoint-id>?key=value
+// plugin id is percent-encoded because it is an url itself
+pub fn parse_deep_link(link: &str) -> anyhow::Result<DeepLink> {
+    let url = Url::parse(link).with_context(|| format!("Invalid deep link: {}", link))?;
+
+    if url.scheme() != DEEP_LINK_SCHEME {
+        return Err(anyhow!(
+            "Deep link has unsupported scheme '{}', expected '{}'",
+            url.scheme(),
+            DEEP_LINK_SCHEME
+        ));
+    }
+
+    let plugin_id = url
+        .host_str()
+        .filter(|plugin_id| !plugin_id.is_empty())
+        .ok_or_else(|| anyhow!("Deep link doesn't specify plugin id: {}", link))?;
+
+    let plugin_id = percent_decode(plugin_id).with_context(|| format!("Deep link has invalid plugin id: {}", link))?;
+
+    let entrypoint_id = match url.path_segments().map(|segments| segments.collect::<Vec<_>>()) {
+        Some(segments) => {
+            match segments.as_slice() {
+                [entrypoint_id] if !entrypoint_id.is_empty() => {
+                    percent_decode(entrypoint_id)
+                        .with_context(|| format!("Deep link has invalid entrypoint id: {}", link))?
+                }
+                _ => return Err(anyhow!("Deep link path should consist of only entrypoint id: {}", link)),
+            }
+        }
+        None => return Err(anyhow!("Deep link doesn't specify entrypoint id: {}", link)),
+    };
+
+    let params = url.query_pairs().into_owned().collect();
+
+    Ok(DeepLink {
+        plugin_id: PluginId::from_string(plugin_id),
+        entrypoint_id: EntrypointId::from_string(entrypoint_id),
+        params,
+    })
+}
+
+// links can be opened by anyone, so only declared arguments with valid values are accepted
+pub fn validate_deep_link_params(
+    arguments: &[SearchResultEntrypointArgument],
+    params: &HashMap<String, String>,
+) -> anyhow::Result<()> {
+    for (key, value) in params {
+        let Some(argument) = arguments.iter().find(|argument| &argument.id == key) else {
+            return Err(anyhow!(
+                "Deep link has parameter '{}' which is not an argument of entrypoint",
+                key
+            ));
+        };
+
+        match &argument.kind {
+            SearchResultEntrypointArgumentKind::Text | SearchResultEntrypointArgumentKind::Password => {}
+            SearchResultEntrypointArgumentKind::Dropdown { values } => {
+                if !values.iter().any(|enum_value| &enum_value.value == value) {
+                    return Err(anyhow!(
+                        "Deep link has parameter '{}' with value '{}' which is not one of allowed values",
+                        key,
+                        value
+                    ));
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn percent_decode(value: &str) -> anyhow::Result<String> {
+    let value = percent_decode_str(value)
+        .decode_utf8()
+        .with_context(|| format!("Percent-decoded value is not a valid utf-8: {}", value))?;
+
+    Ok(value.into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use gauntlet_common::model::PreferenceEnumValue;
+
+    use super::*;
+
+    #[test]
+    fn parse_link_with_params() {
+        let link = parse_deep_link("gauntlet://bundled%3A%2F%2Fgauntlet/calculator?query=2%2B2&mode=fast").unwrap();
+
+        assert_eq!(link.plugin_id.to_string(), "bundled://gauntlet");
+        assert_eq!(link.entrypoint_id.to_string(), "calculator");
+        assert_eq!(
+            link.params,
+            HashMap::from([
+                ("query".to_string(), "2+2".to_string()),
+                ("mode".to_string(), "fast".to_string())
+            ])
+        );
+    }
+
+    #[test]
+    fn parse_link_without_params() {
+        let link = parse_deep_link("gauntlet://file%3A%2F%2F%2Fhome%2Fuser%2Fplugin/entry-point").unwrap();
+
+        assert_eq!(link.plugin_id.to_string(), "file:///home/user/plugin");
+        assert_eq!(link.entrypoint_id.to_string(), "entry-point");
+        assert!(link.params.is_empty());
+    }
+
+    #[test]
+    fn parse_link_with_encoded_entrypoint_id() {
+        let link = parse_deep_link("gauntlet://plugin/entry%20point?q=a%20b+c").unwrap();
+
+        assert_eq!(link.entrypoint_id.to_string(), "entry point");
+        assert_eq!(link.params.get("q").map(|value| value.as_str()), Some("a b c"));
+    }
+
+    #[test]
+    fn parse_invalid_links() {
+        assert!(parse_deep_link("https://plugin/entrypoint").is_err());
+        assert!(parse_deep_link("gauntlet://plugin").is_err());
+        assert!(parse_deep_link("gauntlet://plugin/").is_err());
+        assert!(parse_deep_link("gauntlet://plugin/entrypoint/extra").is_err());
+        assert!(parse_deep_link("gauntlet:///entrypoint").is_err());
+        assert!(parse_deep_link("gauntlet://plugin/%FF").is_err());
+        assert!(parse_deep_link("not a link").is_err());
+    }
+
+    fn arguments() -> Vec<SearchResultEntrypointArgument> {
+        vec![
+            SearchResultEntrypointArgument {
+                id: "query".to_string(),
+                name: "Query".to_string(),
+                required: true,
+                kind: SearchResultEntrypointArgumentKind::Text,
+            },
+            SearchResultEntrypointArgument {
+                id: "mode".to_string(),
+                name: "Mode".to_string(),
+                required: false,
+                kind: SearchResultEntrypointArgumentKind::Dropdown {
+                    values: vec![PreferenceEnumValue {
+                        label: "Fast".to_string(),
+                        value: "fast".to_string(),
+                    }],
+                },
+            },
+        ]
+    }
+
+    #[test]
+    fn validate_declared_params() {
+        let params = HashMap::from([
+            ("query".to_string(), "anything".to_string()),
+            ("mode".to_string(), "fast".to_string()),
+        ]);
+
+        assert!(validate_deep_link_params(&arguments(), &params).is_ok());
+        assert!(validate_deep_link_params(&arguments(), &HashMap::new()).is_ok());
+    }
+
+    #[test]
+    fn validate_undeclared_params() {
+        let params = HashMap::from([("other".to_string(), "value".to_string())]);
+
+        assert!(validate_deep_link_params(&arguments(), &params).is_err());
+        assert!(validate_deep_link_params(&[], &params).is_err());
+    }
+
+    #[test]
+    fn validate_dropdown_value() {
+        let params = HashMap::from([("mode".to_string(), "slow".to_string())]);
+
+        assert!(validate_deep_link_params(&arguments(), &params).is_err());
+    }
+}
@@ -0,0 +1,58 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use gauntlet_common::model::PluginId;
+use tokio::sync::watch;
+
+// plugin runtime starts inspector server itself, so actual address is only known after runtime reports it back
+const INSPECTOR_START_TIMEOUT: Duration = Duration::from_secs(10);
+
+// addresses of inspector servers of currently running dev plugins
+#[derive(Clone)]
+pub struct PluginInspectorAddresses {
+    addresses: Arc<Mutex<HashMap<PluginId, watch::Sender<Option<String>>>>>,
+}
+
+impl PluginInspectorAddresses {
+    pub fn new() -> Self {
+        Self {
+            addresses: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    // called before plugin runtime is started, so address of previous runtime is not returned
+    pub fn reset(&self, plugin_id: &PluginId) {
+        let mut addresses = self.addresses.lock().expect("lock is poisoned");
+
+        addresses.remove(plugin_id);
+    }
+
+    pub fn started(&self, plugin_id: &PluginId, address: String) {
+        let mut addresses = self.addresses.lock().expect("lock is poisoned");
+
+        addresses
+            .entry(plugin_id.clone())
+            .or_insert_with(|| watch::channel(None).0)
+            .send_replace(Some(address));
+    }
+
+    // none if plugin is not running or inspector server failed to start
+    pub async fn wait(&self, plugin_id: &PluginId) -> Option<String> {
+        let mut receiver = {
+            let mut addresses = self.addresses.lock().expect("lock is poisoned");
+
+            addresses
+                .entry(plugin_id.clone())
+                .or_insert_with(|| watch::channel(None).0)
+                .subscribe()
+        };
+
+        tokio::time::timeout(INSPECTOR_START_TIMEOUT, receiver.wait_for(|address| address.is_some()))
+            .await
+            .ok()?
+            .ok()?
+            .clone()
+    }
+}
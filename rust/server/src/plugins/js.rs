@@ -11,6 +11,8 @@ use std::rc::Rc;
 use std::str::FromStr;
 use std::sync::Arc;
 use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
 
 use anyhow::anyhow;
 use anyhow::Context;
@@ -18,10 +20,18 @@ use futures::AsyncBufReadExt;
 use gauntlet_common::dirs::Dirs;
 use gauntlet_common::model::EntrypointId;
 use gauntlet_common::model::KeyboardEventOrigin;
+use gauntlet_common::model::PermissionPromptDecision;
 use gauntlet_common::model::PhysicalKey;
+use gauntlet_common::model::PhysicalShortcut;
 use gauntlet_common::model::PluginId;
+use gauntlet_common::model::PluginPermission;
+use gauntlet_common::model::PluginPermissionKind;
+use gauntlet_common::model::PreferenceEnumValue;
 use gauntlet_common::model::RootWidget;
 use gauntlet_common::model::SearchResultAccessory;
+use gauntlet_common::model::SearchResultEntrypointArgument;
+use gauntlet_common::model::SearchResultEntrypointArgumentKind;
+use gauntlet_common::model::SearchResultEntrypointInputKind;
 use gauntlet_common::model::SearchResultEntrypointType;
 use gauntlet_common::model::UiPropertyValue;
 use gauntlet_common::model::UiRenderLocation;
@@ -32,6 +42,7 @@ use gauntlet_plugin_runtime::recv_message;
 use gauntlet_plugin_runtime::send_message;
 use gauntlet_plugin_runtime::BackendForPluginRuntimeApi;
 use gauntlet_plugin_runtime::JsClipboardData;
+use gauntlet_plugin_runtime::JsEntrypointInput;
 use gauntlet_plugin_runtime::JsEvent;
 use gauntlet_plugin_runtime::JsGeneratedSearchItem;
 use gauntlet_plugin_runtime::JsGeneratedSearchItemAccessory;
@@ -40,8 +51,12 @@ use gauntlet_plugin_runtime::JsInit;
 use gauntlet_plugin_runtime::JsKeyboardEventOrigin;
 use gauntlet_plugin_runtime::JsMessage;
 use gauntlet_plugin_runtime::JsMessageSide;
+use gauntlet_plugin_runtime::JsPermissionPromptDecision;
+use gauntlet_plugin_runtime::JsPermissionPromptKind;
 use gauntlet_plugin_runtime::JsPluginCode;
+use gauntlet_plugin_runtime::JsPluginInspector;
 use gauntlet_plugin_runtime::JsPluginPermissions;
+use gauntlet_plugin_runtime::JsPluginPermissionsDenied;
 use gauntlet_plugin_runtime::JsPluginPermissionsExec;
 use gauntlet_plugin_runtime::JsPluginPermissionsFileSystem;
 use gauntlet_plugin_runtime::JsPluginPermissionsMainSearchBar;
@@ -49,6 +64,8 @@ use gauntlet_plugin_runtime::JsPluginRuntimeMessage;
 use gauntlet_plugin_runtime::JsPreferenceUserData;
 use gauntlet_plugin_runtime::JsRequest;
 use gauntlet_plugin_runtime::JsResponse;
+use gauntlet_plugin_runtime::JsStorageEntry;
+use gauntlet_plugin_runtime::JsStorageOperation;
 use gauntlet_plugin_runtime::JsUiPropertyValue;
 use gauntlet_plugin_runtime::JsUiRenderLocation;
 use interprocess::local_socket::tokio::RecvHalf;
@@ -69,19 +86,30 @@ use tokio::sync::Mutex;
 use tokio::task::spawn_blocking;
 use tokio_util::sync::CancellationToken;
 
+use crate::model::EntrypointInput;
 use crate::model::IntermediateUiEvent;
 use crate::plugins::clipboard::Clipboard;
 use crate::plugins::data_db_repository::db_entrypoint_from_str;
 use crate::plugins::data_db_repository::DataDbRepository;
+use crate::plugins::data_db_repository::DbPluginArgument;
 use crate::plugins::data_db_repository::DbPluginClipboardPermissions;
+use crate::plugins::data_db_repository::DbPluginEntrypointInputKind;
 use crate::plugins::data_db_repository::DbPluginEntrypointType;
 use crate::plugins::data_db_repository::DbPluginPreference;
 use crate::plugins::data_db_repository::DbPluginPreferenceUserData;
+use crate::plugins::data_db_repository::DbPreferenceEnumValue;
 use crate::plugins::data_db_repository::DbReadPlugin;
 use crate::plugins::data_db_repository::DbReadPluginEntrypoint;
 use crate::plugins::icon_cache::IconCache;
 use crate::plugins::image_gatherer::ImageGatherer;
+use crate::plugins::inspectors::PluginInspectorAddresses;
+use crate::plugins::json_schema::validate_value;
+use crate::plugins::plugin_functions::PluginFunctionCalls;
+use crate::plugins::plugin_permission_to_db;
 use crate::plugins::run_status::RunStatusGuard;
+use crate::plugins::run_status::RunStatusHolder;
+use crate::plugins::storage_db_repository::DbStorageOperation;
+use crate::plugins::storage_db_repository::PluginStorageRepository;
 use crate::search::SearchIndex;
 use crate::search::SearchIndexItem;
 use crate::search::SearchIndexItemAction;
@@ -89,6 +117,9 @@ use crate::search::SearchIndexItemActionActionType;
 use crate::PLUGIN_CONNECT_ENV;
 use crate::PLUGIN_UUID_ENV;
 
+// plugin function may need user interaction, e.g. login, so timeout is generous
+const PLUGIN_FUNCTION_CALL_TIMEOUT: Duration = Duration::from_secs(120);
+
 pub struct PluginRuntimeData {
     pub id: PluginId,
     pub uuid: String,
@@ -97,6 +128,7 @@ pub struct PluginRuntimeData {
     pub code: JsPluginCode,
     pub inline_view_entrypoint_id: Option<String>,
     pub permissions: PluginPermissions,
+    pub inspector: Option<PluginInspector>,
     pub command_receiver: tokio::sync::broadcast::Receiver<PluginCommand>,
     pub db_repository: DataDbRepository,
     pub search_index: SearchIndex,
@@ -104,6 +136,11 @@ pub struct PluginRuntimeData {
     pub frontend_api: FrontendApi,
     pub dirs: Dirs,
     pub clipboard: Clipboard,
+    pub command_sender: tokio::sync::broadcast::Sender<PluginCommand>,
+    pub plugin_function_calls: PluginFunctionCalls,
+    pub inspector_addresses: PluginInspectorAddresses,
+    pub run_status_holder: RunStatusHolder,
+    pub plugin_storage: PluginStorageRepository,
 }
 
 pub struct PluginPermissions {
@@ -114,11 +151,22 @@ pub struct PluginPermissions {
     pub system: Vec<String>,
     pub clipboard: Vec<PluginPermissionsClipboard>,
     pub main_search_bar: Vec<JsPluginPermissionsMainSearchBar>,
+    pub plugins: Vec<String>,
+    pub denied: JsPluginPermissionsDenied,
+    pub ask: bool,
+}
+
+#[derive(Clone, Debug)]
+pub struct PluginInspector {
+    // any free port if not specified
+    pub port: Option<u16>,
+    pub break_on_start: bool,
 }
 
 #[derive(Clone, Debug)]
 pub struct PluginRuntimePermissions {
     pub clipboard: Vec<PluginPermissionsClipboard>,
+    pub plugins: Vec<String>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
@@ -138,10 +186,14 @@ pub enum PluginCommand {
 pub enum OnePluginCommandData {
     RenderView {
         entrypoint_id: EntrypointId,
+        arguments: HashMap<String, String>,
+        input: Option<EntrypointInput>,
     },
     CloseView,
     RunCommand {
         entrypoint_id: String,
+        arguments: HashMap<String, String>,
+        input: Option<EntrypointInput>,
     },
     RunGeneratedEntrypoint {
         entrypoint_id: String,
@@ -162,6 +214,19 @@ pub enum OnePluginCommandData {
         modifier_meta: bool,
     },
     RefreshSearchIndex,
+    PreferencesChanged {
+        entrypoint_id: Option<EntrypointId>,
+    },
+    RunScheduledEntrypoint {
+        entrypoint_id: EntrypointId,
+    },
+    CallPluginFunction {
+        call_id: String,
+        caller_plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        function_id: String,
+        args: String,
+    },
 }
 
 #[derive(Clone, Debug)]
@@ -172,9 +237,16 @@ pub enum AllPluginCommandData {
 pub async fn start_plugin_runtime(data: PluginRuntimeData, run_status_guard: RunStatusGuard) -> anyhow::Result<()> {
     let runtime_permissions = PluginRuntimePermissions {
         clipboard: data.permissions.clipboard,
+        plugins: data.permissions.plugins,
     };
 
-    let api = BackendForPluginRuntimeApiImpl::new(
+    // inline view receives main search bar text on every keystroke, so access is recorded once per runtime start
+    // loader guarantees that plugins with inline view have main search bar read permission
+    if data.inline_view_entrypoint_id.is_some() {
+        add_audit_log_entry(&data.db_repository, &data.id, main_search_bar_read_permission(), true).await;
+    }
+
+    let api = Arc::new(BackendForPluginRuntimeApiImpl::new(
         data.icon_cache.clone(),
         data.db_repository,
         data.search_index,
@@ -184,7 +256,12 @@ pub async fn start_plugin_runtime(data: PluginRuntimeData, run_status_guard: Run
         data.id.clone(),
         data.name,
         runtime_permissions,
-    );
+        data.command_sender,
+        data.plugin_function_calls,
+        data.inspector_addresses,
+        data.run_status_holder,
+        data.plugin_storage,
+    ));
 
     let mut command_receiver = data.command_receiver;
     let cache = data.icon_cache;
@@ -284,6 +361,8 @@ pub async fn start_plugin_runtime(data: PluginRuntimeData, run_status_guard: Run
         exec: data.permissions.exec,
         system: data.permissions.system,
         main_search_bar: data.permissions.main_search_bar,
+        denied: data.permissions.denied,
+        ask: data.permissions.ask,
     };
 
     let init = JsInit {
@@ -294,6 +373,12 @@ pub async fn start_plugin_runtime(data: PluginRuntimeData, run_status_guard: Run
         inline_view_entrypoint_id: data.inline_view_entrypoint_id,
         entrypoint_names: data.entrypoint_names,
         dev_plugin,
+        inspector: data.inspector.map(|inspector| {
+            JsPluginInspector {
+                port: inspector.port,
+                break_on_start: inspector.break_on_start,
+            }
+        }),
         home_dir,
         local_storage_dir,
         plugin_cache_dir,
@@ -346,7 +431,8 @@ pub async fn start_plugin_runtime(data: PluginRuntimeData, run_status_guard: Run
             let plugin_id = plugin_id.clone();
             tokio::task::unconstrained(async move {
                 loop {
-                    if let Err(err) = event_loop(&mut command_receiver, &sender, plugin_id.clone()).await {
+                    let result = event_loop(&mut command_receiver, &sender, plugin_id.clone()).await;
+                    if let Err(err) = result {
                         tracing::error!("Event loop faced an error {:?}", err);
                         break;
                     }
@@ -380,6 +466,8 @@ pub async fn start_plugin_runtime(data: PluginRuntimeData, run_status_guard: Run
 
     drop((recver, sender));
 
+    api.plugin_function_calls.cancel_all(&plugin_id);
+
     #[cfg(not(feature = "scenario_runner"))]
     {
         let code = runtime_process
@@ -415,12 +503,28 @@ async fn event_loop(
                 None
             } else {
                 match data {
-                    OnePluginCommandData::RenderView { entrypoint_id } => {
-                        Some(IntermediateUiEvent::OpenView { entrypoint_id })
+                    OnePluginCommandData::RenderView {
+                        entrypoint_id,
+                        arguments,
+                        input,
+                    } => {
+                        Some(IntermediateUiEvent::OpenView {
+                            entrypoint_id,
+                            arguments,
+                            input,
+                        })
                     }
                     OnePluginCommandData::CloseView => Some(IntermediateUiEvent::CloseView),
-                    OnePluginCommandData::RunCommand { entrypoint_id } => {
-                        Some(IntermediateUiEvent::RunCommand { entrypoint_id })
+                    OnePluginCommandData::RunCommand {
+                        entrypoint_id,
+                        arguments,
+                        input,
+                    } => {
+                        Some(IntermediateUiEvent::RunCommand {
+                            entrypoint_id,
+                            arguments,
+                            input,
+                        })
                     }
                     OnePluginCommandData::RunGeneratedEntrypoint {
                         entrypoint_id,
@@ -462,6 +566,27 @@ async fn event_loop(
                         })
                     }
                     OnePluginCommandData::RefreshSearchIndex => Some(IntermediateUiEvent::RefreshSearchIndex),
+                    OnePluginCommandData::PreferencesChanged { entrypoint_id } => {
+                        Some(IntermediateUiEvent::PreferencesChanged { entrypoint_id })
+                    }
+                    OnePluginCommandData::RunScheduledEntrypoint { entrypoint_id } => {
+                        Some(IntermediateUiEvent::RunScheduledEntrypoint { entrypoint_id })
+                    }
+                    OnePluginCommandData::CallPluginFunction {
+                        call_id,
+                        caller_plugin_id,
+                        entrypoint_id,
+                        function_id,
+                        args,
+                    } => {
+                        Some(IntermediateUiEvent::CallPluginFunction {
+                            call_id,
+                            caller_plugin_id,
+                            entrypoint_id,
+                            function_id,
+                            args,
+                        })
+                    }
                 }
             }
         }
@@ -488,8 +613,8 @@ async fn event_loop(
 
 async fn request_loop(
     recv: &mut RecvHalf,
-    send: &Mutex<SendHalf>,
-    api: &BackendForPluginRuntimeApiImpl,
+    send: &Arc<Mutex<SendHalf>>,
+    api: &Arc<BackendForPluginRuntimeApiImpl>,
 ) -> anyhow::Result<bool> {
     match recv_message::<JsPluginRuntimeMessage>(JsMessageSide::Backend, recv).await {
         Err(e) => Err(anyhow!("Unable to handle message: {:?}", e)),
@@ -498,33 +623,50 @@ async fn request_loop(
 
             match message {
                 JsPluginRuntimeMessage::Stopped => Ok(true),
-                JsPluginRuntimeMessage::Request(message) => {
-                    match handle_message(message, api).await {
-                        Ok(response) => {
-                            let mut send = send.lock().await;
-
-                            tracing::trace!("Sending request response: {:?}", response);
-
-                            send_message(JsMessageSide::Backend, &mut send, JsMessage::Response(Ok(response))).await?;
-
-                            Ok(false)
+                JsPluginRuntimeMessage::Request {
+                    id,
+                    request: request @ JsRequest::CallPluginFunction { .. },
+                } => {
+                    // called plugin can itself call functions of this plugin, so other requests
+                    // need to be handled while waiting for the result, response is sent out of order
+                    tokio::spawn({
+                        let send = send.clone();
+                        let api = api.clone();
+                        async move {
+                            let response = handle_message(request, &api).await;
+
+                            if let Err(err) = send_response(id, response, &send).await {
+                                tracing::error!("Unable to send plugin function call response: {:?}", err);
+                            }
                         }
-                        Err(err) => {
-                            let mut send = send.lock().await;
+                    });
 
-                            let err = format!("{:?}", err);
+                    Ok(false)
+                }
+                JsPluginRuntimeMessage::Request { id, request } => {
+                    let response = handle_message(request, api).await;
 
-                            send_message(JsMessageSide::Backend, &mut send, JsMessage::Response(Err(err))).await?;
+                    send_response(id, response, send).await?;
 
-                            Ok(false)
-                        }
-                    }
+                    Ok(false)
                 }
             }
         }
     }
 }
 
+async fn send_response(id: u64, response: anyhow::Result<JsResponse>, send: &Mutex<SendHalf>) -> anyhow::Result<()> {
+    let mut send = send.lock().await;
+
+    let response = response.map_err(|err| format!("{:?}", err));
+
+    tracing::trace!("Sending request response: {:?}", response);
+
+    send_message(JsMessageSide::Backend, &mut send, JsMessage::Response { id, response }).await?;
+
+    Ok(())
+}
+
 async fn handle_message(message: JsRequest, api: &BackendForPluginRuntimeApiImpl) -> anyhow::Result<JsResponse> {
     match message {
         JsRequest::Render {
@@ -606,6 +748,15 @@ async fn handle_message(message: JsRequest, api: &BackendForPluginRuntimeApiImpl
 
             Ok(JsResponse::Nothing)
         }
+        JsRequest::UpdateSearchIndex {
+            upserted_entrypoints,
+            removed_entrypoint_ids,
+        } => {
+            api.update_search_index(upserted_entrypoints, removed_entrypoint_ids)
+                .await?;
+
+            Ok(JsResponse::Nothing)
+        }
         JsRequest::GetAssetData { path } => {
             let data = api.get_asset_data(&path).await?;
 
@@ -685,6 +836,64 @@ async fn handle_message(message: JsRequest, api: &BackendForPluginRuntimeApiImpl
         JsRequest::SynchronizeEvent => {
             api.ui_synchronize_event().await?;
 
+            Ok(JsResponse::Nothing)
+        }
+        JsRequest::PermissionPrompt { kind, resource } => {
+            let data = api.permission_prompt(kind, resource).await?;
+
+            Ok(JsResponse::PermissionPromptDecision { data })
+        }
+        JsRequest::AuditLog {
+            kind,
+            resource,
+            allowed,
+        } => {
+            api.audit_log(kind, resource, allowed).await?;
+
+            Ok(JsResponse::Nothing)
+        }
+        JsRequest::ScheduledRunFinished { entrypoint_id, error } => {
+            api.scheduled_run_finished(entrypoint_id, error).await?;
+
+            Ok(JsResponse::Nothing)
+        }
+        JsRequest::InspectorStarted { address } => {
+            api.inspector_started(address).await?;
+
+            Ok(JsResponse::Nothing)
+        }
+        JsRequest::CallPluginFunction {
+            plugin_id,
+            function_id,
+            args,
+        } => {
+            let data = api.call_plugin_function(plugin_id, function_id, args).await?;
+
+            Ok(JsResponse::PluginFunctionResult { data })
+        }
+        JsRequest::PluginFunctionCallFinished { call_id, result, error } => {
+            api.plugin_function_call_finished(call_id, result, error).await?;
+
+            Ok(JsResponse::Nothing)
+        }
+        JsRequest::StorageGet { namespace, key } => {
+            let data = api.storage_get(namespace, key).await?;
+
+            Ok(JsResponse::StorageValue { data })
+        }
+        JsRequest::StorageList { namespace, prefix } => {
+            let data = api.storage_list(namespace, prefix).await?;
+
+            Ok(JsResponse::StorageEntries { data })
+        }
+        JsRequest::StorageClear { namespace } => {
+            api.storage_clear(namespace).await?;
+
+            Ok(JsResponse::Nothing)
+        }
+        JsRequest::StorageApply { namespace, operations } => {
+            api.storage_apply(namespace, operations).await?;
+
             Ok(JsResponse::Nothing)
         }
     }
@@ -692,13 +901,29 @@ async fn handle_message(message: JsRequest, api: &BackendForPluginRuntimeApiImpl
 
 fn from_intermediate_to_js_event(event: IntermediateUiEvent) -> JsEvent {
     match event {
-        IntermediateUiEvent::OpenView { entrypoint_id } => {
+        IntermediateUiEvent::OpenView {
+            entrypoint_id,
+            arguments,
+            input,
+        } => {
             JsEvent::OpenView {
                 entrypoint_id: entrypoint_id.to_string(),
+                arguments,
+                input: input.map(entrypoint_input_to_js),
             }
         }
         IntermediateUiEvent::CloseView => JsEvent::CloseView,
-        IntermediateUiEvent::RunCommand { entrypoint_id } => JsEvent::RunCommand { entrypoint_id },
+        IntermediateUiEvent::RunCommand {
+            entrypoint_id,
+            arguments,
+            input,
+        } => {
+            JsEvent::RunCommand {
+                entrypoint_id,
+                arguments,
+                input: input.map(entrypoint_input_to_js),
+            }
+        }
         IntermediateUiEvent::RunGeneratedEntrypoint {
             entrypoint_id,
             action_index,
@@ -713,20 +938,7 @@ fn from_intermediate_to_js_event(event: IntermediateUiEvent) -> JsEvent {
             event_name,
             event_arguments,
         } => {
-            let event_arguments = event_arguments
-                .into_iter()
-                .map(|arg| {
-                    match arg {
-                        UiPropertyValue::String(value) => JsUiPropertyValue::String { value },
-                        UiPropertyValue::Number(value) => JsUiPropertyValue::Number { value },
-                        UiPropertyValue::Bool(value) => JsUiPropertyValue::Bool { value },
-                        UiPropertyValue::Undefined => JsUiPropertyValue::Undefined,
-                        UiPropertyValue::Array(_) | UiPropertyValue::Bytes(_) | UiPropertyValue::Object(_) => {
-                            todo!()
-                        }
-                    }
-                })
-                .collect();
+            let event_arguments = event_arguments.into_iter().map(ui_property_value_to_js).collect();
 
             JsEvent::ViewEvent {
                 widget_id,
@@ -758,6 +970,31 @@ fn from_intermediate_to_js_event(event: IntermediateUiEvent) -> JsEvent {
         }
         IntermediateUiEvent::OpenInlineView { text } => JsEvent::OpenInlineView { text },
         IntermediateUiEvent::RefreshSearchIndex => JsEvent::RefreshSearchIndex,
+        IntermediateUiEvent::PreferencesChanged { entrypoint_id } => {
+            JsEvent::PreferencesChanged {
+                entrypoint_id: entrypoint_id.map(|id| id.to_string()),
+            }
+        }
+        IntermediateUiEvent::RunScheduledEntrypoint { entrypoint_id } => {
+            JsEvent::RunScheduledEntrypoint {
+                entrypoint_id: entrypoint_id.to_string(),
+            }
+        }
+        IntermediateUiEvent::CallPluginFunction {
+            call_id,
+            caller_plugin_id,
+            entrypoint_id,
+            function_id,
+            args,
+        } => {
+            JsEvent::CallPluginFunction {
+                call_id,
+                caller_plugin_id: caller_plugin_id.to_string(),
+                entrypoint_id: entrypoint_id.to_string(),
+                function_id,
+                args,
+            }
+        }
     }
 }
 
@@ -772,6 +1009,11 @@ pub struct BackendForPluginRuntimeApiImpl {
     plugin_id: PluginId,
     plugin_name: String,
     permissions: PluginRuntimePermissions,
+    command_sender: tokio::sync::broadcast::Sender<PluginCommand>,
+    plugin_function_calls: PluginFunctionCalls,
+    inspector_addresses: PluginInspectorAddresses,
+    run_status_holder: RunStatusHolder,
+    plugin_storage: PluginStorageRepository,
 }
 
 impl BackendForPluginRuntimeApiImpl {
@@ -785,6 +1027,11 @@ impl BackendForPluginRuntimeApiImpl {
         plugin_id: PluginId,
         plugin_name: String,
         permissions: PluginRuntimePermissions,
+        command_sender: tokio::sync::broadcast::Sender<PluginCommand>,
+        plugin_function_calls: PluginFunctionCalls,
+        inspector_addresses: PluginInspectorAddresses,
+        run_status_holder: RunStatusHolder,
+        plugin_storage: PluginStorageRepository,
     ) -> Self {
         Self {
             icon_cache,
@@ -796,6 +1043,11 @@ impl BackendForPluginRuntimeApiImpl {
             plugin_id,
             plugin_name,
             permissions,
+            command_sender,
+            plugin_function_calls,
+            inspector_addresses,
+            run_status_holder,
+            plugin_storage,
         }
     }
 }
@@ -847,68 +1099,8 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiImpl {
 
         let mut generated_search_items = generated_entrypoints
             .into_iter()
-            .map(|item| {
-                let entrypoint_icon = match item.entrypoint_icon {
-                    None => None,
-                    Some(data) => Some(bytes::Bytes::from(data)),
-                };
-
-                let entrypoint_frecency = frecency_map.get(&item.entrypoint_id).cloned().unwrap_or(0.0);
-
-                let shortcuts = shortcuts.get(&item.generator_entrypoint_id);
-
-                let entrypoint_actions = item
-                    .entrypoint_actions
-                    .iter()
-                    .map(|action| {
-                        let shortcut = match (shortcuts, &action.id) {
-                            (Some(shortcuts), Some(id)) => shortcuts.get(id).cloned(),
-                            _ => None,
-                        };
-
-                        SearchIndexItemAction {
-                            id: action.id.clone(),
-                            label: action.label.clone(),
-                            action_type: match action.action_type {
-                                JsGeneratedSearchItemActionType::View => SearchIndexItemActionActionType::View,
-                                JsGeneratedSearchItemActionType::Command => SearchIndexItemActionActionType::Command,
-                            },
-                            shortcut,
-                        }
-                    })
-                    .collect();
-
-                let entrypoint_accessories = item
-                    .entrypoint_accessories
-                    .into_iter()
-                    .map(|accessory| {
-                        match accessory {
-                            JsGeneratedSearchItemAccessory::TextAccessory { text, icon, tooltip } => {
-                                SearchResultAccessory::TextAccessory { text, icon, tooltip }
-                            }
-                            JsGeneratedSearchItemAccessory::IconAccessory { icon, tooltip } => {
-                                SearchResultAccessory::IconAccessory { icon, tooltip }
-                            }
-                        }
-                    })
-                    .collect();
-
-                let entrypoint_generator_name = generator_names
-                    .get(&item.generator_entrypoint_id)
-                    .map(|name| name.to_string());
-
-                Ok(SearchIndexItem {
-                    entrypoint_type: SearchResultEntrypointType::Generated,
-                    entrypoint_id: EntrypointId::from_string(item.entrypoint_id),
-                    entrypoint_name: item.entrypoint_name,
-                    entrypoint_icon,
-                    entrypoint_frecency,
-                    entrypoint_actions,
-                    entrypoint_accessories,
-                    entrypoint_generator_name,
-                })
-            })
-            .collect::<anyhow::Result<Vec<_>>>()?;
+            .map(|item| generated_search_item(item, &frecency_map, &shortcuts, &generator_names))
+            .collect::<Vec<_>>();
 
         let mut icon_asset_data = HashMap::new();
 
@@ -946,6 +1138,60 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiImpl {
 
                 let entrypoint_id = EntrypointId::from_string(entrypoint_id);
 
+                let entrypoint_accepts_input = entrypoint
+                    .accepts_input
+                    .into_iter()
+                    .map(|kind| {
+                        match kind {
+                            DbPluginEntrypointInputKind::Text => SearchResultEntrypointInputKind::Text,
+                            DbPluginEntrypointInputKind::Image => SearchResultEntrypointInputKind::Image,
+                        }
+                    })
+                    .collect::<Vec<_>>();
+
+                let entrypoint_arguments = entrypoint
+                    .arguments
+                    .into_iter()
+                    .map(|argument| {
+                        match argument {
+                            DbPluginArgument::Text { id, name, required } => {
+                                SearchResultEntrypointArgument {
+                                    id,
+                                    name,
+                                    required,
+                                    kind: SearchResultEntrypointArgumentKind::Text,
+                                }
+                            }
+                            DbPluginArgument::Password { id, name, required } => {
+                                SearchResultEntrypointArgument {
+                                    id,
+                                    name,
+                                    required,
+                                    kind: SearchResultEntrypointArgumentKind::Password,
+                                }
+                            }
+                            DbPluginArgument::Dropdown {
+                                id,
+                                name,
+                                required,
+                                values,
+                            } => {
+                                let values = values
+                                    .into_iter()
+                                    .map(|DbPreferenceEnumValue { label, value }| PreferenceEnumValue { label, value })
+                                    .collect();
+
+                                SearchResultEntrypointArgument {
+                                    id,
+                                    name,
+                                    required,
+                                    kind: SearchResultEntrypointArgumentKind::Dropdown { values },
+                                }
+                            }
+                        }
+                    })
+                    .collect();
+
                 match &entrypoint_type {
                     DbPluginEntrypointType::Command => {
                         Ok(Some(SearchIndexItem {
@@ -957,6 +1203,8 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiImpl {
                             entrypoint_frecency,
                             entrypoint_actions: vec![],
                             entrypoint_accessories: vec![],
+                            entrypoint_arguments,
+                            entrypoint_accepts_input,
                         }))
                     }
                     DbPluginEntrypointType::View => {
@@ -969,6 +1217,8 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiImpl {
                             entrypoint_frecency,
                             entrypoint_actions: vec![],
                             entrypoint_accessories: vec![],
+                            entrypoint_arguments,
+                            entrypoint_accepts_input,
                         }))
                     }
                     DbPluginEntrypointType::EntrypointGenerator | DbPluginEntrypointType::InlineView => Ok(None),
@@ -993,6 +1243,74 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiImpl {
         Ok(())
     }
 
+    async fn update_search_index(
+        &self,
+        upserted_entrypoints: Vec<JsGeneratedSearchItem>,
+        removed_entrypoint_ids: Vec<String>,
+    ) -> anyhow::Result<()> {
+        let DbReadPlugin { name, .. } = self
+            .repository
+            .get_plugin_by_id(&self.plugin_id.to_string())
+            .await
+            .context("error when getting plugin by id")?;
+
+        let frecency_map = self
+            .repository
+            .get_frecency_for_plugin(&self.plugin_id.to_string())
+            .await
+            .context("error when getting frecency for plugin")?;
+
+        let generator_names: HashMap<_, _> = self
+            .repository
+            .get_entrypoints_by_plugin_id(&self.plugin_id.to_string())
+            .await
+            .context("error when getting entrypoints by plugin id")?
+            .into_iter()
+            .filter(|entrypoint| {
+                matches!(
+                    db_entrypoint_from_str(&entrypoint.entrypoint_type),
+                    DbPluginEntrypointType::EntrypointGenerator
+                )
+            })
+            .map(|entrypoint| (entrypoint.id, entrypoint.name))
+            .collect();
+
+        // only shortcuts of generators which produced changed entrypoints are needed
+        let mut shortcuts = HashMap::new();
+
+        for item in &upserted_entrypoints {
+            if !shortcuts.contains_key(&item.generator_entrypoint_id) {
+                let entrypoint_shortcuts = self
+                    .repository
+                    .action_shortcuts(&self.plugin_id.to_string(), &item.generator_entrypoint_id)
+                    .await?;
+
+                shortcuts.insert(item.generator_entrypoint_id.clone(), entrypoint_shortcuts);
+            }
+        }
+
+        let upserted_search_items = upserted_entrypoints
+            .into_iter()
+            .map(|item| generated_search_item(item, &frecency_map, &shortcuts, &generator_names))
+            .collect();
+
+        let removed_entrypoint_ids = removed_entrypoint_ids
+            .into_iter()
+            .map(|entrypoint_id| EntrypointId::from_string(entrypoint_id))
+            .collect();
+
+        self.search_index
+            .update_for_plugin(
+                self.plugin_id.clone(),
+                name,
+                upserted_search_items,
+                removed_entrypoint_ids,
+            )
+            .context("error when updating search index")?;
+
+        Ok(())
+    }
+
     async fn get_asset_data(&self, path: &str) -> anyhow::Result<Vec<u8>> {
         let data = self
             .repository
@@ -1073,6 +1391,8 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiImpl {
     async fn clipboard_read(&self) -> anyhow::Result<JsClipboardData> {
         let allow = self.permissions.clipboard.contains(&PluginPermissionsClipboard::Read);
 
+        add_audit_log_entry(&self.repository, &self.plugin_id, clipboard_read_permission(), allow).await;
+
         if !allow {
             return Err(anyhow!("Plugin doesn't have 'read' permission for clipboard"));
         }
@@ -1085,6 +1405,8 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiImpl {
     async fn clipboard_read_text(&self) -> anyhow::Result<Option<String>> {
         let allow = self.permissions.clipboard.contains(&PluginPermissionsClipboard::Read);
 
+        add_audit_log_entry(&self.repository, &self.plugin_id, clipboard_read_permission(), allow).await;
+
         if !allow {
             return Err(anyhow!("Plugin doesn't have 'read' permission for clipboard"));
         }
@@ -1242,6 +1564,431 @@ impl BackendForPluginRuntimeApi for BackendForPluginRuntimeApiImpl {
 
         Ok(())
     }
+
+    async fn permission_prompt(
+        &self,
+        kind: JsPermissionPromptKind,
+        resource: String,
+    ) -> anyhow::Result<JsPermissionPromptDecision> {
+        let permission = PluginPermission {
+            kind: permission_kind_from_js(kind),
+            resource,
+        };
+
+        tracing::debug!(
+            "Prompting user for permission {:?}, plugin id: {:?}",
+            permission,
+            self.plugin_id
+        );
+
+        let decision = self
+            .frontend_api
+            .show_permission_prompt(self.plugin_id.clone(), self.plugin_name.clone(), permission.clone())
+            .await?;
+
+        let allowed = !matches!(decision, PermissionPromptDecision::Deny);
+
+        add_audit_log_entry(&self.repository, &self.plugin_id, permission.clone(), allowed).await;
+
+        let decision = match decision {
+            PermissionPromptDecision::AllowOnce => JsPermissionPromptDecision::AllowOnce,
+            PermissionPromptDecision::AllowAlways => {
+                self.repository
+                    .add_granted_permission(&self.plugin_id.to_string(), plugin_permission_to_db(permission))
+                    .await
+                    .context("error when saving granted permission")?;
+
+                JsPermissionPromptDecision::AllowAlways
+            }
+            PermissionPromptDecision::Deny => JsPermissionPromptDecision::Deny,
+        };
+
+        Ok(decision)
+    }
+
+    async fn audit_log(&self, kind: JsPermissionPromptKind, resource: String, allowed: bool) -> anyhow::Result<()> {
+        let permission = PluginPermission {
+            kind: permission_kind_from_js(kind),
+            resource,
+        };
+
+        add_audit_log_entry(&self.repository, &self.plugin_id, permission, allowed).await;
+
+        Ok(())
+    }
+
+    async fn scheduled_run_finished(&self, entrypoint_id: EntrypointId, error: Option<String>) -> anyhow::Result<()> {
+        if let Some(error) = &error {
+            tracing::warn!(
+                "Scheduled run of entrypoint {:?} of plugin {:?} failed: {}",
+                entrypoint_id,
+                self.plugin_id,
+                error
+            );
+        }
+
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_millis() as i64)
+            .unwrap_or_default();
+
+        self.repository
+            .set_scheduled_run_result(
+                &self.plugin_id.to_string(),
+                &entrypoint_id.to_string(),
+                timestamp,
+                error,
+            )
+            .await
+    }
+
+    async fn inspector_started(&self, address: String) -> anyhow::Result<()> {
+        tracing::info!(
+            "Inspector server of plugin {:?} is listening on {}",
+            self.plugin_id,
+            address
+        );
+
+        self.inspector_addresses.started(&self.plugin_id, address);
+
+        Ok(())
+    }
+
+    async fn call_plugin_function(
+        &self,
+        plugin_id: PluginId,
+        function_id: String,
+        args: String,
+    ) -> anyhow::Result<String> {
+        let allow = self.permissions.plugins.contains(&plugin_id.to_string());
+
+        add_audit_log_entry(&self.repository, &self.plugin_id, plugins_permission(&plugin_id), allow).await;
+
+        if !allow {
+            return Err(anyhow!(
+                "Plugin doesn't have permission to call functions of plugin: {}",
+                plugin_id
+            ));
+        }
+
+        // plugin can call its own functions directly, without going through backend
+        if plugin_id == self.plugin_id {
+            return Err(anyhow!("Plugin cannot call functions exported by itself"));
+        }
+
+        let Some(plugin) = self.repository.get_plugin_by_id_option(&plugin_id.to_string()).await? else {
+            return Err(anyhow!("Plugin is not installed: {}", plugin_id));
+        };
+
+        if !plugin.enabled {
+            return Err(anyhow!("Plugin is not enabled: {}", plugin_id));
+        }
+
+        // otherwise the call would only fail after timeout
+        if !self.run_status_holder.is_plugin_running(&plugin_id) {
+            return Err(anyhow!("Plugin is not running: {}", plugin_id));
+        }
+
+        let Some(export) = plugin.exports.into_iter().find(|export| export.id == function_id) else {
+            return Err(anyhow!("Plugin {} doesn't export function: {}", plugin_id, function_id));
+        };
+
+        if let Some(schema) = &export.input_schema {
+            let value = serde_json::from_str(&args).context("Plugin function arguments are not a valid json")?;
+
+            validate_value(schema, &value).with_context(|| {
+                format!(
+                    "Arguments of function '{}' of plugin {} don't match its schema",
+                    function_id, plugin_id
+                )
+            })?;
+        }
+
+        tracing::debug!(
+            "Calling function '{}' of plugin {:?}, caller plugin id: {:?}",
+            function_id,
+            plugin_id,
+            self.plugin_id
+        );
+
+        let (call_id, receiver) = self.plugin_function_calls.start(plugin_id.clone());
+
+        // sending can only fail if there are no running plugins, and caller itself is running
+        let _ = self.command_sender.send(PluginCommand::One {
+            id: plugin_id.clone(),
+            data: OnePluginCommandData::CallPluginFunction {
+                call_id: call_id.clone(),
+                caller_plugin_id: self.plugin_id.clone(),
+                entrypoint_id: EntrypointId::from_string(export.entrypoint_id),
+                function_id: function_id.clone(),
+                args,
+            },
+        });
+
+        let result = match tokio::time::timeout(PLUGIN_FUNCTION_CALL_TIMEOUT, receiver).await {
+            Ok(Ok(result)) => result,
+            Ok(Err(_)) => {
+                return Err(anyhow!(
+                    "Function '{}' of plugin {} was cancelled",
+                    function_id,
+                    plugin_id
+                ))
+            }
+            Err(_) => {
+                self.plugin_function_calls.cancel(&call_id);
+
+                return Err(anyhow!(
+                    "Function '{}' of plugin {} didn't finish in time",
+                    function_id,
+                    plugin_id
+                ));
+            }
+        };
+
+        let result =
+            result.map_err(|err| anyhow!("Function '{}' of plugin {} failed: {}", function_id, plugin_id, err))?;
+
+        if let Some(schema) = &export.output_schema {
+            let value = serde_json::from_str(&result).context("Plugin function result is not a valid json")?;
+
+            validate_value(schema, &value).with_context(|| {
+                format!(
+                    "Result of function '{}' of plugin {} doesn't match its schema",
+                    function_id, plugin_id
+                )
+            })?;
+        }
+
+        Ok(result)
+    }
+
+    async fn plugin_function_call_finished(
+        &self,
+        call_id: String,
+        result: Option<String>,
+        error: Option<String>,
+    ) -> anyhow::Result<()> {
+        let result = match error {
+            Some(error) => Err(error),
+            None => Ok(result.unwrap_or_else(|| "null".to_string())),
+        };
+
+        self.plugin_function_calls.finish(&self.plugin_id, &call_id, result)
+    }
+
+    async fn storage_get(&self, namespace: String, key: String) -> anyhow::Result<Option<String>> {
+        self.plugin_storage.get(&self.plugin_uuid, &namespace, &key).await
+    }
+
+    async fn storage_list(&self, namespace: String, prefix: String) -> anyhow::Result<Vec<JsStorageEntry>> {
+        let entries = self
+            .plugin_storage
+            .list(&self.plugin_uuid, &namespace, &prefix)
+            .await?
+            .into_iter()
+            .map(|entry| {
+                JsStorageEntry {
+                    key: entry.key,
+                    value: entry.value,
+                }
+            })
+            .collect();
+
+        Ok(entries)
+    }
+
+    async fn storage_clear(&self, namespace: String) -> anyhow::Result<()> {
+        self.plugin_storage.clear_namespace(&self.plugin_uuid, &namespace).await
+    }
+
+    async fn storage_apply(&self, namespace: String, operations: Vec<JsStorageOperation>) -> anyhow::Result<()> {
+        let operations = operations
+            .into_iter()
+            .map(|operation| {
+                match operation {
+                    JsStorageOperation::Set {
+                        key,
+                        value,
+                        ttl_seconds,
+                    } => {
+                        DbStorageOperation::Set {
+                            key,
+                            value,
+                            ttl_seconds,
+                        }
+                    }
+                    JsStorageOperation::Delete { key } => DbStorageOperation::Delete { key },
+                }
+            })
+            .collect();
+
+        self.plugin_storage
+            .apply(&self.plugin_uuid, &namespace, operations)
+            .await
+    }
+}
+
+fn generated_search_item(
+    item: JsGeneratedSearchItem,
+    frecency_map: &HashMap<String, f64>,
+    shortcuts: &HashMap<String, HashMap<String, PhysicalShortcut>>,
+    generator_names: &HashMap<String, String>,
+) -> SearchIndexItem {
+    let entrypoint_icon = match item.entrypoint_icon {
+        None => None,
+        Some(data) => Some(bytes::Bytes::from(data)),
+    };
+
+    let entrypoint_frecency = frecency_map.get(&item.entrypoint_id).cloned().unwrap_or(0.0);
+
+    let shortcuts = shortcuts.get(&item.generator_entrypoint_id);
+
+    let entrypoint_actions = item
+        .entrypoint_actions
+        .iter()
+        .map(|action| {
+            let shortcut = match (shortcuts, &action.id) {
+                (Some(shortcuts), Some(id)) => shortcuts.get(id).cloned(),
+                _ => None,
+            };
+
+            SearchIndexItemAction {
+                id: action.id.clone(),
+                label: action.label.clone(),
+                action_type: match action.action_type {
+                    JsGeneratedSearchItemActionType::View => SearchIndexItemActionActionType::View,
+                    JsGeneratedSearchItemActionType::Command => SearchIndexItemActionActionType::Command,
+                },
+                shortcut,
+            }
+        })
+        .collect();
+
+    let entrypoint_accessories = item
+        .entrypoint_accessories
+        .into_iter()
+        .map(|accessory| {
+            match accessory {
+                JsGeneratedSearchItemAccessory::TextAccessory { text, icon, tooltip } => {
+                    SearchResultAccessory::TextAccessory { text, icon, tooltip }
+                }
+                JsGeneratedSearchItemAccessory::IconAccessory { icon, tooltip } => {
+                    SearchResultAccessory::IconAccessory { icon, tooltip }
+                }
+            }
+        })
+        .collect();
+
+    let entrypoint_generator_name = generator_names
+        .get(&item.generator_entrypoint_id)
+        .map(|name| name.to_string());
+
+    SearchIndexItem {
+        entrypoint_type: SearchResultEntrypointType::Generated,
+        entrypoint_id: EntrypointId::from_string(item.entrypoint_id),
+        entrypoint_name: item.entrypoint_name,
+        entrypoint_icon,
+        entrypoint_frecency,
+        entrypoint_actions,
+        entrypoint_accessories,
+        entrypoint_generator_name,
+        entrypoint_arguments: vec![],
+        entrypoint_accepts_input: vec![],
+    }
+}
+
+fn ui_property_value_to_js(value: UiPropertyValue) -> JsUiPropertyValue {
+    match value {
+        UiPropertyValue::String(value) => JsUiPropertyValue::String { value },
+        UiPropertyValue::Number(value) => JsUiPropertyValue::Number { value },
+        UiPropertyValue::Bool(value) => JsUiPropertyValue::Bool { value },
+        UiPropertyValue::Array(values) => {
+            JsUiPropertyValue::Array {
+                values: values.into_iter().map(ui_property_value_to_js).collect(),
+            }
+        }
+        UiPropertyValue::Object(values) => {
+            JsUiPropertyValue::Object {
+                values: values
+                    .into_iter()
+                    .map(|(key, value)| (key, ui_property_value_to_js(value)))
+                    .collect(),
+            }
+        }
+        UiPropertyValue::Undefined => JsUiPropertyValue::Undefined,
+        UiPropertyValue::Bytes(_) => {
+            // no form field produces bytes
+            tracing::warn!("Bytes property value cannot be passed to plugin, replacing with undefined");
+
+            JsUiPropertyValue::Undefined
+        }
+    }
+}
+
+fn entrypoint_input_to_js(input: EntrypointInput) -> JsEntrypointInput {
+    JsEntrypointInput {
+        text: input.text,
+        png_data: input.png_data,
+    }
+}
+
+fn permission_kind_from_js(kind: JsPermissionPromptKind) -> PluginPermissionKind {
+    match kind {
+        JsPermissionPromptKind::Environment => PluginPermissionKind::Environment,
+        JsPermissionPromptKind::Network => PluginPermissionKind::Network,
+        JsPermissionPromptKind::FilesystemRead => PluginPermissionKind::FilesystemRead,
+        JsPermissionPromptKind::FilesystemWrite => PluginPermissionKind::FilesystemWrite,
+        JsPermissionPromptKind::ExecCommand => PluginPermissionKind::ExecCommand,
+        JsPermissionPromptKind::ExecExecutable => PluginPermissionKind::ExecExecutable,
+        JsPermissionPromptKind::System => PluginPermissionKind::System,
+    }
+}
+
+pub(super) fn clipboard_read_permission() -> PluginPermission {
+    PluginPermission {
+        kind: PluginPermissionKind::Clipboard,
+        resource: "read".to_string(),
+    }
+}
+
+fn main_search_bar_read_permission() -> PluginPermission {
+    PluginPermission {
+        kind: PluginPermissionKind::MainSearchBar,
+        resource: "read".to_string(),
+    }
+}
+
+fn plugins_permission(plugin_id: &PluginId) -> PluginPermission {
+    PluginPermission {
+        kind: PluginPermissionKind::Plugins,
+        resource: plugin_id.to_string(),
+    }
+}
+
+// failing to record an entry should not prevent plugin from working
+pub(super) async fn add_audit_log_entry(
+    repository: &DataDbRepository,
+    plugin_id: &PluginId,
+    permission: PluginPermission,
+    allowed: bool,
+) {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_millis() as i64)
+        .unwrap_or_default();
+
+    let result = repository
+        .add_audit_log_entry(
+            &plugin_id.to_string(),
+            timestamp,
+            plugin_permission_to_db(permission),
+            allowed,
+        )
+        .await;
+
+    if let Err(err) = result {
+        tracing::error!("Unable to add audit log entry for plugin {:?}: {:?}", plugin_id, err);
+    }
 }
 
 fn preferences_to_js(
@@ -0,0 +1,289 @@
+use anyhow::anyhow;
+use serde_json::Map;
+use serde_json::Value;
+
+// only a subset of json schema is supported:
+// "type", "enum", "properties", "required", "additionalProperties" and "items"
+const VALIDATION_KEYWORDS: [&str; 6] = [
+    "type",
+    "enum",
+    "properties",
+    "required",
+    "additionalProperties",
+    "items",
+];
+
+// do not affect validation, so can be safely ignored
+const ANNOTATION_KEYWORDS: [&str; 5] = ["$schema", "title", "description", "default", "examples"];
+
+pub fn validate_schema(schema: &Value) -> anyhow::Result<()> {
+    let Value::Object(schema) = schema else {
+        return Err(anyhow!("Schema should be an object"));
+    };
+
+    // unsupported keywords would be silently ignored, which would make validation less strict than declared
+    for keyword in schema.keys() {
+        if !VALIDATION_KEYWORDS.contains(&keyword.as_str()) && !ANNOTATION_KEYWORDS.contains(&keyword.as_str()) {
+            return Err(anyhow!("Schema keyword '{}' is not supported", keyword));
+        }
+    }
+
+    if let Some(types) = schema.get("type") {
+        for schema_type in schema_types(types)? {
+            match schema_type {
+                "string" | "number" | "integer" | "boolean" | "object" | "array" | "null" => {}
+                schema_type @ _ => return Err(anyhow!("Schema has unknown type '{}'", schema_type)),
+            }
+        }
+    }
+
+    if let Some(values) = schema.get("enum") {
+        if !values.is_array() {
+            return Err(anyhow!("Schema 'enum' should be an array"));
+        }
+    }
+
+    if let Some(properties) = schema.get("properties") {
+        let Value::Object(properties) = properties else {
+            return Err(anyhow!("Schema 'properties' should be an object"));
+        };
+
+        for (name, property) in properties {
+            validate_schema(property).map_err(|err| anyhow!("Property '{}': {}", name, err))?;
+        }
+    }
+
+    if let Some(required) = schema.get("required") {
+        let valid = required
+            .as_array()
+            .is_some_and(|required| required.iter().all(|name| name.is_string()));
+
+        if !valid {
+            return Err(anyhow!("Schema 'required' should be an array of strings"));
+        }
+    }
+
+    if let Some(additional_properties) = schema.get("additionalProperties") {
+        if !additional_properties.is_boolean() {
+            return Err(anyhow!("Schema 'additionalProperties' should be a boolean"));
+        }
+    }
+
+    if let Some(items) = schema.get("items") {
+        validate_schema(items).map_err(|err| anyhow!("Items: {}", err))?;
+    }
+
+    Ok(())
+}
+
+// expects schema that already passed validate_schema
+pub fn validate_value(schema: &Value, value: &Value) -> anyhow::Result<()> {
+    validate_value_at(schema, value, "$")
+}
+
+fn validate_value_at(schema: &Value, value: &Value, path: &str) -> anyhow::Result<()> {
+    let Value::Object(schema) = schema else {
+        return Ok(());
+    };
+
+    if let Some(types) = schema.get("type") {
+        let types = schema_types(types)?;
+
+        if !types.iter().any(|schema_type| value_has_type(value, schema_type)) {
+            return Err(anyhow!(
+                "Value at '{}' should be of type '{}'",
+                path,
+                types.join("' or '")
+            ));
+        }
+    }
+
+    if let Some(Value::Array(values)) = schema.get("enum") {
+        if !values.contains(value) {
+            return Err(anyhow!("Value at '{}' is not one of allowed values", path));
+        }
+    }
+
+    match value {
+        Value::Object(object) => validate_object(schema, object, path)?,
+        Value::Array(array) => {
+            if let Some(items) = schema.get("items") {
+                for (index, item) in array.iter().enumerate() {
+                    validate_value_at(items, item, &format!("{}[{}]", path, index))?;
+                }
+            }
+        }
+        Value::Null | Value::Bool(_) | Value::Number(_) | Value::String(_) => {}
+    }
+
+    Ok(())
+}
+
+fn validate_object(schema: &Map<String, Value>, object: &Map<String, Value>, path: &str) -> anyhow::Result<()> {
+    let properties = schema.get("properties").and_then(|properties| properties.as_object());
+
+    if let Some(Value::Array(required)) = schema.get("required") {
+        for name in required.iter().filter_map(|name| name.as_str()) {
+            if !object.contains_key(name) {
+                return Err(anyhow!("Value at '{}' is missing required property '{}'", path, name));
+            }
+        }
+    }
+
+    let additional_properties = schema
+        .get("additionalProperties")
+        .and_then(|value| value.as_bool())
+        .unwrap_or(true);
+
+    for (name, value) in object {
+        let property_path = format!("{}.{}", path, name);
+
+        match properties.and_then(|properties| properties.get(name)) {
+            Some(property) => validate_value_at(property, value, &property_path)?,
+            None => {
+                if !additional_properties {
+                    return Err(anyhow!("Value at '{}' is not allowed", property_path));
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn schema_types(types: &Value) -> anyhow::Result<Vec<&str>> {
+    match types {
+        Value::String(schema_type) => Ok(vec![schema_type.as_str()]),
+        Value::Array(types) => {
+            types
+                .iter()
+                .map(|schema_type| {
+                    schema_type
+                        .as_str()
+                        .ok_or_else(|| anyhow!("Schema 'type' should be a string or an array of strings"))
+                })
+                .collect()
+        }
+        _ => Err(anyhow!("Schema 'type' should be a string or an array of strings")),
+    }
+}
+
+fn value_has_type(value: &Value, schema_type: &str) -> bool {
+    match schema_type {
+        "string" => value.is_string(),
+        "number" => value.is_number(),
+        "integer" => value.is_i64() || value.is_u64() || value.as_f64().is_some_and(|number| number.fract() == 0.0),
+        "boolean" => value.is_boolean(),
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        "null" => value.is_null(),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn valid_schema() {
+        let schema = json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "description": "Auth token request",
+            "type": "object",
+            "properties": {
+                "scope": { "type": "string", "enum": ["read", "write"] },
+                "ids": { "type": "array", "items": { "type": ["integer", "null"] } }
+            },
+            "required": ["scope"],
+            "additionalProperties": false
+        });
+
+        assert!(validate_schema(&schema).is_ok());
+    }
+
+    #[test]
+    fn unsupported_keywords() {
+        assert!(validate_schema(&json!({ "type": "string", "pattern": "^a" })).is_err());
+        assert!(validate_schema(&json!({ "type": "number", "minimum": 0 })).is_err());
+        assert!(validate_schema(&json!({ "oneOf": [{ "type": "string" }] })).is_err());
+        assert!(validate_schema(&json!({ "properties": { "a": { "$ref": "#/definitions/a" } } })).is_err());
+        assert!(validate_schema(&json!({ "items": { "maxLength": 1 } })).is_err());
+    }
+
+    #[test]
+    fn invalid_keyword_values() {
+        assert!(validate_schema(&json!("string")).is_err());
+        assert!(validate_schema(&json!({ "type": "text" })).is_err());
+        assert!(validate_schema(&json!({ "type": ["string", 1] })).is_err());
+        assert!(validate_schema(&json!({ "enum": "a" })).is_err());
+        assert!(validate_schema(&json!({ "properties": [] })).is_err());
+        assert!(validate_schema(&json!({ "required": ["a", 1] })).is_err());
+        assert!(validate_schema(&json!({ "additionalProperties": {} })).is_err());
+    }
+
+    #[test]
+    fn value_types() {
+        assert!(validate_value(&json!({ "type": "string" }), &json!("a")).is_ok());
+        assert!(validate_value(&json!({ "type": "string" }), &json!(1)).is_err());
+        assert!(validate_value(&json!({ "type": "integer" }), &json!(1)).is_ok());
+        assert!(validate_value(&json!({ "type": "integer" }), &json!(1.0)).is_ok());
+        assert!(validate_value(&json!({ "type": "integer" }), &json!(1.5)).is_err());
+        assert!(validate_value(&json!({ "type": ["string", "null"] }), &json!(null)).is_ok());
+        assert!(validate_value(&json!({ "type": ["string", "null"] }), &json!(false)).is_err());
+        assert!(validate_value(&json!({}), &json!({ "a": [1] })).is_ok());
+    }
+
+    #[test]
+    fn value_enum() {
+        let schema = json!({ "enum": ["read", 1] });
+
+        assert!(validate_value(&schema, &json!("read")).is_ok());
+        assert!(validate_value(&schema, &json!(1)).is_ok());
+        assert!(validate_value(&schema, &json!("write")).is_err());
+    }
+
+    #[test]
+    fn value_object() {
+        let schema = json!({
+            "type": "object",
+            "properties": { "scope": { "type": "string" } },
+            "required": ["scope"],
+            "additionalProperties": false
+        });
+
+        assert!(validate_value(&schema, &json!({ "scope": "read" })).is_ok());
+        assert!(validate_value(&schema, &json!({})).is_err());
+        assert!(validate_value(&schema, &json!({ "scope": 1 })).is_err());
+        assert!(validate_value(&schema, &json!({ "scope": "read", "other": 1 })).is_err());
+
+        let schema = json!({ "properties": { "scope": { "type": "string" } } });
+
+        assert!(validate_value(&schema, &json!({ "other": 1 })).is_ok());
+    }
+
+    #[test]
+    fn value_array() {
+        let schema = json!({ "type": "array", "items": { "type": "integer" } });
+
+        assert!(validate_value(&schema, &json!([1, 2])).is_ok());
+        assert!(validate_value(&schema, &json!([])).is_ok());
+
+        let err = validate_value(&schema, &json!([1, "a"])).unwrap_err();
+
+        assert_eq!(err.to_string(), "Value at '$[1]' should be of type 'integer'");
+    }
+
+    #[test]
+    fn nested_path() {
+        let schema = json!({
+            "properties": { "a": { "properties": { "b": { "type": "boolean" } } } }
+        });
+
+        let err = validate_value(&schema, &json!({ "a": { "b": "yes" } })).unwrap_err();
+
+        assert_eq!(err.to_string(), "Value at '$.a.b' should be of type 'boolean'");
+    }
+}
@@ -1,19 +1,23 @@
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::ffi::OsStr;
 use std::fs::DirEntry;
 use std::io::ErrorKind;
 use std::path::Path;
 use std::path::PathBuf;
 use std::thread;
+use std::time::Duration;
 
 use anyhow::anyhow;
 use anyhow::Context;
 use gauntlet_common::model::DownloadStatus;
 use gauntlet_common::model::PluginId;
+use gauntlet_plugin_runtime::NetworkPermission;
 use gauntlet_plugin_runtime::PERMISSIONS_VARIABLE_PATTERN;
 use include_dir::Dir;
 use itertools::Itertools;
 use once_cell::sync::Lazy;
+use regex::Regex;
 use serde::Deserialize;
 use serde::Serialize;
 use typed_path::TypedPathBuf;
@@ -32,8 +36,11 @@ use crate::plugins::data_db_repository::DataDbRepository;
 use crate::plugins::data_db_repository::DbCode;
 use crate::plugins::data_db_repository::DbPluginAction;
 use crate::plugins::data_db_repository::DbPluginActionShortcutKind;
+use crate::plugins::data_db_repository::DbPluginArgument;
 use crate::plugins::data_db_repository::DbPluginClipboardPermissions;
+use crate::plugins::data_db_repository::DbPluginEntrypointInputKind;
 use crate::plugins::data_db_repository::DbPluginEntrypointType;
+use crate::plugins::data_db_repository::DbPluginExport;
 use crate::plugins::data_db_repository::DbPluginMainSearchBarPermissions;
 use crate::plugins::data_db_repository::DbPluginPermissions;
 use crate::plugins::data_db_repository::DbPluginPermissionsExec;
@@ -46,6 +53,7 @@ use crate::plugins::data_db_repository::DbWritePlugin;
 use crate::plugins::data_db_repository::DbWritePluginAssetData;
 use crate::plugins::data_db_repository::DbWritePluginEntrypoint;
 use crate::plugins::download_status::DownloadStatusHolder;
+use crate::plugins::json_schema::validate_schema;
 
 pub struct PluginLoader {
     db_repository: DataDbRepository,
@@ -91,6 +99,7 @@ impl PluginLoader {
                         permissions: plugin_data.permissions,
                         plugin_type: db_plugin_type_to_str(DbPluginType::Normal).to_owned(),
                         preferences: plugin_data.preferences,
+                        exports: plugin_data.exports,
                     })
                     .await?;
 
@@ -135,6 +144,7 @@ impl PluginLoader {
                 permissions: plugin_data.permissions,
                 plugin_type: db_plugin_type_to_str(DbPluginType::Normal).to_owned(),
                 preferences: plugin_data.preferences,
+                exports: plugin_data.exports,
             })
             .await?;
 
@@ -163,6 +173,7 @@ impl PluginLoader {
                 permissions: plugin_data.permissions,
                 plugin_type: db_plugin_type_to_str(DbPluginType::Bundled).to_owned(),
                 preferences: plugin_data.preferences,
+                exports: plugin_data.exports,
             })
             .await?;
 
@@ -186,12 +197,16 @@ impl PluginLoader {
         let js_dir_context = js_dir.display().to_string();
         let js_files = std::fs::read_dir(js_dir).context(js_dir_context)?;
 
-        let js: HashMap<_, _> = js_files
+        let js_files = js_files
             .into_iter()
             .collect::<std::io::Result<Vec<DirEntry>>>()
             .context("Unable to get list of plugin js files")?
             .into_iter()
             .map(|dist_path| dist_path.path())
+            .collect::<Vec<_>>();
+
+        let js: HashMap<_, _> = js_files
+            .iter()
             .filter(|dist_path| dist_path.extension() == Some(OsStr::new("js")))
             .map(|dist_path| {
                 let js_content = std::fs::read_to_string(&dist_path)?;
@@ -209,6 +224,26 @@ impl PluginLoader {
             .into_iter()
             .collect();
 
+        // source maps are optional, "<id>.js.map" files are matched to "<id>.js"
+        let source_maps: HashMap<_, _> = js_files
+            .iter()
+            .filter(|dist_path| dist_path.extension() == Some(OsStr::new("map")))
+            .filter_map(|dist_path| {
+                let id = dist_path.file_stem()?.to_str()?.strip_suffix(".js")?.to_owned();
+
+                Some((id, dist_path))
+            })
+            .filter(|(id, _)| js.contains_key(id))
+            .map(|(id, dist_path)| {
+                let source_map_content = std::fs::read_to_string(&dist_path)?;
+
+                Ok((id, source_map_content))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()
+            .context("Unable to read plugin source maps")?
+            .into_iter()
+            .collect();
+
         let asset_data = WalkDir::new(&assets)
             .into_iter()
             .collect::<walkdir::Result<Vec<walkdir::DirEntry>>>()
@@ -254,6 +289,20 @@ impl PluginLoader {
         let plugin_name = plugin_manifest.gauntlet.name;
         let plugin_description = plugin_manifest.gauntlet.description;
 
+        let exports = plugin_manifest
+            .exports
+            .into_iter()
+            .map(|export| {
+                DbPluginExport {
+                    id: export.id,
+                    entrypoint_id: export.entrypoint,
+                    description: export.description,
+                    input_schema: export.input_schema,
+                    output_schema: export.output_schema,
+                }
+            })
+            .collect();
+
         let entrypoints: Vec<_> = plugin_manifest
             .entrypoint
             .into_iter()
@@ -272,6 +321,11 @@ impl PluginLoader {
                         }
                     })
                     .to_owned(),
+                    interval_seconds: entrypoint.interval.as_deref().map(|interval| {
+                        let interval = parse_interval(interval).expect("interval is validated when manifest is read");
+
+                        i64::try_from(interval.as_secs()).expect("interval is validated when manifest is read")
+                    }),
                     preferences: entrypoint
                         .preferences
                         .into_iter()
@@ -409,6 +463,50 @@ impl PluginLoader {
                             }
                         })
                         .collect(),
+                    arguments: entrypoint
+                        .arguments
+                        .into_iter()
+                        .map(|argument| {
+                            match argument {
+                                PluginManifestArgument::Text { id, name, required } => {
+                                    DbPluginArgument::Text { id, name, required }
+                                }
+                                PluginManifestArgument::Password { id, name, required } => {
+                                    DbPluginArgument::Password { id, name, required }
+                                }
+                                PluginManifestArgument::Dropdown {
+                                    id,
+                                    name,
+                                    required,
+                                    values,
+                                } => {
+                                    let values = values
+                                        .into_iter()
+                                        .map(|PluginManifestPreferenceEnumValue { label, value }| {
+                                            DbPreferenceEnumValue { label, value }
+                                        })
+                                        .collect();
+
+                                    DbPluginArgument::Dropdown {
+                                        id,
+                                        name,
+                                        required,
+                                        values,
+                                    }
+                                }
+                            }
+                        })
+                        .collect(),
+                    accepts_input: entrypoint
+                        .accepts_input
+                        .into_iter()
+                        .map(|kind| {
+                            match kind {
+                                PluginManifestEntrypointInputKind::Text => DbPluginEntrypointInputKind::Text,
+                                PluginManifestEntrypointInputKind::Image => DbPluginEntrypointInputKind::Image,
+                            }
+                        })
+                        .collect(),
                 }
             })
             .collect();
@@ -572,18 +670,20 @@ impl PluginLoader {
             system: plugin_manifest.permissions.system,
             clipboard,
             main_search_bar,
+            plugins: plugin_manifest.permissions.plugins,
         };
 
         Ok(PluginDownloadData {
             id: plugin_id.to_string(),
             name: plugin_name,
             description: plugin_description,
-            code: DbCode { js },
+            code: DbCode { js, source_maps },
             entrypoints,
             asset_data,
             permissions,
             preferences: plugin_preferences,
             preferences_user_data: HashMap::new(),
+            exports,
         })
     }
 
@@ -630,6 +730,7 @@ impl PluginLoader {
         // adoption to breaking changes in deno
         // TODO do a warning
         Self::validate_string_permissions(&permissions.system)?;
+        Self::validate_string_permissions(&permissions.plugins)?;
 
         let env_exists = !permissions.environment.is_empty();
         let fs_read_exists = !permissions.filesystem.read.is_empty();
@@ -675,6 +776,154 @@ impl PluginLoader {
             }
         }
 
+        let accepts_input = plugin_manifest
+            .entrypoint
+            .iter()
+            .any(|entrypoint| !entrypoint.accepts_input.is_empty());
+
+        // captured input contains selected text or clipboard content
+        if accepts_input {
+            let clipboard = &permissions.clipboard;
+            if !clipboard.contains(&PluginManifestClipboardPermissions::Read) {
+                return Err(anyhow!(
+                    "Plugin has entrypoints that accept input but doesn't specify clipboard 'read' permission"
+                ));
+            }
+        }
+
+        for entrypoint in &plugin_manifest.entrypoint {
+            if let Some(interval) = &entrypoint.interval {
+                match entrypoint.entrypoint_type {
+                    PluginManifestEntrypointTypes::Command | PluginManifestEntrypointTypes::EntrypointGenerator => {}
+                    PluginManifestEntrypointTypes::View | PluginManifestEntrypointTypes::InlineView => {
+                        return Err(anyhow!(
+                            "Entrypoint '{}' specifies interval, but only 'command' and 'entrypoint-generator' entrypoints can be run on interval",
+                            entrypoint.id
+                        ));
+                    }
+                }
+
+                let interval = parse_interval(interval)
+                    .with_context(|| format!("Entrypoint '{}' has invalid interval", entrypoint.id))?;
+
+                if interval < MIN_ENTRYPOINT_INTERVAL {
+                    return Err(anyhow!(
+                        "Entrypoint '{}' has interval shorter than minimum allowed 1 minute",
+                        entrypoint.id
+                    ));
+                }
+
+                if interval > MAX_ENTRYPOINT_INTERVAL {
+                    return Err(anyhow!(
+                        "Entrypoint '{}' has interval longer than maximum allowed 365 days",
+                        entrypoint.id
+                    ));
+                }
+            }
+
+            if !entrypoint.accepts_input.is_empty() {
+                match entrypoint.entrypoint_type {
+                    PluginManifestEntrypointTypes::Command | PluginManifestEntrypointTypes::View => {}
+                    PluginManifestEntrypointTypes::InlineView | PluginManifestEntrypointTypes::EntrypointGenerator => {
+                        return Err(anyhow!(
+                            "Entrypoint '{}' specifies accepted input, but only 'command' and 'view' entrypoints can accept input",
+                            entrypoint.id
+                        ));
+                    }
+                }
+            }
+
+            if !entrypoint.arguments.is_empty() {
+                match entrypoint.entrypoint_type {
+                    PluginManifestEntrypointTypes::Command | PluginManifestEntrypointTypes::View => {}
+                    PluginManifestEntrypointTypes::InlineView | PluginManifestEntrypointTypes::EntrypointGenerator => {
+                        return Err(anyhow!(
+                            "Entrypoint '{}' specifies arguments, but only 'command' and 'view' entrypoints can accept arguments",
+                            entrypoint.id
+                        ));
+                    }
+                }
+            }
+
+            if entrypoint.arguments.len() > MAX_ENTRYPOINT_ARGUMENTS {
+                return Err(anyhow!(
+                    "Entrypoint '{}' specifies {} arguments, but maximum allowed is {}",
+                    entrypoint.id,
+                    entrypoint.arguments.len(),
+                    MAX_ENTRYPOINT_ARGUMENTS
+                ));
+            }
+
+            // scheduled runs happen in background, there is no one to enter the arguments
+            let has_required_arguments = entrypoint.arguments.iter().any(|argument| argument.required());
+
+            if entrypoint.interval.is_some() && has_required_arguments {
+                return Err(anyhow!(
+                    "Entrypoint '{}' specifies interval, but has required arguments",
+                    entrypoint.id
+                ));
+            }
+
+            let mut argument_ids = HashSet::new();
+
+            for argument in &entrypoint.arguments {
+                if !argument_ids.insert(argument.id()) {
+                    return Err(anyhow!(
+                        "Entrypoint '{}' has multiple arguments with id '{}'",
+                        entrypoint.id,
+                        argument.id()
+                    ));
+                }
+
+                if let PluginManifestArgument::Dropdown { values, .. } = argument {
+                    if values.is_empty() {
+                        return Err(anyhow!(
+                            "Argument '{}' of entrypoint '{}' is a dropdown without values",
+                            argument.id(),
+                            entrypoint.id
+                        ));
+                    }
+                }
+            }
+        }
+
+        let mut export_ids = HashSet::new();
+
+        for export in &plugin_manifest.exports {
+            // exported functions are named exports of entrypoint module, default export is entrypoint itself
+            if !EXPORT_ID_PATTERN.is_match(&export.id) || export.id == "default" {
+                return Err(anyhow!(
+                    "Export '{}' has invalid id, it should be a valid javascript identifier",
+                    export.id
+                ));
+            }
+
+            if !export_ids.insert(&export.id) {
+                return Err(anyhow!("Plugin has multiple exports with id '{}'", export.id));
+            }
+
+            let entrypoint_exists = plugin_manifest
+                .entrypoint
+                .iter()
+                .any(|entrypoint| entrypoint.id == export.entrypoint);
+
+            if !entrypoint_exists {
+                return Err(anyhow!(
+                    "Export '{}' refers to entrypoint '{}' which doesn't exist",
+                    export.id,
+                    export.entrypoint
+                ));
+            }
+
+            if let Some(schema) = &export.input_schema {
+                validate_schema(schema).with_context(|| format!("Export '{}' has invalid input schema", export.id))?;
+            }
+
+            if let Some(schema) = &export.output_schema {
+                validate_schema(schema).with_context(|| format!("Export '{}' has invalid output schema", export.id))?;
+            }
+        }
+
         Ok(())
     }
 
@@ -859,20 +1108,25 @@ impl PluginLoader {
                 Err(anyhow!("Empty string value is not allowed in permissions"))?
             }
 
-            let url = url::Url::parse(&format!("http://{value}"))?;
+            match NetworkPermission::parse(value)? {
+                NetworkPermission::Exact => {
+                    let url = url::Url::parse(&format!("http://{value}"))?;
 
-            let contains_username = !url.username().is_empty();
-            let contains_password = matches!(url.password(), Some(_));
-            let contains_path = url.path() != "/";
-            let contains_query = matches!(url.query(), Some(_));
-            let contains_fragment = matches!(url.fragment(), Some(_));
+                    let contains_username = !url.username().is_empty();
+                    let contains_password = matches!(url.password(), Some(_));
+                    let contains_path = url.path() != "/";
+                    let contains_query = matches!(url.query(), Some(_));
+                    let contains_fragment = matches!(url.fragment(), Some(_));
 
-            // allow only domain and optional port
-            if contains_username || contains_password || contains_path || contains_query || contains_fragment {
-                Err(anyhow!(
-                    "Network permission can only contain domain and optionally port: {}",
-                    value
-                ))?
+                    // allow only domain and optional port
+                    if contains_username || contains_password || contains_path || contains_query || contains_fragment {
+                        Err(anyhow!(
+                            "Network permission can only contain domain and optionally port: {}",
+                            value
+                        ))?
+                    }
+                }
+                NetworkPermission::Subdomains { .. } | NetworkPermission::IpRange { .. } => {}
             }
         }
         Ok(())
@@ -889,6 +1143,7 @@ struct PluginDownloadData {
     pub permissions: DbPluginPermissions,
     pub preferences: HashMap<String, DbPluginPreference>,
     pub preferences_user_data: HashMap<String, DbPluginPreferenceUserData>,
+    pub exports: Vec<DbPluginExport>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -901,6 +1156,17 @@ struct PluginManifest {
     permissions: PluginManifestPermissions,
     #[serde(default)]
     preferences: Vec<PluginManifestPreference>,
+    #[serde(default)]
+    exports: Vec<PluginManifestExport>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PluginManifestExport {
+    id: String,
+    entrypoint: String,
+    description: String,
+    input_schema: Option<serde_json::Value>,
+    output_schema: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -917,6 +1183,64 @@ struct PluginManifestEntrypoint {
     preferences: Vec<PluginManifestPreference>,
     #[serde(default)]
     actions: Vec<PluginManifestAction>,
+    interval: Option<String>,
+    #[serde(default)]
+    arguments: Vec<PluginManifestArgument>,
+    #[serde(default)]
+    accepts_input: Vec<PluginManifestEntrypointInputKind>,
+}
+
+#[derive(Debug, Deserialize)]
+enum PluginManifestEntrypointInputKind {
+    #[serde(rename = "text")]
+    Text,
+    #[serde(rename = "image")]
+    Image,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type")]
+enum PluginManifestArgument {
+    #[serde(rename = "text")]
+    Text {
+        id: String,
+        name: String,
+        #[serde(default)]
+        required: bool,
+    },
+    #[serde(rename = "password")]
+    Password {
+        id: String,
+        name: String,
+        #[serde(default)]
+        required: bool,
+    },
+    #[serde(rename = "dropdown")]
+    Dropdown {
+        id: String,
+        name: String,
+        #[serde(default)]
+        required: bool,
+        values: Vec<PluginManifestPreferenceEnumValue>,
+    },
+}
+
+impl PluginManifestArgument {
+    fn id(&self) -> &str {
+        match self {
+            PluginManifestArgument::Text { id, .. } => id,
+            PluginManifestArgument::Password { id, .. } => id,
+            PluginManifestArgument::Dropdown { id, .. } => id,
+        }
+    }
+
+    fn required(&self) -> bool {
+        match self {
+            PluginManifestArgument::Text { required, .. } => *required,
+            PluginManifestArgument::Password { required, .. } => *required,
+            PluginManifestArgument::Dropdown { required, .. } => *required,
+        }
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -993,6 +1317,48 @@ pub enum PluginManifestEntrypointTypes {
     EntrypointGenerator,
 }
 
+// arguments are shown next to main search bar, so there is only space for a few of them
+const MAX_ENTRYPOINT_ARGUMENTS: usize = 3;
+
+const MIN_ENTRYPOINT_INTERVAL: Duration = Duration::from_secs(60);
+// interval is stored as signed number of seconds
+const MAX_ENTRYPOINT_INTERVAL: Duration = Duration::from_secs(365 * 24 * 60 * 60);
+
+static EXPORT_ID_PATTERN: Lazy<Regex> = Lazy::new(|| Regex::new(r"^[a-zA-Z_$][a-zA-Z0-9_$]*$").expect("invalid regex"));
+
+// intervals are specified as number followed by unit, e.g. "30s", "15m", "1h" or "1d"
+fn parse_interval(value: &str) -> anyhow::Result<Duration> {
+    let unit_index = value
+        .find(|char: char| !char.is_ascii_digit())
+        .ok_or_else(|| anyhow!("Interval '{}' doesn't specify unit, expected one of: s, m, h, d", value))?;
+
+    let (amount, unit) = value.split_at(unit_index);
+
+    let amount: u64 = amount
+        .parse()
+        .with_context(|| format!("Interval '{}' doesn't start with a number", value))?;
+
+    let multiplier = match unit {
+        "s" => 1,
+        "m" => 60,
+        "h" => 60 * 60,
+        "d" => 60 * 60 * 24,
+        _ => {
+            return Err(anyhow!(
+                "Interval '{}' has unknown unit '{}', expected one of: s, m, h, d",
+                value,
+                unit
+            ))
+        }
+    };
+
+    let seconds = amount
+        .checked_mul(multiplier)
+        .ok_or_else(|| anyhow!("Interval '{}' is too large", value))?;
+
+    Ok(Duration::from_secs(seconds))
+}
+
 #[derive(Debug, Deserialize)]
 pub struct PluginManifestAction {
     id: String,
@@ -1350,6 +1716,8 @@ pub struct PluginManifestPermissions {
     clipboard: Vec<PluginManifestClipboardPermissions>,
     #[serde(default)]
     main_search_bar: Vec<PluginManifestMainSearchBarPermissions>,
+    #[serde(default)]
+    plugins: Vec<String>,
 }
 
 #[derive(Debug, Deserialize, Default)]
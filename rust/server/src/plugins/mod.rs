@@ -1,6 +1,8 @@
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::ops::Index;
+use std::path::Path;
+use std::path::PathBuf;
 use std::sync::Mutex;
 use std::thread;
 use std::time::Duration;
@@ -10,19 +12,26 @@ use anyhow::Context;
 use gauntlet_common::dirs::Dirs;
 use gauntlet_common::model::DownloadStatus;
 use gauntlet_common::model::EntrypointId;
+use gauntlet_common::model::FilePickerDirectory;
+use gauntlet_common::model::FilePickerEntry;
 use gauntlet_common::model::KeyboardEventOrigin;
 use gauntlet_common::model::LocalSaveData;
 use gauntlet_common::model::PhysicalKey;
 use gauntlet_common::model::PhysicalShortcut;
+use gauntlet_common::model::PluginAuditLogEntry;
 use gauntlet_common::model::PluginId;
+use gauntlet_common::model::PluginPermission;
+use gauntlet_common::model::PluginPermissionKind;
 use gauntlet_common::model::PluginPreference;
 use gauntlet_common::model::PluginPreferenceUserData;
 use gauntlet_common::model::PreferenceEnumValue;
+use gauntlet_common::model::ScheduledRun;
 use gauntlet_common::model::SearchResult;
 use gauntlet_common::model::SearchResultEntrypointType;
 use gauntlet_common::model::SettingsEntrypoint;
 use gauntlet_common::model::SettingsEntrypointType;
 use gauntlet_common::model::SettingsPlugin;
+use gauntlet_common::model::SettingsPluginPermissions;
 use gauntlet_common::model::SettingsTheme;
 use gauntlet_common::model::UiPropertyValue;
 use gauntlet_common::model::UiRequestData;
@@ -34,8 +43,11 @@ use gauntlet_common::rpc::frontend_api::FrontendApi;
 use gauntlet_common::settings_env_data_to_string;
 use gauntlet_common::SettingsEnvData;
 use gauntlet_common::SETTINGS_ENV;
+use gauntlet_plugin_runtime::read_permission_paths;
+use gauntlet_plugin_runtime::JsClipboardData;
 use gauntlet_plugin_runtime::JsPluginCode;
 use gauntlet_plugin_runtime::JsPluginPermissions;
+use gauntlet_plugin_runtime::JsPluginPermissionsDenied;
 use gauntlet_plugin_runtime::JsPluginPermissionsExec;
 use gauntlet_plugin_runtime::JsPluginPermissionsFileSystem;
 use gauntlet_plugin_runtime::JsPluginPermissionsMainSearchBar;
@@ -45,6 +57,7 @@ use include_dir::Dir;
 use tokio::runtime::Handle;
 
 use crate::model::ActionShortcutKey;
+use crate::model::EntrypointInput;
 use crate::plugins::clipboard::Clipboard;
 use crate::plugins::config_reader::ConfigReader;
 use crate::plugins::data_db_repository::db_entrypoint_from_str;
@@ -53,20 +66,36 @@ use crate::plugins::data_db_repository::DbPluginActionShortcutKind;
 use crate::plugins::data_db_repository::DbPluginClipboardPermissions;
 use crate::plugins::data_db_repository::DbPluginEntrypointType;
 use crate::plugins::data_db_repository::DbPluginMainSearchBarPermissions;
+use crate::plugins::data_db_repository::DbPluginPermission;
+use crate::plugins::data_db_repository::DbPluginPermissionKind;
+use crate::plugins::data_db_repository::DbPluginPermissions;
+use crate::plugins::data_db_repository::DbPluginPermissionsUserData;
 use crate::plugins::data_db_repository::DbPluginPreference;
 use crate::plugins::data_db_repository::DbPluginPreferenceUserData;
 use crate::plugins::data_db_repository::DbReadPluginEntrypoint;
+use crate::plugins::deep_link::parse_deep_link;
+use crate::plugins::deep_link::validate_deep_link_params;
+use crate::plugins::deep_link::DeepLink;
 use crate::plugins::icon_cache::IconCache;
+use crate::plugins::inspectors::PluginInspectorAddresses;
+use crate::plugins::js::add_audit_log_entry;
+use crate::plugins::js::clipboard_read_permission;
 use crate::plugins::js::start_plugin_runtime;
 use crate::plugins::js::AllPluginCommandData;
 use crate::plugins::js::OnePluginCommandData;
 use crate::plugins::js::PluginCommand;
+use crate::plugins::js::PluginInspector;
 use crate::plugins::js::PluginPermissions;
 use crate::plugins::js::PluginPermissionsClipboard;
 use crate::plugins::js::PluginRuntimeData;
 use crate::plugins::loader::PluginLoader;
+use crate::plugins::plugin_functions::PluginFunctionCalls;
 use crate::plugins::run_status::RunStatusHolder;
+use crate::plugins::scheduler::EntrypointScheduler;
+use crate::plugins::scheduler::ScheduledEntrypoint;
+use crate::plugins::scheduler::SCHEDULER_TICK;
 use crate::plugins::settings::Settings;
+use crate::plugins::storage_db_repository::PluginStorageRepository;
 use crate::search::EntrypointActionDataView;
 use crate::search::EntrypointActionType;
 use crate::search::EntrypointDataView;
@@ -76,15 +105,21 @@ use crate::search::SearchIndex;
 mod clipboard;
 mod config_reader;
 mod data_db_repository;
+mod deep_link;
 mod download_status;
 pub(super) mod frecency;
 mod icon_cache;
 mod image_gatherer;
+mod inspectors;
 pub mod js;
+mod json_schema;
 mod loader;
+mod plugin_functions;
 mod run_status;
 mod runtime;
+mod scheduler;
 mod settings;
+mod storage_db_repository;
 mod theme;
 
 static BUNDLED_PLUGINS: [(&str, Dir); 1] = [(
@@ -104,6 +139,11 @@ pub struct ApplicationManager {
     dirs: Dirs,
     clipboard: Clipboard,
     settings: Settings,
+    inspectors: Mutex<HashMap<PluginId, PluginInspector>>,
+    plugin_function_calls: PluginFunctionCalls,
+    inspector_addresses: PluginInspectorAddresses,
+    plugin_storage: PluginStorageRepository,
+    entrypoint_input: Mutex<Option<EntrypointInput>>,
 }
 
 impl ApplicationManager {
@@ -118,6 +158,7 @@ impl ApplicationManager {
         let search_index = SearchIndex::create_index(frontend_api.clone())?;
         let clipboard = Clipboard::new()?;
         let settings = Settings::new(dirs.clone(), db_repository.clone(), frontend_api.clone())?;
+        let plugin_storage = PluginStorageRepository::new(dirs.clone());
 
         let (command_broadcaster, _) = tokio::sync::broadcast::channel::<PluginCommand>(100);
 
@@ -133,6 +174,11 @@ impl ApplicationManager {
             clipboard,
             settings,
             dirs,
+            inspectors: Mutex::new(HashMap::new()),
+            plugin_function_calls: PluginFunctionCalls::new(),
+            inspector_addresses: PluginInspectorAddresses::new(),
+            plugin_storage,
+            entrypoint_input: Mutex::new(None),
         })
     }
 
@@ -173,7 +219,16 @@ impl ApplicationManager {
     }
 
     pub fn search(&self, text: &str, render_inline_view: bool) -> anyhow::Result<Vec<SearchResult>> {
-        let result = self.search_index.search(&text);
+        let result = self.search_index.search(&text).map(|mut result| {
+            let entrypoint_input = self.entrypoint_input.lock().expect("lock is poisoned");
+
+            if let Some(entrypoint_input) = entrypoint_input.as_ref() {
+                // sort is stable, so relevance order is kept inside both groups
+                result.sort_by_key(|item| !entrypoint_input.is_accepted_by(&item.entrypoint_accepts_input));
+            }
+
+            result
+        });
 
         if render_inline_view {
             self.handle_inline_view(&text);
@@ -182,12 +237,101 @@ impl ApplicationManager {
         result
     }
 
-    pub async fn show_window(&self) -> anyhow::Result<()> {
+    pub async fn show_window(&self, input: Option<String>) -> anyhow::Result<()> {
+        self.capture_entrypoint_input(input);
+
         self.frontend_api.show_window().await?;
 
+        // window may already be open, its search results need to reflect new input
+        self.frontend_api.request_search_results_update().await?;
+
         Ok(())
     }
 
+    // called every time window is opened, so input from previous opening is not delivered
+    pub fn capture_entrypoint_input(&self, text: Option<String>) {
+        let entrypoint_input = match text {
+            Some(text) => {
+                Some(EntrypointInput {
+                    text: Some(text),
+                    png_data: None,
+                })
+            }
+            None => {
+                // selection and clipboard are not read if there is no one to receive them
+                if self.search_index.any_entrypoint_accepts_input() {
+                    match self.read_entrypoint_input() {
+                        Ok(entrypoint_input) => entrypoint_input,
+                        Err(err) => {
+                            tracing::warn!("error occurred when reading entrypoint input {:?}", err);
+                            None
+                        }
+                    }
+                } else {
+                    None
+                }
+            }
+        };
+
+        *self.entrypoint_input.lock().expect("lock is poisoned") = entrypoint_input;
+    }
+
+    fn read_entrypoint_input(&self) -> anyhow::Result<Option<EntrypointInput>> {
+        // selected text is more likely to be what user wants to act on than older clipboard content
+        if let Some(text) = self.clipboard.read_primary_selection_text()? {
+            if !text.trim().is_empty() {
+                return Ok(Some(EntrypointInput {
+                    text: Some(text),
+                    png_data: None,
+                }));
+            }
+        }
+
+        let JsClipboardData { text_data, png_data } = self.clipboard.read()?;
+
+        let text_data = text_data.filter(|text| !text.trim().is_empty());
+
+        if text_data.is_none() && png_data.is_none() {
+            return Ok(None);
+        }
+
+        Ok(Some(EntrypointInput {
+            text: text_data,
+            png_data,
+        }))
+    }
+
+    pub async fn entrypoint_input_for(
+        &self,
+        plugin_id: &PluginId,
+        entrypoint_id: &EntrypointId,
+    ) -> Option<EntrypointInput> {
+        let entrypoint_input = {
+            let entrypoint_input = self.entrypoint_input.lock().expect("lock is poisoned");
+
+            let accepts_input = self.search_index.entrypoint_accepts_input(plugin_id, entrypoint_id);
+
+            entrypoint_input.as_ref()?.for_entrypoint(&accepts_input)?
+        };
+
+        // input is read from selection or clipboard, so clipboard read permission may have been revoked by user
+        let allow = match self.db_repository.get_plugin_by_id(&plugin_id.to_string()).await {
+            Ok(plugin) => {
+                plugin_effective_permissions(&plugin.permissions, plugin.permissions_user_data)
+                    .clipboard
+                    .contains(&PluginPermissionsClipboard::Read)
+            }
+            Err(err) => {
+                tracing::warn!("error occurred when reading plugin permissions {:?}", err);
+                false
+            }
+        };
+
+        add_audit_log_entry(&self.db_repository, plugin_id, clipboard_read_permission(), allow).await;
+
+        allow.then_some(entrypoint_input)
+    }
+
     pub async fn run_action(
         &self,
         plugin_id: PluginId,
@@ -220,7 +364,8 @@ impl ApplicationManager {
             ":primary" => {
                 match entrypoint_type {
                     SearchResultEntrypointType::Command => {
-                        self.handle_run_command(plugin_id, entrypoint_id).await;
+                        self.handle_run_command(plugin_id, entrypoint_id, HashMap::new(), None)
+                            .await;
                     }
                     SearchResultEntrypointType::View => {
                         self.frontend_api
@@ -229,6 +374,7 @@ impl ApplicationManager {
                                 plugin_name.to_string(),
                                 entrypoint_id,
                                 entrypoint_name.to_string(),
+                                HashMap::new(),
                             )
                             .await?;
                     }
@@ -343,15 +489,90 @@ impl ApplicationManager {
         Ok(())
     }
 
-    pub async fn save_local_plugin(&self, path: &str) -> anyhow::Result<LocalSaveData> {
+    pub async fn open_deep_link(&self, link: String) -> anyhow::Result<()> {
+        let DeepLink {
+            plugin_id,
+            entrypoint_id,
+            params,
+        } = parse_deep_link(&link)?;
+
+        let Some(search_result) = self.search_index.entrypoint_search_result(&plugin_id, &entrypoint_id) else {
+            return Err(anyhow!(
+                "Unable to find entrypoint with id: {} of plugin with id: {}",
+                entrypoint_id,
+                plugin_id
+            ));
+        };
+
+        validate_deep_link_params(&search_result.entrypoint_arguments, &params)?;
+
+        match search_result.entrypoint_type {
+            SearchResultEntrypointType::Command => {
+                // links can come from anywhere, so command is only run after user confirms it in argument form
+                self.frontend_api
+                    .show_entrypoint_arguments(search_result, params)
+                    .await?;
+            }
+            SearchResultEntrypointType::View => {
+                let missing_required_argument = search_result
+                    .entrypoint_arguments
+                    .iter()
+                    .any(|argument| argument.required && !params.contains_key(&argument.id));
+
+                if missing_required_argument {
+                    self.frontend_api
+                        .show_entrypoint_arguments(search_result, params)
+                        .await?;
+                } else {
+                    self.frontend_api
+                        .open_plugin_view(
+                            plugin_id,
+                            search_result.plugin_name,
+                            entrypoint_id,
+                            search_result.entrypoint_name,
+                            params,
+                        )
+                        .await?;
+                }
+            }
+            SearchResultEntrypointType::Generated => {
+                return Err(anyhow!("Deep links are not supported for generated entrypoints"));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub async fn save_local_plugin(
+        &self,
+        path: &str,
+        inspector_port: Option<u16>,
+        inspector_break_on_start: bool,
+    ) -> anyhow::Result<LocalSaveData> {
         tracing::info!(target = "plugin", "Saving local plugin at path: {:?}", path);
 
         let plugin_id = self.plugin_downloader.save_local_plugin(path).await?;
 
         let plugin = self.db_repository.get_plugin_by_id(&plugin_id.to_string()).await?;
 
+        let inspector = PluginInspector {
+            port: inspector_port,
+            break_on_start: inspector_break_on_start,
+        };
+
+        self.inspectors
+            .lock()
+            .expect("lock is poisoned")
+            .insert(plugin_id.clone(), inspector);
+
         self.reload_plugin(plugin_id.clone()).await?;
 
+        let inspector_address = if self.run_status_holder.is_plugin_running(&plugin_id) {
+            self.inspector_addresses.wait(&plugin_id).await
+        } else {
+            None
+        };
+
         let (stdout_file_path, stderr_file_path) = self.dirs.plugin_log_files(&plugin.uuid);
 
         Ok(LocalSaveData {
@@ -363,6 +584,7 @@ impl ApplicationManager {
                 .into_os_string()
                 .into_string()
                 .map_err(|_| anyhow!("non uft8 paths are not supported"))?,
+            inspector_address,
         })
     }
 
@@ -379,15 +601,31 @@ impl ApplicationManager {
     }
 
     pub async fn plugins(&self) -> anyhow::Result<Vec<SettingsPlugin>> {
-        let result = self
+        let plugins = self.db_repository.list_plugins_and_entrypoints().await?;
+
+        let mut scheduled_runs: HashMap<_, _> = self
             .db_repository
-            .list_plugins_and_entrypoints()
+            .list_scheduled_runs()
             .await?
+            .into_iter()
+            .map(|run| ((run.plugin_id.clone(), run.entrypoint_id.clone()), run))
+            .collect();
+
+        let result = plugins
             .into_iter()
             .map(|(plugin, entrypoints)| {
                 let entrypoints = entrypoints
                     .into_iter()
                     .map(|entrypoint| {
+                        let last_scheduled_run = scheduled_runs
+                            .remove(&(plugin.id.clone(), entrypoint.id.clone()))
+                            .map(|run| {
+                                ScheduledRun {
+                                    timestamp: run.timestamp,
+                                    error: run.error,
+                                }
+                            });
+
                         let entrypoint_id = EntrypointId::from_string(entrypoint.id);
 
                         let entrypoint = SettingsEntrypoint {
@@ -417,12 +655,38 @@ impl ApplicationManager {
                                 .into_iter()
                                 .map(|(key, value)| (key, plugin_preference_user_data_from_db(value)))
                                 .collect(),
+                            interval_seconds: entrypoint
+                                .interval_seconds
+                                .and_then(|interval| u64::try_from(interval).ok()),
+                            last_scheduled_run,
                         };
 
                         (entrypoint_id, entrypoint)
                     })
                     .collect();
 
+                let permissions = SettingsPluginPermissions {
+                    declared: plugin_permissions_from_db(&plugin.permissions),
+                    revoked: plugin
+                        .permissions_user_data
+                        .revoked
+                        .into_iter()
+                        .map(|permission| plugin_permission_from_db(permission))
+                        .collect(),
+                    ask: plugin.permissions_user_data.ask,
+                    granted: plugin
+                        .permissions_user_data
+                        .granted
+                        .into_iter()
+                        .map(|permission| plugin_permission_from_db(permission))
+                        .collect(),
+                };
+
+                let storage_usage = self.plugin_storage.usage(&plugin.uuid).unwrap_or_else(|err| {
+                    tracing::warn!("Unable to get storage usage of plugin {:?}: {:?}", plugin.id, err);
+                    0
+                });
+
                 SettingsPlugin {
                     plugin_id: PluginId::from_string(plugin.id),
                     plugin_name: plugin.name,
@@ -442,6 +706,8 @@ impl ApplicationManager {
                         .into_iter()
                         .map(|(key, value)| (key, plugin_preference_user_data_from_db(value)))
                         .collect(),
+                    permissions,
+                    storage_usage,
                 }
             })
             .collect();
@@ -516,6 +782,43 @@ impl ApplicationManager {
         Ok(())
     }
 
+    pub async fn set_plugin_permissions(
+        &self,
+        plugin_id: PluginId,
+        revoked: Vec<PluginPermission>,
+        ask: bool,
+        granted: Vec<PluginPermission>,
+    ) -> anyhow::Result<()> {
+        tracing::debug!(
+            target = "plugin",
+            "Setting permissions for plugin id: {:?}, revoked: {:?}, ask: {}, granted: {:?}",
+            plugin_id,
+            revoked,
+            ask,
+            granted
+        );
+
+        let user_data = DbPluginPermissionsUserData {
+            ask,
+            revoked: revoked
+                .into_iter()
+                .map(|permission| plugin_permission_to_db(permission))
+                .collect(),
+            granted: granted
+                .into_iter()
+                .map(|permission| plugin_permission_to_db(permission))
+                .collect(),
+        };
+
+        self.db_repository
+            .set_permissions_user_data(&plugin_id.to_string(), user_data)
+            .await?;
+
+        self.reload_plugin(plugin_id.clone()).await?;
+
+        Ok(())
+    }
+
     pub async fn set_global_shortcut(&self, shortcut: Option<PhysicalShortcut>) -> anyhow::Result<()> {
         self.settings.set_global_shortcut(shortcut).await
     }
@@ -560,13 +863,18 @@ impl ApplicationManager {
         self.db_repository
             .set_preference_value(
                 plugin_id.to_string(),
-                entrypoint_id.map(|id| id.to_string()),
+                entrypoint_id.as_ref().map(|id| id.to_string()),
                 preference_id,
                 user_data,
             )
             .await?;
 
-        self.reload_plugin(plugin_id.clone()).await?;
+        // running plugin reads preferences from db on each access,
+        // so it is enough to notify it instead of restarting
+        self.send_command(PluginCommand::One {
+            id: plugin_id,
+            data: OnePluginCommandData::PreferencesChanged { entrypoint_id },
+        });
 
         Ok(())
     }
@@ -607,22 +915,58 @@ impl ApplicationManager {
         if running {
             self.stop_plugin(plugin_id.clone()).await;
         }
+        let plugin = self.db_repository.get_plugin_by_id(&plugin_id.to_string()).await?;
+        self.plugin_storage.clear(&plugin.uuid).await?;
         self.db_repository.remove_plugin(&plugin_id.to_string()).await?;
         self.search_index.remove_for_plugin(plugin_id)?;
         Ok(())
     }
 
+    pub async fn clear_plugin_storage(&self, plugin_id: PluginId) -> anyhow::Result<()> {
+        tracing::info!(target = "plugin", "Clearing storage of plugin with id: {:?}", plugin_id);
+
+        let plugin = self.db_repository.get_plugin_by_id(&plugin_id.to_string()).await?;
+
+        self.plugin_storage.clear(&plugin.uuid).await
+    }
+
+    pub async fn plugin_audit_log(&self, plugin_id: PluginId) -> anyhow::Result<Vec<PluginAuditLogEntry>> {
+        let audit_log = self
+            .db_repository
+            .get_audit_log(&plugin_id.to_string())
+            .await?
+            .into_iter()
+            .map(|entry| {
+                PluginAuditLogEntry {
+                    timestamp: entry.timestamp,
+                    permission: plugin_permission_from_db(entry.permission),
+                    allowed: entry.allowed,
+                }
+            })
+            .collect();
+
+        Ok(audit_log)
+    }
+
     pub fn handle_inline_view(&self, text: &str) {
         self.send_command(PluginCommand::All {
             data: AllPluginCommandData::OpenInlineView { text: text.to_owned() },
         })
     }
 
-    pub async fn handle_run_command(&self, plugin_id: PluginId, entrypoint_id: EntrypointId) {
+    pub async fn handle_run_command(
+        &self,
+        plugin_id: PluginId,
+        entrypoint_id: EntrypointId,
+        arguments: HashMap<String, String>,
+        input: Option<EntrypointInput>,
+    ) {
         self.send_command(PluginCommand::One {
             id: plugin_id.clone(),
             data: OnePluginCommandData::RunCommand {
                 entrypoint_id: entrypoint_id.to_string(),
+                arguments,
+                input,
             },
         });
 
@@ -650,11 +994,15 @@ impl ApplicationManager {
         &self,
         plugin_id: PluginId,
         entrypoint_id: EntrypointId,
+        arguments: HashMap<String, String>,
+        input: Option<EntrypointInput>,
     ) -> anyhow::Result<HashMap<String, PhysicalShortcut>> {
         self.send_command(PluginCommand::One {
             id: plugin_id.clone(),
             data: OnePluginCommandData::RenderView {
                 entrypoint_id: entrypoint_id.clone(),
+                arguments,
+                input,
             },
         });
 
@@ -715,6 +1063,56 @@ impl ApplicationManager {
         })
     }
 
+    pub async fn run_scheduled_entrypoints(&self) {
+        let mut scheduler = EntrypointScheduler::new();
+        let mut interval = tokio::time::interval(SCHEDULER_TICK);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            interval.tick().await;
+
+            if let Err(err) = self.run_due_scheduled_entrypoints(&mut scheduler).await {
+                tracing::error!("error occurred when running scheduled entrypoints: {:?}", err);
+            }
+        }
+    }
+
+    async fn run_due_scheduled_entrypoints(&self, scheduler: &mut EntrypointScheduler) -> anyhow::Result<()> {
+        let scheduled = self
+            .db_repository
+            .get_scheduled_entrypoints()
+            .await?
+            .into_iter()
+            .filter_map(|entrypoint| {
+                // negative values can only come from manually edited database
+                let interval_seconds = u64::try_from(entrypoint.interval_seconds).ok()?;
+
+                Some(ScheduledEntrypoint {
+                    plugin_id: PluginId::from_string(entrypoint.plugin_id),
+                    entrypoint_id: EntrypointId::from_string(entrypoint.entrypoint_id),
+                    interval: Duration::from_secs(interval_seconds),
+                })
+            })
+            .filter(|entrypoint| self.run_status_holder.is_plugin_running(&entrypoint.plugin_id))
+            .collect();
+
+        for (plugin_id, entrypoint_id) in scheduler.due_entrypoints(scheduled) {
+            tracing::info!(
+                target = "plugin",
+                "Running scheduled entrypoint {:?} of plugin {:?}",
+                entrypoint_id,
+                plugin_id
+            );
+
+            self.send_command(PluginCommand::One {
+                id: plugin_id,
+                data: OnePluginCommandData::RunScheduledEntrypoint { entrypoint_id },
+            });
+        }
+
+        Ok(())
+    }
+
     pub fn request_search_index_refresh(&self, plugin_id: PluginId) {
         self.send_command(PluginCommand::One {
             id: plugin_id,
@@ -810,52 +1208,35 @@ impl ApplicationManager {
 
         let receiver = self.command_broadcaster.subscribe();
 
-        let clipboard_permissions = plugin
-            .permissions
-            .clipboard
-            .into_iter()
-            .map(|permission| {
-                match permission {
-                    DbPluginClipboardPermissions::Read => PluginPermissionsClipboard::Read,
-                    DbPluginClipboardPermissions::Write => PluginPermissionsClipboard::Write,
-                    DbPluginClipboardPermissions::Clear => PluginPermissionsClipboard::Clear,
-                }
-            })
-            .collect();
+        let permissions = plugin_effective_permissions(&plugin.permissions, plugin.permissions_user_data);
 
-        let main_search_bar_permissions = plugin
-            .permissions
-            .main_search_bar
-            .into_iter()
-            .map(|permission| {
-                match permission {
-                    DbPluginMainSearchBarPermissions::Read => JsPluginPermissionsMainSearchBar::Read,
-                }
-            })
-            .collect();
+        let inspector = if plugin_id_str.starts_with("file://") {
+            let inspectors = self.inspectors.lock().expect("lock is poisoned");
+
+            let inspector = inspectors.get(&plugin_id).cloned().unwrap_or(PluginInspector {
+                port: None,
+                break_on_start: false,
+            });
+
+            self.inspector_addresses.reset(&plugin_id);
+
+            Some(inspector)
+        } else {
+            None
+        };
 
         let data = PluginRuntimeData {
             id: plugin_id,
             uuid: plugin.uuid,
             name: plugin.name,
             entrypoint_names,
-            code: JsPluginCode { js: plugin.code.js },
-            inline_view_entrypoint_id,
-            permissions: PluginPermissions {
-                environment: plugin.permissions.environment,
-                network: plugin.permissions.network,
-                filesystem: JsPluginPermissionsFileSystem {
-                    read: plugin.permissions.filesystem.read,
-                    write: plugin.permissions.filesystem.write,
-                },
-                exec: JsPluginPermissionsExec {
-                    command: plugin.permissions.exec.command,
-                    executable: plugin.permissions.exec.executable,
-                },
-                system: plugin.permissions.system,
-                clipboard: clipboard_permissions,
-                main_search_bar: main_search_bar_permissions,
+            code: JsPluginCode {
+                js: plugin.code.js,
+                source_maps: plugin.code.source_maps,
             },
+            inline_view_entrypoint_id,
+            permissions,
+            inspector,
             command_receiver: receiver,
             db_repository: self.db_repository.clone(),
             search_index: self.search_index.clone(),
@@ -863,6 +1244,11 @@ impl ApplicationManager {
             frontend_api: self.frontend_api.clone(),
             dirs: self.dirs.clone(),
             clipboard: self.clipboard.clone(),
+            command_sender: self.command_broadcaster.clone(),
+            plugin_function_calls: self.plugin_function_calls.clone(),
+            inspector_addresses: self.inspector_addresses.clone(),
+            run_status_holder: self.run_status_holder.clone(),
+            plugin_storage: self.plugin_storage.clone(),
         };
 
         self.start_plugin_runtime(data);
@@ -919,6 +1305,104 @@ impl ApplicationManager {
 
         Ok(result)
     }
+
+    pub async fn list_plugin_directory(
+        &self,
+        plugin_id: PluginId,
+        path: Option<String>,
+    ) -> anyhow::Result<FilePickerDirectory> {
+        let plugin = self.db_repository.get_plugin_by_id(&plugin_id.to_string()).await?;
+
+        let permissions = plugin_effective_permissions(&plugin.permissions, plugin.permissions_user_data);
+
+        let read_paths = read_permission_paths(
+            &permissions.filesystem.read,
+            &self.dirs.home_dir(),
+            &self.dirs.plugin_data(&plugin.uuid)?,
+            &self.dirs.plugin_cache(&plugin.uuid)?,
+        )?;
+
+        // canonicalize and read_dir can block on slow or network file systems
+        tokio::task::spawn_blocking(move || list_directory(read_paths, path)).await?
+    }
+}
+
+fn list_directory(read_paths: Vec<PathBuf>, path: Option<String>) -> anyhow::Result<FilePickerDirectory> {
+    let Some(path) = path else {
+        let entries = read_paths
+            .iter()
+            .filter(|read_path| read_path.exists())
+            .filter_map(|read_path| {
+                let read_path_str = read_path.to_str()?.to_string();
+
+                Some(FilePickerEntry {
+                    name: read_path_str.clone(),
+                    path: read_path_str,
+                    is_directory: read_path.is_dir(),
+                })
+            })
+            .collect();
+
+        return Ok(FilePickerDirectory {
+            path: None,
+            parent: None,
+            entries,
+        });
+    };
+
+    // canonicalize to not allow escaping read paths using ".." or symlinks
+    let path = PathBuf::from(path).canonicalize()?;
+
+    let read_paths = read_paths
+        .into_iter()
+        .filter_map(|read_path| read_path.canonicalize().ok())
+        .collect::<Vec<_>>();
+
+    let Some(read_path) = read_paths.iter().find(|read_path| path.starts_with(read_path)) else {
+        return Err(anyhow!(
+            "Path {:?} is not allowed by \"filesystem.read\" permission of the plugin",
+            path
+        ));
+    };
+
+    let mut entries = vec![];
+
+    for entry in std::fs::read_dir(&path)? {
+        let entry = entry?;
+        let entry_path = entry.path();
+
+        // path is sent back when entry is selected, so entries which path cannot be represented are skipped
+        let Some(entry_path_str) = entry_path.to_str() else {
+            continue;
+        };
+
+        entries.push(FilePickerEntry {
+            name: entry.file_name().to_string_lossy().to_string(),
+            path: entry_path_str.to_string(),
+            is_directory: entry_path.is_dir(),
+        });
+    }
+
+    entries.sort_by_key(|entry| (!entry.is_directory, entry.name.to_lowercase()));
+
+    // going up from read path itself goes back to the list of read paths
+    let parent = if &path == read_path {
+        None
+    } else {
+        path.parent().map(|parent| path_to_string(parent)).transpose()?
+    };
+
+    Ok(FilePickerDirectory {
+        path: Some(path_to_string(&path)?),
+        parent,
+        entries,
+    })
+}
+
+fn path_to_string(path: &Path) -> anyhow::Result<String> {
+    let path = path.to_str().context("non-utf8 paths are not supported")?.to_string();
+
+    Ok(path)
 }
 
 fn plugin_preference_from_db(id: &str, value: DbPluginPreference) -> PluginPreference {
@@ -1050,3 +1534,191 @@ fn plugin_preference_user_data_from_db(value: DbPluginPreferenceUserData) -> Plu
         DbPluginPreferenceUserData::ListOfEnums { value, .. } => PluginPreferenceUserData::ListOfEnums { value },
     }
 }
+
+fn plugin_permissions_from_db(permissions: &DbPluginPermissions) -> Vec<PluginPermission> {
+    let mut result = vec![];
+
+    let mut push = |kind: PluginPermissionKind, resources: &[String]| {
+        for resource in resources {
+            result.push(PluginPermission {
+                kind,
+                resource: resource.clone(),
+            })
+        }
+    };
+
+    push(PluginPermissionKind::Environment, &permissions.environment);
+    push(PluginPermissionKind::Network, &permissions.network);
+    push(PluginPermissionKind::FilesystemRead, &permissions.filesystem.read);
+    push(PluginPermissionKind::FilesystemWrite, &permissions.filesystem.write);
+    push(PluginPermissionKind::ExecCommand, &permissions.exec.command);
+    push(PluginPermissionKind::ExecExecutable, &permissions.exec.executable);
+    push(PluginPermissionKind::System, &permissions.system);
+    push(PluginPermissionKind::Plugins, &permissions.plugins);
+
+    for permission in &permissions.clipboard {
+        let resource = match permission {
+            DbPluginClipboardPermissions::Read => "read",
+            DbPluginClipboardPermissions::Write => "write",
+            DbPluginClipboardPermissions::Clear => "clear",
+        };
+
+        result.push(PluginPermission {
+            kind: PluginPermissionKind::Clipboard,
+            resource: resource.to_string(),
+        })
+    }
+
+    for permission in &permissions.main_search_bar {
+        let resource = match permission {
+            DbPluginMainSearchBarPermissions::Read => "read",
+        };
+
+        result.push(PluginPermission {
+            kind: PluginPermissionKind::MainSearchBar,
+            resource: resource.to_string(),
+        })
+    }
+
+    result
+}
+
+// declared permissions minus the ones revoked by user plus the ones granted by user,
+// revoked permissions are also passed as denied, so they are not prompted for in "ask" mode
+fn plugin_effective_permissions(
+    declared: &DbPluginPermissions,
+    user_data: DbPluginPermissionsUserData,
+) -> PluginPermissions {
+    let revoked = user_data
+        .revoked
+        .into_iter()
+        .map(|permission| plugin_permission_from_db(permission))
+        .collect::<Vec<_>>();
+
+    let granted = user_data
+        .granted
+        .into_iter()
+        .map(|permission| plugin_permission_from_db(permission));
+
+    let mut result = PluginPermissions {
+        environment: vec![],
+        network: vec![],
+        filesystem: JsPluginPermissionsFileSystem {
+            read: vec![],
+            write: vec![],
+        },
+        exec: JsPluginPermissionsExec {
+            command: vec![],
+            executable: vec![],
+        },
+        system: vec![],
+        clipboard: vec![],
+        main_search_bar: vec![],
+        plugins: vec![],
+        denied: JsPluginPermissionsDenied {
+            environment: vec![],
+            network: vec![],
+            filesystem: JsPluginPermissionsFileSystem {
+                read: vec![],
+                write: vec![],
+            },
+            exec: JsPluginPermissionsExec {
+                command: vec![],
+                executable: vec![],
+            },
+            system: vec![],
+        },
+        ask: user_data.ask,
+    };
+
+    let permissions = plugin_permissions_from_db(declared)
+        .into_iter()
+        .filter(|permission| !revoked.contains(permission))
+        .chain(granted);
+
+    for PluginPermission { kind, resource } in permissions {
+        match kind {
+            PluginPermissionKind::Environment => result.environment.push(resource),
+            PluginPermissionKind::Network => result.network.push(resource),
+            PluginPermissionKind::FilesystemRead => result.filesystem.read.push(resource),
+            PluginPermissionKind::FilesystemWrite => result.filesystem.write.push(resource),
+            PluginPermissionKind::ExecCommand => result.exec.command.push(resource),
+            PluginPermissionKind::ExecExecutable => result.exec.executable.push(resource),
+            PluginPermissionKind::System => result.system.push(resource),
+            PluginPermissionKind::Clipboard => {
+                match resource.as_str() {
+                    "read" => result.clipboard.push(PluginPermissionsClipboard::Read),
+                    "write" => result.clipboard.push(PluginPermissionsClipboard::Write),
+                    "clear" => result.clipboard.push(PluginPermissionsClipboard::Clear),
+                    _ => {}
+                }
+            }
+            PluginPermissionKind::MainSearchBar => {
+                match resource.as_str() {
+                    "read" => result.main_search_bar.push(JsPluginPermissionsMainSearchBar::Read),
+                    _ => {}
+                }
+            }
+            PluginPermissionKind::Plugins => result.plugins.push(resource),
+        }
+    }
+
+    // clipboard, main search bar and plugins permissions are not checked by deno,
+    // so not having them in the allow list is enough
+    for PluginPermission { kind, resource } in revoked {
+        match kind {
+            PluginPermissionKind::Environment => result.denied.environment.push(resource),
+            PluginPermissionKind::Network => result.denied.network.push(resource),
+            PluginPermissionKind::FilesystemRead => result.denied.filesystem.read.push(resource),
+            PluginPermissionKind::FilesystemWrite => result.denied.filesystem.write.push(resource),
+            PluginPermissionKind::ExecCommand => result.denied.exec.command.push(resource),
+            PluginPermissionKind::ExecExecutable => result.denied.exec.executable.push(resource),
+            PluginPermissionKind::System => result.denied.system.push(resource),
+            PluginPermissionKind::Clipboard => {}
+            PluginPermissionKind::MainSearchBar => {}
+            PluginPermissionKind::Plugins => {}
+        }
+    }
+
+    result
+}
+
+pub(super) fn plugin_permission_to_db(value: PluginPermission) -> DbPluginPermission {
+    let kind = match value.kind {
+        PluginPermissionKind::Environment => DbPluginPermissionKind::Environment,
+        PluginPermissionKind::Network => DbPluginPermissionKind::Network,
+        PluginPermissionKind::FilesystemRead => DbPluginPermissionKind::FilesystemRead,
+        PluginPermissionKind::FilesystemWrite => DbPluginPermissionKind::FilesystemWrite,
+        PluginPermissionKind::ExecCommand => DbPluginPermissionKind::ExecCommand,
+        PluginPermissionKind::ExecExecutable => DbPluginPermissionKind::ExecExecutable,
+        PluginPermissionKind::System => DbPluginPermissionKind::System,
+        PluginPermissionKind::Clipboard => DbPluginPermissionKind::Clipboard,
+        PluginPermissionKind::MainSearchBar => DbPluginPermissionKind::MainSearchBar,
+        PluginPermissionKind::Plugins => DbPluginPermissionKind::Plugins,
+    };
+
+    DbPluginPermission {
+        kind,
+        resource: value.resource,
+    }
+}
+
+fn plugin_permission_from_db(value: DbPluginPermission) -> PluginPermission {
+    let kind = match value.kind {
+        DbPluginPermissionKind::Environment => PluginPermissionKind::Environment,
+        DbPluginPermissionKind::Network => PluginPermissionKind::Network,
+        DbPluginPermissionKind::FilesystemRead => PluginPermissionKind::FilesystemRead,
+        DbPluginPermissionKind::FilesystemWrite => PluginPermissionKind::FilesystemWrite,
+        DbPluginPermissionKind::ExecCommand => PluginPermissionKind::ExecCommand,
+        DbPluginPermissionKind::ExecExecutable => PluginPermissionKind::ExecExecutable,
+        DbPluginPermissionKind::System => PluginPermissionKind::System,
+        DbPluginPermissionKind::Clipboard => PluginPermissionKind::Clipboard,
+        DbPluginPermissionKind::MainSearchBar => PluginPermissionKind::MainSearchBar,
+        DbPluginPermissionKind::Plugins => PluginPermissionKind::Plugins,
+    };
+
+    PluginPermission {
+        kind,
+        resource: value.resource,
+    }
+}
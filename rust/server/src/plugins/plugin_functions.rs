@@ -0,0 +1,69 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use anyhow::anyhow;
+use gauntlet_common::model::PluginId;
+use tokio::sync::oneshot;
+use uuid::Uuid;
+
+// calls of functions exported by other plugins, which are waiting for result from plugin that exports the function
+#[derive(Clone)]
+pub struct PluginFunctionCalls {
+    pending: Arc<Mutex<HashMap<String, PendingPluginFunctionCall>>>,
+}
+
+struct PendingPluginFunctionCall {
+    plugin_id: PluginId,
+    sender: oneshot::Sender<Result<String, String>>,
+}
+
+impl PluginFunctionCalls {
+    pub fn new() -> Self {
+        Self {
+            pending: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    pub fn start(&self, plugin_id: PluginId) -> (String, oneshot::Receiver<Result<String, String>>) {
+        let call_id = Uuid::new_v4().to_string();
+
+        let (sender, receiver) = oneshot::channel();
+
+        let mut pending = self.pending.lock().expect("lock is poisoned");
+
+        pending.insert(call_id.clone(), PendingPluginFunctionCall { plugin_id, sender });
+
+        (call_id, receiver)
+    }
+
+    pub fn finish(&self, plugin_id: &PluginId, call_id: &str, result: Result<String, String>) -> anyhow::Result<()> {
+        let mut pending = self.pending.lock().expect("lock is poisoned");
+
+        // only plugin that was asked to run the function can provide the result
+        match pending.get(call_id) {
+            Some(call) if &call.plugin_id == plugin_id => {}
+            Some(_) | None => return Err(anyhow!("Unknown plugin function call: {}", call_id)),
+        }
+
+        let call = pending.remove(call_id).expect("presence checked above");
+
+        // caller may have already given up waiting
+        let _ = call.sender.send(result);
+
+        Ok(())
+    }
+
+    pub fn cancel(&self, call_id: &str) {
+        let mut pending = self.pending.lock().expect("lock is poisoned");
+
+        pending.remove(call_id);
+    }
+
+    // plugin that was asked to run the functions has stopped, dropping the senders cancels the calls
+    pub fn cancel_all(&self, plugin_id: &PluginId) {
+        let mut pending = self.pending.lock().expect("lock is poisoned");
+
+        pending.retain(|_, call| &call.plugin_id != plugin_id);
+    }
+}
@@ -6,6 +6,7 @@ use gauntlet_common::model::PluginId;
 use tokio_util::sync::CancellationToken;
 use tokio_util::sync::WaitForCancellationFutureOwned;
 
+#[derive(Clone)]
 pub struct RunStatusHolder {
     running_plugins: Arc<Mutex<HashMap<PluginId, CancellationToken>>>,
 }
@@ -0,0 +1,219 @@
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+use std::hash::BuildHasher;
+use std::hash::Hasher;
+use std::time::Duration;
+use std::time::Instant;
+use std::time::SystemTime;
+
+use gauntlet_common::model::EntrypointId;
+use gauntlet_common::model::PluginId;
+#[cfg(target_os = "macos")]
+use objc2_core_graphics::CGEventSource;
+#[cfg(target_os = "macos")]
+use objc2_core_graphics::CGEventSourceStateID;
+#[cfg(target_os = "macos")]
+use objc2_core_graphics::CGEventType;
+#[cfg(target_os = "windows")]
+use windows::Win32::System::SystemInformation::GetTickCount;
+#[cfg(target_os = "windows")]
+use windows::Win32::UI::Input::KeyboardAndMouse::GetLastInputInfo;
+#[cfg(target_os = "windows")]
+use windows::Win32::UI::Input::KeyboardAndMouse::LASTINPUTINFO;
+#[cfg(target_os = "linux")]
+use x11rb::connection::Connection;
+#[cfg(target_os = "linux")]
+use x11rb::protocol::screensaver;
+#[cfg(target_os = "linux")]
+use x11rb::rust_connection::RustConnection;
+
+pub const SCHEDULER_TICK: Duration = Duration::from_secs(30);
+
+// tick that arrives much later than expected means that machine was asleep
+const SLEEP_DETECTION_THRESHOLD: Duration = Duration::from_secs(60);
+
+const IDLE_THRESHOLD: Duration = Duration::from_secs(10 * 60);
+
+const MAX_JITTER: Duration = Duration::from_secs(5 * 60);
+
+pub struct ScheduledEntrypoint {
+    pub plugin_id: PluginId,
+    pub entrypoint_id: EntrypointId,
+    pub interval: Duration,
+}
+
+struct ScheduleState {
+    interval: Duration,
+    next_run: Instant,
+}
+
+pub struct EntrypointScheduler {
+    schedule: HashMap<(PluginId, EntrypointId), ScheduleState>,
+    last_tick: Option<(Instant, SystemTime)>,
+}
+
+impl EntrypointScheduler {
+    pub fn new() -> Self {
+        Self {
+            schedule: HashMap::new(),
+            last_tick: None,
+        }
+    }
+
+    // expects only entrypoints of running plugins, returns entrypoints that should be run now
+    pub fn due_entrypoints(&mut self, scheduled: Vec<ScheduledEntrypoint>) -> Vec<(PluginId, EntrypointId)> {
+        let now = Instant::now();
+        let now_wall = SystemTime::now();
+
+        let woke_up = self.detect_wake_up(now, now_wall);
+
+        self.last_tick = Some((now, now_wall));
+
+        let mut schedule = HashMap::new();
+
+        for entrypoint in scheduled {
+            let key = (entrypoint.plugin_id, entrypoint.entrypoint_id);
+
+            let state = match self.schedule.remove(&key) {
+                Some(state) if state.interval == entrypoint.interval => state,
+                _ => {
+                    // first run happens after one interval, plugin start already did the initial work
+                    ScheduleState {
+                        interval: entrypoint.interval,
+                        next_run: now + entrypoint.interval + jitter(entrypoint.interval),
+                    }
+                }
+            };
+
+            schedule.insert(key, state);
+        }
+
+        // entrypoints that are not scheduled anymore are dropped here
+        self.schedule = schedule;
+
+        if woke_up {
+            tracing::debug!("Machine woke up from sleep, spreading out overdue scheduled entrypoints");
+
+            for state in self.schedule.values_mut() {
+                if state.next_run <= now {
+                    state.next_run = now + jitter(state.interval);
+                }
+            }
+
+            return vec![];
+        }
+
+        if is_user_idle() {
+            tracing::trace!("User is idle, skipping scheduled entrypoints");
+
+            return vec![];
+        }
+
+        let mut due = vec![];
+
+        for ((plugin_id, entrypoint_id), state) in self.schedule.iter_mut() {
+            if state.next_run <= now {
+                state.next_run = now + state.interval + jitter(state.interval);
+
+                due.push((plugin_id.clone(), entrypoint_id.clone()));
+            }
+        }
+
+        due
+    }
+
+    fn detect_wake_up(&self, now: Instant, now_wall: SystemTime) -> bool {
+        let Some((last_tick, last_tick_wall)) = self.last_tick else {
+            return false;
+        };
+
+        // monotonic clock doesn't advance during sleep on some platforms, wall clock does
+        let elapsed = now.duration_since(last_tick);
+        let elapsed_wall = now_wall.duration_since(last_tick_wall).unwrap_or_default();
+
+        elapsed.max(elapsed_wall) > SCHEDULER_TICK + SLEEP_DETECTION_THRESHOLD
+    }
+}
+
+// up to 10% of interval, to avoid all plugins doing their work at the same moment
+fn jitter(interval: Duration) -> Duration {
+    let max_jitter = (interval / 10).min(MAX_JITTER);
+
+    let random = RandomState::new().build_hasher().finish();
+
+    let fraction = (random % 1000) as u32;
+
+    max_jitter * fraction / 1000
+}
+
+fn is_user_idle() -> bool {
+    match user_idle_time() {
+        Some(idle_time) => idle_time >= IDLE_THRESHOLD,
+        None => false,
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn user_idle_time() -> Option<Duration> {
+    // kCGAnyInputEventType is a macro in CoreGraphics headers, so it is not generated
+    let any_input_event_type = CGEventType(u32::MAX);
+
+    let seconds = unsafe {
+        CGEventSource::seconds_since_last_event_type(CGEventSourceStateID::HIDSystemState, any_input_event_type)
+    };
+
+    Duration::try_from_secs_f64(seconds).ok()
+}
+
+#[cfg(target_os = "windows")]
+fn user_idle_time() -> Option<Duration> {
+    let mut info = LASTINPUTINFO {
+        cbSize: std::mem::size_of::<LASTINPUTINFO>() as u32,
+        dwTime: 0,
+    };
+
+    let result = unsafe { GetLastInputInfo(&mut info) };
+    if !result.as_bool() {
+        return None;
+    }
+
+    let now = unsafe { GetTickCount() };
+
+    Some(Duration::from_millis(now.wrapping_sub(info.dwTime) as u64))
+}
+
+// x11 only, wayland doesn't have compositor independent way to get idle time and xwayland only sees input
+// to x11 windows, so on wayland user is never considered idle
+#[cfg(target_os = "linux")]
+fn user_idle_time() -> Option<Duration> {
+    let wayland = std::env::var("WAYLAND_DISPLAY")
+        .or_else(|_| std::env::var("WAYLAND_SOCKET"))
+        .is_ok();
+
+    if wayland {
+        return None;
+    }
+
+    match x11_user_idle_time() {
+        Ok(idle_time) => Some(idle_time),
+        Err(err) => {
+            tracing::debug!("Unable to get user idle time from x11 server: {:?}", err);
+            None
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn x11_user_idle_time() -> anyhow::Result<Duration> {
+    let (conn, screen_num) = RustConnection::connect(None)?;
+    let screen = &conn.setup().roots[screen_num];
+
+    let info = screensaver::query_info(&conn, screen.root)?.reply()?;
+
+    Ok(Duration::from_millis(info.ms_since_user_input as u64))
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "linux")))]
+fn user_idle_time() -> Option<Duration> {
+    None
+}
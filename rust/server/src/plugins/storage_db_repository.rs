@@ -0,0 +1,263 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use anyhow::Context;
+use gauntlet_common::dirs::Dirs;
+use sqlx::sqlite::SqliteConnectOptions;
+use sqlx::Pool;
+use sqlx::Sqlite;
+use sqlx::SqlitePool;
+use tokio::sync::Mutex;
+
+const STORAGE_FILE_NAME: &str = "storage.sqlite";
+// sqlx opens sqlite databases in wal mode, recent writes live in these files until checkpoint
+const STORAGE_FILE_SUFFIXES: [&str; 3] = ["", "-wal", "-shm"];
+
+// key-value storage of each plugin lives in separate sqlite file in plugin data directory,
+// so it can be inspected and cleared independently of main database
+#[derive(Clone)]
+pub struct PluginStorageRepository {
+    dirs: Dirs,
+    pools: Arc<Mutex<HashMap<String, Pool<Sqlite>>>>,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct DbStorageEntry {
+    pub key: String,
+    pub value: String,
+}
+
+#[derive(Debug)]
+pub enum DbStorageOperation {
+    Set {
+        key: String,
+        value: String,
+        ttl_seconds: Option<u64>,
+    },
+    Delete {
+        key: String,
+    },
+}
+
+impl PluginStorageRepository {
+    pub fn new(dirs: Dirs) -> Self {
+        Self {
+            dirs,
+            pools: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    fn storage_file(&self, plugin_uuid: &str) -> anyhow::Result<PathBuf> {
+        Ok(self.dirs.plugin_data(plugin_uuid)?.join(STORAGE_FILE_NAME))
+    }
+
+    fn storage_files(&self, plugin_uuid: &str) -> anyhow::Result<Vec<PathBuf>> {
+        let storage_file = self.storage_file(plugin_uuid)?;
+
+        let files = STORAGE_FILE_SUFFIXES
+            .iter()
+            .map(|suffix| {
+                let mut file = storage_file.clone().into_os_string();
+                file.push(suffix);
+                PathBuf::from(file)
+            })
+            .collect();
+
+        Ok(files)
+    }
+
+    async fn pool(&self, plugin_uuid: &str) -> anyhow::Result<Pool<Sqlite>> {
+        let mut pools = self.pools.lock().await;
+
+        if let Some(pool) = pools.get(plugin_uuid) {
+            return Ok(pool.clone());
+        }
+
+        let storage_file = self.storage_file(plugin_uuid)?;
+
+        std::fs::create_dir_all(&storage_file.parent().unwrap()).context("Unable to create plugin data directory")?;
+
+        let conn = SqliteConnectOptions::new()
+            .filename(storage_file)
+            .create_if_missing(true);
+
+        let pool = SqlitePool::connect_with(conn)
+            .await
+            .context("Unable to open plugin storage database connection")?;
+
+        // language=SQLite
+        let sql = r#"
+            CREATE TABLE IF NOT EXISTS storage_entry (
+                namespace  TEXT    NOT NULL,
+                key        TEXT    NOT NULL,
+                value      TEXT    NOT NULL,
+                expires_at INTEGER,
+                PRIMARY KEY (namespace, key)
+            )
+        "#;
+
+        sqlx::query(sql).execute(&pool).await?;
+
+        // language=SQLite
+        sqlx::query("DELETE FROM storage_entry WHERE expires_at <= ?1")
+            .bind(now())
+            .execute(&pool)
+            .await?;
+
+        pools.insert(plugin_uuid.to_string(), pool.clone());
+
+        Ok(pool)
+    }
+
+    pub async fn get(&self, plugin_uuid: &str, namespace: &str, key: &str) -> anyhow::Result<Option<String>> {
+        let pool = self.pool(plugin_uuid).await?;
+
+        // language=SQLite
+        let sql = r#"
+            SELECT value FROM storage_entry
+                WHERE namespace = ?1 AND key = ?2 AND (expires_at IS NULL OR expires_at > ?3)
+        "#;
+
+        let value = sqlx::query_as::<_, (String,)>(sql)
+            .bind(namespace)
+            .bind(key)
+            .bind(now())
+            .fetch_optional(&pool)
+            .await?
+            .map(|(value,)| value);
+
+        Ok(value)
+    }
+
+    pub async fn list(&self, plugin_uuid: &str, namespace: &str, prefix: &str) -> anyhow::Result<Vec<DbStorageEntry>> {
+        let pool = self.pool(plugin_uuid).await?;
+
+        // substr is used instead of LIKE, so prefix doesn't need escaping
+        // language=SQLite
+        let sql = r#"
+            SELECT key, value FROM storage_entry
+                WHERE namespace = ?1 AND substr(key, 1, length(?2)) = ?2 AND (expires_at IS NULL OR expires_at > ?3)
+                ORDER BY key
+        "#;
+
+        let entries = sqlx::query_as::<_, DbStorageEntry>(sql)
+            .bind(namespace)
+            .bind(prefix)
+            .bind(now())
+            .fetch_all(&pool)
+            .await?;
+
+        Ok(entries)
+    }
+
+    pub async fn clear_namespace(&self, plugin_uuid: &str, namespace: &str) -> anyhow::Result<()> {
+        let pool = self.pool(plugin_uuid).await?;
+
+        // language=SQLite
+        sqlx::query("DELETE FROM storage_entry WHERE namespace = ?1")
+            .bind(namespace)
+            .execute(&pool)
+            .await?;
+
+        Ok(())
+    }
+
+    // single set or delete is a transaction with one operation
+    pub async fn apply(
+        &self,
+        plugin_uuid: &str,
+        namespace: &str,
+        operations: Vec<DbStorageOperation>,
+    ) -> anyhow::Result<()> {
+        let pool = self.pool(plugin_uuid).await?;
+
+        let mut tx = pool.begin().await?;
+
+        for operation in operations {
+            match operation {
+                DbStorageOperation::Set {
+                    key,
+                    value,
+                    ttl_seconds,
+                } => {
+                    // very large ttl means entry never expires in practice
+                    let expires_at = ttl_seconds.map(|ttl_seconds| {
+                        let ttl_millis = i64::try_from(ttl_seconds.saturating_mul(1000)).unwrap_or(i64::MAX);
+
+                        now().saturating_add(ttl_millis)
+                    });
+
+                    // language=SQLite
+                    let sql = r#"
+                        INSERT INTO storage_entry (namespace, key, value, expires_at) VALUES (?1, ?2, ?3, ?4)
+                            ON CONFLICT (namespace, key) DO UPDATE SET value = ?3, expires_at = ?4
+                    "#;
+
+                    sqlx::query(sql)
+                        .bind(namespace)
+                        .bind(key)
+                        .bind(value)
+                        .bind(expires_at)
+                        .execute(&mut *tx)
+                        .await?;
+                }
+                DbStorageOperation::Delete { key } => {
+                    // language=SQLite
+                    sqlx::query("DELETE FROM storage_entry WHERE namespace = ?1 AND key = ?2")
+                        .bind(namespace)
+                        .bind(key)
+                        .execute(&mut *tx)
+                        .await?;
+                }
+            }
+        }
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    // size of the database files in bytes
+    pub fn usage(&self, plugin_uuid: &str) -> anyhow::Result<u64> {
+        let mut usage = 0;
+
+        for storage_file in self.storage_files(plugin_uuid)? {
+            match std::fs::metadata(storage_file) {
+                Ok(metadata) => usage += metadata.len(),
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+                Err(err) => return Err(err.into()),
+            }
+        }
+
+        Ok(usage)
+    }
+
+    pub async fn clear(&self, plugin_uuid: &str) -> anyhow::Result<()> {
+        let mut pools = self.pools.lock().await;
+
+        // connections need to be closed before file is removed, next access will create empty database
+        if let Some(pool) = pools.remove(plugin_uuid) {
+            pool.close().await;
+        }
+
+        for storage_file in self.storage_files(plugin_uuid)? {
+            match std::fs::remove_file(storage_file) {
+                Ok(()) => {}
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+                Err(err) => return Err(err.into()),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("time went backwards")
+        .as_millis() as i64
+}
@@ -7,7 +7,9 @@ use gauntlet_common::model::EntrypointId;
 use gauntlet_common::model::LocalSaveData;
 use gauntlet_common::model::PhysicalKey;
 use gauntlet_common::model::PhysicalShortcut;
+use gauntlet_common::model::PluginAuditLogEntry;
 use gauntlet_common::model::PluginId;
+use gauntlet_common::model::PluginPermission;
 use gauntlet_common::model::PluginPreferenceUserData;
 use gauntlet_common::model::SearchResult;
 use gauntlet_common::model::SettingsPlugin;
@@ -34,8 +36,8 @@ impl BackendServerImpl {
 
 #[tonic::async_trait]
 impl BackendServer for BackendServerImpl {
-    async fn show_window(&self) -> anyhow::Result<()> {
-        self.application_manager.show_window().await
+    async fn show_window(&self, input: Option<String>) -> anyhow::Result<()> {
+        self.application_manager.show_window(input).await
     }
 
     async fn show_settings_window(&self) -> anyhow::Result<()> {
@@ -57,6 +59,12 @@ impl BackendServer for BackendServerImpl {
         Ok(())
     }
 
+    async fn open_deep_link(&self, link: String) -> anyhow::Result<()> {
+        self.application_manager.open_deep_link(link).await?;
+
+        Ok(())
+    }
+
     async fn plugins(&self) -> anyhow::Result<Vec<SettingsPlugin>> {
         let result = self.application_manager.plugins().await;
 
@@ -170,6 +178,29 @@ impl BackendServer for BackendServerImpl {
         Ok(())
     }
 
+    async fn set_plugin_permissions(
+        &self,
+        plugin_id: PluginId,
+        revoked: Vec<PluginPermission>,
+        ask: bool,
+        granted: Vec<PluginPermission>,
+    ) -> anyhow::Result<()> {
+        let result = self
+            .application_manager
+            .set_plugin_permissions(plugin_id, revoked, ask, granted)
+            .await;
+
+        if let Err(err) = &result {
+            tracing::warn!(
+                target = "rpc",
+                "error occurred when handling 'set_plugin_permissions' request {:?}",
+                err
+            )
+        }
+
+        Ok(())
+    }
+
     async fn download_plugin(&self, plugin_id: PluginId) -> anyhow::Result<()> {
         let result = self.application_manager.download_plugin(plugin_id).await;
 
@@ -202,8 +233,44 @@ impl BackendServer for BackendServerImpl {
         Ok(())
     }
 
-    async fn save_local_plugin(&self, path: String) -> anyhow::Result<LocalSaveData> {
-        let result = self.application_manager.save_local_plugin(&path).await?;
+    async fn clear_plugin_storage(&self, plugin_id: PluginId) -> anyhow::Result<()> {
+        let result = self.application_manager.clear_plugin_storage(plugin_id).await;
+
+        if let Err(err) = &result {
+            tracing::warn!(
+                target = "rpc",
+                "error occurred when handling 'clear_plugin_storage' request {:?}",
+                err
+            )
+        }
+
+        Ok(())
+    }
+
+    async fn plugin_audit_log(&self, plugin_id: PluginId) -> anyhow::Result<Vec<PluginAuditLogEntry>> {
+        let result = self.application_manager.plugin_audit_log(plugin_id).await;
+
+        if let Err(err) = &result {
+            tracing::warn!(
+                target = "rpc",
+                "error occurred when handling 'plugin_audit_log' request {:?}",
+                err
+            )
+        }
+
+        result
+    }
+
+    async fn save_local_plugin(
+        &self,
+        path: String,
+        inspector_port: Option<u16>,
+        inspector_break_on_start: bool,
+    ) -> anyhow::Result<LocalSaveData> {
+        let result = self
+            .application_manager
+            .save_local_plugin(&path, inspector_port, inspector_break_on_start)
+            .await?;
 
         Ok(result)
     }
@@ -11,6 +11,8 @@ use gauntlet_common::model::SearchResult;
 use gauntlet_common::model::SearchResultAccessory;
 use gauntlet_common::model::SearchResultEntrypointAction;
 use gauntlet_common::model::SearchResultEntrypointActionType;
+use gauntlet_common::model::SearchResultEntrypointArgument;
+use gauntlet_common::model::SearchResultEntrypointInputKind;
 use gauntlet_common::model::SearchResultEntrypointType;
 use gauntlet_common::rpc::frontend_api::FrontendApi;
 use tantivy::collector::TopDocs;
@@ -57,6 +59,8 @@ struct EntrypointData {
     frecency: f64,
     actions: Vec<EntrypointActionData>,
     accessories: Vec<SearchResultAccessory>,
+    arguments: Vec<SearchResultEntrypointArgument>,
+    accepts_input: Vec<SearchResultEntrypointInputKind>,
 }
 
 struct EntrypointActionData {
@@ -101,6 +105,8 @@ pub struct SearchIndexItem {
     pub entrypoint_frecency: f64,
     pub entrypoint_actions: Vec<SearchIndexItemAction>,
     pub entrypoint_accessories: Vec<SearchResultAccessory>,
+    pub entrypoint_arguments: Vec<SearchResultEntrypointArgument>,
+    pub entrypoint_accepts_input: Vec<SearchResultEntrypointInputKind>,
 }
 
 #[derive(Clone, Debug)]
@@ -207,38 +213,7 @@ impl SearchIndex {
         index_writer.commit()?;
         self.index_reader.reload()?;
 
-        let data = search_items
-            .into_iter()
-            .map(|item| {
-                let actions = item
-                    .entrypoint_actions
-                    .into_iter()
-                    .map(|action| {
-                        EntrypointActionData {
-                            id: action.id,
-                            label: action.label,
-                            action_type: match action.action_type {
-                                SearchIndexItemActionActionType::Command => EntrypointActionType::Command,
-                                SearchIndexItemActionActionType::View => EntrypointActionType::View,
-                            },
-                            shortcut: action.shortcut,
-                        }
-                    })
-                    .collect();
-
-                let data = EntrypointData {
-                    entrypoint_name: item.entrypoint_name,
-                    entrypoint_generator_name: item.entrypoint_generator_name,
-                    entrypoint_type: item.entrypoint_type,
-                    icon: item.entrypoint_icon,
-                    frecency: item.entrypoint_frecency,
-                    actions,
-                    accessories: item.entrypoint_accessories,
-                };
-
-                (item.entrypoint_id.clone(), data)
-            })
-            .collect();
+        let data = search_items.into_iter().map(entrypoint_data_from_item).collect();
 
         entrypoint_data.insert(
             plugin_id.clone(),
@@ -267,6 +242,103 @@ impl SearchIndex {
         Ok(())
     }
 
+    // unlike save_for_plugin, only documents of given entrypoints are touched,
+    // and frontend is sent just the changed rows instead of being asked to redo the search
+    pub fn update_for_plugin(
+        &self,
+        plugin_id: PluginId,
+        plugin_name: String,
+        upserted_items: Vec<SearchIndexItem>,
+        removed_entrypoint_ids: Vec<EntrypointId>,
+    ) -> tantivy::Result<()> {
+        tracing::debug!(
+            "Updating {} and removing {} entrypoints in search index for plugin {:?}",
+            upserted_items.len(),
+            removed_entrypoint_ids.len(),
+            plugin_id
+        );
+
+        // writer panics if another writer exists
+        let _guard = self.index_writer_mutex.lock().expect("lock is poisoned");
+        let mut entrypoint_data = self.entrypoint_data.lock().expect("lock is poisoned");
+
+        let mut index_writer = self.index.writer::<TantivyDocument>(15_000_000)?;
+
+        let changed_entrypoint_ids = upserted_items
+            .iter()
+            .map(|item| &item.entrypoint_id)
+            .chain(removed_entrypoint_ids.iter());
+
+        for entrypoint_id in changed_entrypoint_ids {
+            index_writer.delete_query(Box::new(BooleanQuery::intersection(vec![
+                Box::new(TermQuery::new(
+                    Term::from_field_text(self.plugin_id, &plugin_id.to_string()),
+                    IndexRecordOption::Basic,
+                )),
+                Box::new(TermQuery::new(
+                    Term::from_field_text(self.entrypoint_id, &entrypoint_id.to_string()),
+                    IndexRecordOption::Basic,
+                )),
+            ])))?;
+        }
+
+        for search_item in &upserted_items {
+            index_writer.add_document(doc!(
+                self.entrypoint_name => search_item.entrypoint_name.clone(),
+                self.entrypoint_id => search_item.entrypoint_id.to_string(),
+                self.plugin_name => plugin_name.clone(),
+                self.plugin_id => plugin_id.to_string(),
+            ))?;
+        }
+
+        index_writer.commit()?;
+        self.index_reader.reload()?;
+
+        let plugin_data = entrypoint_data.entry(plugin_id.clone()).or_insert_with(|| {
+            PluginData {
+                plugin_name: plugin_name.clone(),
+                entrypoints: HashMap::new(),
+            }
+        });
+
+        for entrypoint_id in &removed_entrypoint_ids {
+            plugin_data.entrypoints.remove(entrypoint_id);
+        }
+
+        let mut updated_results = vec![];
+
+        for item in upserted_items {
+            let (entrypoint_id, data) = entrypoint_data_from_item(item);
+
+            updated_results.push(search_result(&plugin_id, &plugin_name, &entrypoint_id, &data));
+
+            plugin_data.entrypoints.insert(entrypoint_id, data);
+        }
+
+        let frontend_api = self.frontend_api.clone();
+        tokio::spawn(async move {
+            let result = frontend_api
+                .update_search_result_rows(plugin_id, updated_results, removed_entrypoint_ids)
+                .await;
+
+            if let Err(err) = &result {
+                tracing::warn!("error occurred when updating search result rows {:?}", err)
+            }
+        });
+
+        Ok(())
+    }
+
+    pub fn entrypoint_search_result(&self, plugin_id: &PluginId, entrypoint_id: &EntrypointId) -> Option<SearchResult> {
+        let entrypoint_data = self.entrypoint_data.lock().expect("lock is poisoned");
+
+        let plugin_data = entrypoint_data.get(plugin_id)?;
+
+        let data = plugin_data.entrypoints.get(entrypoint_id)?;
+
+        Some(search_result(plugin_id, &plugin_data.plugin_name, entrypoint_id, data))
+    }
+
     pub fn plugin_entrypoint_actions(&self) -> HashMap<PluginId, PluginDataView> {
         let entrypoint_data = self.entrypoint_data.lock().expect("lock is poisoned");
 
@@ -313,6 +385,29 @@ impl SearchIndex {
             .collect()
     }
 
+    pub fn entrypoint_accepts_input(
+        &self,
+        plugin_id: &PluginId,
+        entrypoint_id: &EntrypointId,
+    ) -> Vec<SearchResultEntrypointInputKind> {
+        let entrypoint_data = self.entrypoint_data.lock().expect("lock is poisoned");
+
+        entrypoint_data
+            .get(plugin_id)
+            .and_then(|data| data.entrypoints.get(entrypoint_id))
+            .map(|data| data.accepts_input.clone())
+            .unwrap_or_default()
+    }
+
+    pub fn any_entrypoint_accepts_input(&self) -> bool {
+        let entrypoint_data = self.entrypoint_data.lock().expect("lock is poisoned");
+
+        entrypoint_data
+            .values()
+            .flat_map(|data| data.entrypoints.values())
+            .any(|data| !data.accepts_input.is_empty())
+    }
+
     pub fn search(&self, query: &str) -> anyhow::Result<Vec<SearchResult>> {
         let entrypoint_data = self.entrypoint_data.lock().expect("lock is poisoned");
 
@@ -395,7 +490,6 @@ impl SearchIndex {
 
                 let entrypoint_id = EntrypointId::from_string(get_str_field(&retrieved_doc, self.entrypoint_id));
                 let plugin_id = PluginId::from_string(get_str_field(&retrieved_doc, self.plugin_id));
-                let entrypoint_name = get_str_field(&retrieved_doc, self.entrypoint_name);
                 let plugin_name = get_str_field(&retrieved_doc, self.plugin_name);
 
                 let entrypoint_data = entrypoint_data
@@ -405,34 +499,7 @@ impl SearchIndex {
                     .get(&entrypoint_id)
                     .expect("Entrypoint should always exist in plugin in entrypoint data");
 
-                let entrypoint_actions = entrypoint_data
-                    .actions
-                    .iter()
-                    .map(|data| {
-                        SearchResultEntrypointAction {
-                            action_type: match data.action_type {
-                                EntrypointActionType::Command => SearchResultEntrypointActionType::Command,
-                                EntrypointActionType::View => SearchResultEntrypointActionType::View,
-                            },
-                            label: data.label.clone(),
-                            shortcut: data.shortcut.clone(),
-                        }
-                    })
-                    .collect();
-
-                let entrypoint_accessories = entrypoint_data.accessories.iter().cloned().collect();
-
-                let result_item = SearchResult {
-                    entrypoint_type: entrypoint_data.entrypoint_type.clone(),
-                    entrypoint_name,
-                    entrypoint_generator_name: entrypoint_data.entrypoint_generator_name.clone(),
-                    entrypoint_id,
-                    entrypoint_icon: entrypoint_data.icon.clone(),
-                    plugin_name,
-                    plugin_id,
-                    entrypoint_actions,
-                    entrypoint_accessories,
-                };
+                let result_item = search_result(&plugin_id, &plugin_name, &entrypoint_id, entrypoint_data);
 
                 (result_item, entrypoint_data.frecency)
             })
@@ -442,6 +509,76 @@ impl SearchIndex {
     }
 }
 
+fn entrypoint_data_from_item(item: SearchIndexItem) -> (EntrypointId, EntrypointData) {
+    let actions = item
+        .entrypoint_actions
+        .into_iter()
+        .map(|action| {
+            EntrypointActionData {
+                id: action.id,
+                label: action.label,
+                action_type: match action.action_type {
+                    SearchIndexItemActionActionType::Command => EntrypointActionType::Command,
+                    SearchIndexItemActionActionType::View => EntrypointActionType::View,
+                },
+                shortcut: action.shortcut,
+            }
+        })
+        .collect();
+
+    let data = EntrypointData {
+        entrypoint_name: item.entrypoint_name,
+        entrypoint_generator_name: item.entrypoint_generator_name,
+        entrypoint_type: item.entrypoint_type,
+        icon: item.entrypoint_icon,
+        frecency: item.entrypoint_frecency,
+        actions,
+        accessories: item.entrypoint_accessories,
+        arguments: item.entrypoint_arguments,
+        accepts_input: item.entrypoint_accepts_input,
+    };
+
+    (item.entrypoint_id, data)
+}
+
+fn search_result(
+    plugin_id: &PluginId,
+    plugin_name: &str,
+    entrypoint_id: &EntrypointId,
+    entrypoint_data: &EntrypointData,
+) -> SearchResult {
+    let entrypoint_actions = entrypoint_data
+        .actions
+        .iter()
+        .map(|data| {
+            SearchResultEntrypointAction {
+                action_type: match data.action_type {
+                    EntrypointActionType::Command => SearchResultEntrypointActionType::Command,
+                    EntrypointActionType::View => SearchResultEntrypointActionType::View,
+                },
+                label: data.label.clone(),
+                shortcut: data.shortcut.clone(),
+            }
+        })
+        .collect();
+
+    let entrypoint_accessories = entrypoint_data.accessories.iter().cloned().collect();
+
+    SearchResult {
+        entrypoint_type: entrypoint_data.entrypoint_type.clone(),
+        entrypoint_name: entrypoint_data.entrypoint_name.clone(),
+        entrypoint_generator_name: entrypoint_data.entrypoint_generator_name.clone(),
+        entrypoint_id: entrypoint_id.clone(),
+        entrypoint_icon: entrypoint_data.icon.clone(),
+        plugin_name: plugin_name.to_string(),
+        plugin_id: plugin_id.clone(),
+        entrypoint_actions,
+        entrypoint_accessories,
+        entrypoint_arguments: entrypoint_data.arguments.clone(),
+        entrypoint_accepts_input: entrypoint_data.accepts_input.clone(),
+    }
+}
+
 struct QueryParser {
     tokenizer_manager: TokenizerManager,
     entrypoint_name: Field,
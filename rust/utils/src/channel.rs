@@ -109,6 +109,11 @@ impl<Res: std::fmt::Debug> Responder<Res> {
     pub fn respond(self, response: Res) {
         self.response_sender.send(response).expect("the receiver was closed")
     }
+
+    // for long-lived requests where other side could have been legitimately dropped while waiting
+    pub fn try_respond(self, response: Res) {
+        let _ = self.response_sender.send(response);
+    }
 }
 
 #[derive(Debug)]
@@ -0,0 +1,30 @@
+import { Detail } from "@project-gauntlet/api/components";
+import { ReactNode } from "react";
+
+const markdown = `
+# Ezaraa
+
+The Ezaraa were a species of **warmongering** carnivorous sentients that were native to the the planet \`Ezaraa\`.
+More information can be found on [Wookieepedia](https://starwars.fandom.com/wiki/Ezaraa).
+
+## Plans
+
+- [x] Attend the Auction of Rur
+- [ ] Overthrow the Galactic Empire
+
+| Name   | Affiliation       |
+|--------|-------------------|
+| Ezaraa | Ezaraa dominion   |
+`;
+
+export default function Main(): ReactNode {
+    return (
+        <Detail>
+            <Detail.Content>
+                <Detail.Content.Markdown>
+                    {markdown}
+                </Detail.Content.Markdown>
+            </Detail.Content>
+        </Detail>
+    )
+}
@@ -0,0 +1,15 @@
+import { Detail } from "@project-gauntlet/api/components";
+import { ReactNode } from "react";
+
+export default function Main(): ReactNode {
+    return (
+        <Detail>
+            <Detail.Content>
+                <Detail.Content.Paragraph>
+                    The Ezaraa were a species of warmongering carnivorous sentients that were native to the the planet <Detail.Content.Paragraph.Code>Ezaraa</Detail.Content.Paragraph.Code>.
+                    More information can be found on <Detail.Content.Paragraph.Link href="https://starwars.fandom.com/wiki/Ezaraa">Wookieepedia</Detail.Content.Paragraph.Link>.
+                </Detail.Content.Paragraph>
+            </Detail.Content>
+        </Detail>
+    )
+}
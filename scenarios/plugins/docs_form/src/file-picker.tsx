@@ -0,0 +1,18 @@
+import { ReactElement } from 'react';
+import { Form } from "@project-gauntlet/api/components";
+
+export default function Main(): ReactElement {
+    return (
+        <Form>
+            <Form.FilePicker
+                label="Images"
+                allowMultiple
+                extensions={["png", "jpg"]}
+                value={["/home/user/Pictures/cat.png", "/home/user/Pictures/dog.jpg"]}
+                onChange={values => {
+                    console.log(`values: ${values}`)
+                }}
+            />
+        </Form>
+    );
+};
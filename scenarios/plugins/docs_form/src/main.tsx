@@ -44,6 +44,12 @@ export default function Main(): ReactElement {
                     console.log(`value: ${value}`)
                 }}
             />
+            <Form.TextArea
+                label="Notes"
+                onChange={value => {
+                    console.log(`value: ${value}`)
+                }}
+            />
             <Form.Separator/>
             <Form.DatePicker
                 label="Date"
@@ -0,0 +1,23 @@
+import { ReactElement } from 'react';
+import { Form } from "@project-gauntlet/api/components";
+
+export default function Main(): ReactElement {
+    return (
+        <Form>
+            <Form.MultiSelect
+                label="Food"
+                value={["burger", "cookies"]}
+                onChange={values => {
+                    console.log(`values: ${values}`)
+                }}
+            >
+                <Form.MultiSelect.Item value="burger">Burger</Form.MultiSelect.Item>
+                <Form.MultiSelect.Item value="hot-dog">Hot Dog</Form.MultiSelect.Item>
+                <Form.MultiSelect.Item value="croissant">Croissant</Form.MultiSelect.Item>
+                <Form.MultiSelect.Item value="cookies">Cookies</Form.MultiSelect.Item>
+                <Form.MultiSelect.Item value="steak">Steak</Form.MultiSelect.Item>
+                <Form.MultiSelect.Item value="seafood">Seafood</Form.MultiSelect.Item>
+            </Form.MultiSelect>
+        </Form>
+    );
+};
@@ -0,0 +1,20 @@
+import { ReactElement } from 'react';
+import { Form } from "@project-gauntlet/api/components";
+
+export default function Main(): ReactElement {
+    return (
+        <Form>
+            <Form.NumberField
+                label="Temperature"
+                value={21.5}
+                min={10}
+                max={30}
+                step={0.5}
+                precision={1}
+                onChange={value => {
+                    console.log(`value: ${value}`)
+                }}
+            />
+        </Form>
+    );
+};
@@ -0,0 +1,16 @@
+import { ReactElement } from 'react';
+import { Form } from "@project-gauntlet/api/components";
+
+export default function Main(): ReactElement {
+    return (
+        <Form>
+            <Form.TextArea
+                label="Opening Crawl"
+                value={"It is a period of civil war.\nRebel spaceships, striking\nfrom a hidden base, have won\ntheir first victory against\nthe evil Galactic Empire."}
+                onChange={value => {
+                    console.log(`value: ${value}`)
+                }}
+            />
+        </Form>
+    );
+};
@@ -0,0 +1,36 @@
+import { ReactElement, useState } from 'react';
+import { Form } from "@project-gauntlet/api/components";
+
+export default function Main(): ReactElement {
+    const [nameError, setNameError] = useState<string | undefined>(undefined);
+    const [emailError, setEmailError] = useState<string | undefined>(undefined);
+
+    return (
+        <Form
+            onSubmit={values => {
+                const name = values["name"] as string;
+                const email = values["email"] as string;
+
+                setNameError(name.length === 0 ? "Name is required" : undefined)
+                setEmailError(!email.includes("@") ? "Invalid email" : undefined)
+            }}
+        >
+            <Form.TextField
+                id="name"
+                label="Name"
+                error={nameError}
+            />
+            <Form.TextField
+                id="email"
+                label="Email"
+                error={emailError}
+                info="Holonet address, e.g. obi-wan@jedi.org"
+            />
+            <Form.Checkbox
+                id="newsletter"
+                label="Newsletter"
+                title="Receive news from the Jedi Council"
+            />
+        </Form>
+    );
+};
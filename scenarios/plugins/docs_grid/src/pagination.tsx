@@ -0,0 +1,62 @@
+import { ReactElement, useEffect, useState } from "react";
+import { Grid } from "@project-gauntlet/api/components";
+
+const droids = [
+    "C-3PO",
+    "R2-D2",
+    "BB-8",
+    "IG-88",
+    "D-O",
+    "C1-10P",
+    "K-2SO",
+    "L3-37",
+    "R5-D4",
+    "IG-11",
+    "BD-1",
+    "AP-5",
+    "Chopper",
+    "Huyang",
+    "4-LOM",
+    "2-1B",
+    "BT-1",
+    "0-0-0",
+    "R4-P17",
+    "EV-9D9",
+    "WAC-47",
+    "B2EMO",
+    "Gonk",
+    "Mouse",
+]
+
+const pageSize = 10;
+
+async function fetchPage(page: number): Promise<string[]> {
+    return droids.slice(page * pageSize, (page + 1) * pageSize)
+}
+
+export default function Main(): ReactElement {
+    const [items, setItems] = useState<string[]>([]);
+    const [page, setPage] = useState(0);
+
+    useEffect(() => {
+        fetchPage(page)
+            .then(result => setItems(prev => [...prev, ...result]))
+    }, [page]);
+
+    return (
+        <Grid
+            hasMore={items.length < droids.length}
+            onLoadMore={() => setPage(prev => prev + 1)}
+        >
+            {items.map(value => (
+                <Grid.Item key={value}>
+                    <Grid.Item.Content>
+                        <Grid.Item.Content.Paragraph>
+                            {value}
+                        </Grid.Item.Content.Paragraph>
+                    </Grid.Item.Content>
+                </Grid.Item>
+            ))}
+        </Grid>
+    )
+}
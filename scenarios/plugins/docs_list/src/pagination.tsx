@@ -0,0 +1,56 @@
+import { ReactElement, useEffect, useState } from "react";
+import { List } from "@project-gauntlet/api/components";
+
+const planets = [
+    "Tatooine",
+    "Alderaan",
+    "Yavin IV",
+    "Hoth",
+    "Dagobah",
+    "Bespin",
+    "Endor",
+    "Naboo",
+    "Coruscant",
+    "Kamino",
+    "Geonosis",
+    "Utapau",
+    "Mustafar",
+    "Kashyyyk",
+    "Polis Massa",
+    "Mygeeto",
+    "Felucia",
+    "Cato Neimoidia",
+    "Saleucami",
+    "Stewjon",
+    "Eriadu",
+    "Corellia",
+    "Rodia",
+    "Nal Hutta",
+]
+
+const pageSize = 10;
+
+async function fetchPage(page: number): Promise<string[]> {
+    return planets.slice(page * pageSize, (page + 1) * pageSize)
+}
+
+export default function Main(): ReactElement {
+    const [items, setItems] = useState<string[]>([]);
+    const [page, setPage] = useState(0);
+
+    useEffect(() => {
+        fetchPage(page)
+            .then(result => setItems(prev => [...prev, ...result]))
+    }, [page]);
+
+    return (
+        <List
+            hasMore={items.length < planets.length}
+            onLoadMore={() => setPage(prev => prev + 1)}
+        >
+            {items.map(value => (
+                <List.Item id={value} title={value}/>
+            ))}
+        </List>
+    )
+}
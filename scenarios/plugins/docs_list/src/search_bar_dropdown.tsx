@@ -0,0 +1,39 @@
+import { ReactElement, useState } from "react";
+import { List } from "@project-gauntlet/api/components";
+
+const results = [
+    { title: "Disturbances in the Force", era: "old-republic" },
+    { title: "Bounty hunters", era: "empire" },
+    { title: "Astromech droids", era: "empire" },
+    { title: "Celestials and their technology", era: "old-republic" },
+    { title: "Ahsoka Tano", era: "clone-wars" },
+    { title: "Mandalorian Culture", era: "new-republic" }
+]
+
+export default function Main(): ReactElement {
+    const [era, setEra] = useState("all");
+
+    return (
+        <List>
+            <List.SearchBar placeholder="What knowledge do you seek...?">
+                <List.SearchBar.Dropdown value={era} onChange={setEra}>
+                    <List.SearchBar.Dropdown.Item value="all" title="All Eras"/>
+                    <List.SearchBar.Dropdown.Section title="Before Empire">
+                        <List.SearchBar.Dropdown.Section.Item value="old-republic" title="Old Republic"/>
+                        <List.SearchBar.Dropdown.Section.Item value="clone-wars" title="Clone Wars"/>
+                    </List.SearchBar.Dropdown.Section>
+                    <List.SearchBar.Dropdown.Section title="After Republic">
+                        <List.SearchBar.Dropdown.Section.Item value="empire" title="Empire"/>
+                        <List.SearchBar.Dropdown.Section.Item value="new-republic" title="New Republic"/>
+                    </List.SearchBar.Dropdown.Section>
+                </List.SearchBar.Dropdown>
+            </List.SearchBar>
+            {results
+                .filter(value => era === "all" || value.era === era)
+                .map(value => (
+                    <List.Item id={value.title} title={value.title}/>
+                ))
+            }
+        </List>
+    )
+}
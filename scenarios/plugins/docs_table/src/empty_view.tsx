@@ -0,0 +1,11 @@
+import { ReactElement } from "react";
+import { Table } from "@project-gauntlet/api/components";
+
+export default function Main(): ReactElement {
+    return (
+        <Table>
+            <Table.Column id="name" title="Name"/>
+            <Table.EmptyView title="Nothing here" description="But there was something"/>
+        </Table>
+    )
+}
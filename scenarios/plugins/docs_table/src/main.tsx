@@ -0,0 +1,40 @@
+import { ReactElement } from "react";
+import { Action, ActionPanel, Table } from "@project-gauntlet/api/components";
+
+export default function Main(): ReactElement {
+    return (
+        <Table
+            actions={
+                <ActionPanel>
+                    <Action label="Show species" onAction={id => console.log(id)}/>
+                </ActionPanel>
+            }
+        >
+            <Table.Column id="name" title="Name" width={2} sortable/>
+            <Table.Column id="homeworld" title="Homeworld" width={2} sortable/>
+            <Table.Column id="height" title="Average height (m)" sortable/>
+            <Table.Row id="adarian">
+                <Table.Row.Cell>Adarian</Table.Row.Cell>
+                <Table.Row.Cell>Adari</Table.Row.Cell>
+                <Table.Row.Cell>1.8</Table.Row.Cell>
+            </Table.Row>
+            <Table.Row id="ezaraa">
+                <Table.Row.Cell>Ezaraa</Table.Row.Cell>
+                <Table.Row.Cell>Ezaraa</Table.Row.Cell>
+                <Table.Row.Cell>1.9</Table.Row.Cell>
+            </Table.Row>
+            <Table.Row
+                id="houk"
+                actions={
+                    <ActionPanel>
+                        <Action label="Show Houk" onAction={id => console.log(id)}/>
+                    </ActionPanel>
+                }
+            >
+                <Table.Row.Cell>Houk</Table.Row.Cell>
+                <Table.Row.Cell>Lijuter</Table.Row.Cell>
+                <Table.Row.Cell>2.5</Table.Row.Cell>
+            </Table.Row>
+        </Table>
+    )
+}
@@ -0,0 +1,34 @@
+import { ReactElement, useState } from "react";
+import { Table } from "@project-gauntlet/api/components";
+
+const species = [
+    { name: "Adarian", homeworld: "Adari" },
+    { name: "Blutopian", homeworld: "Blutopia" },
+    { name: "Caphex", homeworld: "Caphex" },
+    { name: "Ezaraa", homeworld: "Ezaraa" },
+    { name: "Houk", homeworld: "Lijuter" },
+]
+
+export default function Main(): ReactElement {
+    const [searchText, setSearchText] = useState<string | undefined>("");
+
+    return (
+        <Table>
+            <Table.SearchBar placeholder="What species do you seek...?"
+                             value={searchText}
+                             onChange={setSearchText}
+            />
+            <Table.Column id="name" title="Name"/>
+            <Table.Column id="homeworld" title="Homeworld"/>
+            {species
+                .filter(value => !searchText ? true : value.name.toLowerCase().includes(searchText))
+                .map(value => (
+                    <Table.Row id={value.name}>
+                        <Table.Row.Cell>{value.name}</Table.Row.Cell>
+                        <Table.Row.Cell>{value.homeworld}</Table.Row.Cell>
+                    </Table.Row>
+                ))
+            }
+        </Table>
+    )
+}